@@ -0,0 +1,244 @@
+//! backpack – full inventory screen with drag‑and‑drop
+//!
+//! Tab toggles the grid, Escape closes it.  Each cell shows one stack as a
+//! coloured icon (no item sprites yet) with its count.  Dragging a stack onto
+//! an occupied cell swaps the two, onto an empty cell moves it; counts live in
+//! `Inventory::stacks` and are never touched by a drag.  While the screen is
+//! open the mouse is swallowed so clicks don't mine or shoot.
+
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use crate::components::{Inventory, ItemKind, Player, BACKPACK_SLOTS};
+use crate::settings::Settings;
+
+/// cells per row
+const BACKPACK_COLS: usize = 6;
+const CELL_SIZE:     f32   = 40.0;
+const ICON_SIZE:     f32   = 24.0;
+
+#[derive(Resource, Default)]
+pub struct BackpackUi {
+    pub open:     bool,
+    /// cell the held stack was picked up from
+    pub dragging: Option<usize>,
+}
+
+#[derive(Component)]
+pub struct BackpackScreen;
+
+#[derive(Component)]
+pub struct BackpackSlot(pub usize);
+
+#[derive(Component)]
+pub struct BackpackIcon(pub usize);
+
+#[derive(Component)]
+pub struct BackpackCount(pub usize);
+
+/// icon that follows the cursor while dragging
+#[derive(Component)]
+pub struct DragGhost;
+
+fn item_color(item: ItemKind) -> Color {
+    match item {
+        ItemKind::Dirt            => Color::srgb(0.45, 0.30, 0.15),
+        ItemKind::Stone           => Color::srgb(0.50, 0.50, 0.50),
+        ItemKind::Obsidian        => Color::srgb(0.20, 0.10, 0.30),
+        ItemKind::ReinforcedBlock => Color::srgb(0.70, 0.70, 0.75),
+        ItemKind::ObsidianBrick   => Color::srgb(0.35, 0.20, 0.45),
+        ItemKind::StonePickaxe    => Color::srgb(0.60, 0.60, 0.55),
+        ItemKind::IronPickaxe     => Color::srgb(0.80, 0.80, 0.85),
+        ItemKind::Glass           => Color::srgb(0.70, 0.88, 0.95),
+        ItemKind::Coin            => Color::srgb(0.95, 0.80, 0.25),
+        ItemKind::WarlordTusk     => Color::srgb(0.95, 0.92, 0.80),
+        ItemKind::Booster         => Color::srgb(0.85, 0.55, 0.15),
+        ItemKind::DirtWall        => Color::srgb(0.32, 0.18, 0.08),
+        ItemKind::StoneWall       => Color::srgb(0.30, 0.30, 0.32),
+        ItemKind::Miner           => Color::srgb(0.45, 0.50, 0.55),
+    }
+}
+
+/* ===========================================================
+   startup: hidden grid in the middle of the screen + ghost
+   =========================================================== */
+pub fn setup_backpack(mut commands: Commands) {
+    let grid_w = BACKPACK_COLS as f32 * (CELL_SIZE + 4.0);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width:         Val::Percent(100.0),
+                height:        Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items:     AlignItems::Center,
+                ..default()
+            },
+            Visibility::Hidden,
+            BackpackScreen,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    width:     Val::Px(grid_w + 16.0),
+                    flex_wrap: FlexWrap::Wrap,
+                    padding:   UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            ))
+            .with_children(|grid| {
+                for i in 0..BACKPACK_SLOTS {
+                    grid.spawn((
+                        Node {
+                            width:           Val::Px(CELL_SIZE),
+                            height:          Val::Px(CELL_SIZE),
+                            margin:          UiRect::all(Val::Px(2.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items:     AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                        RelativeCursorPosition::default(),
+                        BackpackSlot(i),
+                    ))
+                    .with_children(|cell| {
+                        cell.spawn((
+                            Node {
+                                width:  Val::Px(ICON_SIZE),
+                                height: Val::Px(ICON_SIZE),
+                                ..default()
+                            },
+                            BackgroundColor(Color::NONE),
+                            BackpackIcon(i),
+                        ));
+                        cell.spawn((
+                            Text::new(""),
+                            TextFont { font_size: 12.0, ..default() },
+                            TextColor(Color::WHITE),
+                            Node {
+                                position_type: PositionType::Absolute,
+                                right:  Val::Px(2.0),
+                                bottom: Val::Px(0.0),
+                                ..default()
+                            },
+                            BackpackCount(i),
+                        ));
+                    });
+                }
+            });
+        });
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width:  Val::Px(ICON_SIZE),
+            height: Val::Px(ICON_SIZE),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(50),
+        Visibility::Hidden,
+        DragGhost,
+    ));
+}
+
+/* ===========================================================
+   input – PreUpdate, after UI focus so hover is current; reads raw
+   button events so the drag survives swallowing the mouse state
+   =========================================================== */
+pub fn backpack_input_system(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut buttons: EventReader<MouseButtonInput>,
+    mut ui: ResMut<BackpackUi>,
+    slot_q: Query<(&BackpackSlot, &RelativeCursorPosition)>,
+    mut inv_q: Query<&mut Inventory, With<Player>>,
+) {
+    let Ok(mut inv) = inv_q.get_single_mut() else {
+        buttons.clear();
+        return;
+    };
+
+    if keys.just_pressed(settings.keys.backpack) {
+        ui.open = !ui.open;
+    } else if ui.open && keys.just_pressed(KeyCode::Escape) {
+        ui.open = false;
+        keys.clear_just_pressed(KeyCode::Escape); // don't leave fullscreen too
+    }
+    if !ui.open {
+        ui.dragging = None;
+        buttons.clear();
+        return;
+    }
+
+    let hovered = slot_q
+        .iter()
+        .find(|(_, rel)| rel.mouse_over())
+        .map(|(slot, _)| slot.0);
+
+    for ev in buttons.read() {
+        if ev.button != MouseButton::Left {
+            continue;
+        }
+        if ev.state.is_pressed() {
+            ui.dragging = hovered.filter(|&i| inv.slots[i].is_some());
+        } else if let Some(from) = ui.dragging.take() {
+            if let Some(to) = hovered.filter(|&to| to != from) {
+                inv.swap_slots(from, to);
+            }
+        }
+    }
+    mouse.reset_all();
+}
+
+/* ===========================================================
+   redraw – icons, counts, ghost
+   =========================================================== */
+pub fn backpack_panel_system(
+    ui: Res<BackpackUi>,
+    inv_q: Query<&Inventory, With<Player>>,
+    window_q: Query<&Window>,
+    mut screen_q: Query<&mut Visibility, (With<BackpackScreen>, Without<DragGhost>)>,
+    mut icon_q: Query<(&BackpackIcon, &mut BackgroundColor), Without<DragGhost>>,
+    mut count_q: Query<(&BackpackCount, &mut Text)>,
+    mut ghost_q: Query<(&mut Node, &mut BackgroundColor, &mut Visibility), With<DragGhost>>,
+) {
+    let Ok(mut vis) = screen_q.get_single_mut() else { return };
+    let Ok((mut ghost_node, mut ghost_bg, mut ghost_vis)) = ghost_q.get_single_mut() else {
+        return;
+    };
+    *vis = if ui.open { Visibility::Visible } else { Visibility::Hidden };
+    *ghost_vis = Visibility::Hidden;
+    if !ui.open {
+        return;
+    }
+    let Ok(inv) = inv_q.get_single() else { return };
+
+    for (icon, mut bg) in &mut icon_q {
+        bg.0 = match inv.slots[icon.0] {
+            // the picked‑up stack stays faintly in its cell until dropped
+            Some(item) if ui.dragging == Some(icon.0) => item_color(item).with_alpha(0.3),
+            Some(item) => item_color(item),
+            None       => Color::NONE,
+        };
+    }
+    for (count, mut text) in &mut count_q {
+        text.0 = inv.slots[count.0]
+            .map(|item| inv.count(item).to_string())
+            .unwrap_or_default();
+    }
+
+    let dragged = ui.dragging.and_then(|i| inv.slots[i]);
+    let cursor  = window_q.get_single().ok().and_then(|w| w.cursor_position());
+    if let (Some(item), Some(cursor)) = (dragged, cursor) {
+        ghost_node.left = Val::Px(cursor.x - ICON_SIZE * 0.5);
+        ghost_node.top  = Val::Px(cursor.y - ICON_SIZE * 0.5);
+        ghost_bg.0      = item_color(item);
+        *ghost_vis      = Visibility::Visible;
+    }
+}
@@ -0,0 +1,162 @@
+//! booster pads – placed like blocks, they push whatever stands on them
+//!
+//! The direction comes from the build‑stamp rotation at placement time
+//! (R while holding the block): 0° up, then clockwise.  An upward pad
+//! launches the body, a sideways one adds `BOOSTER_PUSH_SPEED` to the
+//! walk – slower than `WALK_SPEED`, so the player can still walk against
+//! it.  Pointing down it is just a floor.  Resting pickups are slid along
+//! directly (their velocity is zeroed while on the ground).  Orcs keep
+//! their velocity between frames, so they are left out rather than have
+//! the push pile up.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::components::{Collider, ItemPickup, Player, Velocity};
+use crate::constants::*;
+use crate::tile_stream::solid;
+use crate::world_gen::{tile_to_world_y, world_to_cell, Terrain, TileKind};
+
+/// direction and arrow sprite of one placed booster
+pub struct BoosterTile {
+    /// quarter turns clockwise from "up"
+    pub rotation: u8,
+    marker:       Option<Entity>,
+    /// marker currently shown (follows the tile's FOV visibility)
+    shown:        bool,
+}
+
+/// every placed booster, keyed by tile; entries whose tile was mined
+/// out are dropped by `booster_system`
+#[derive(Resource, Default)]
+pub struct Boosters {
+    pub tiles: HashMap<(usize, usize), BoosterTile>,
+    /// arrows of overwritten entries, despawned next frame
+    orphans:   Vec<Entity>,
+}
+
+impl Boosters {
+    pub fn place(&mut self, x: usize, y: usize, rotation: u8) {
+        let old = self.tiles.insert((x, y), BoosterTile { rotation, marker: None, shown: false });
+        // re‑placed on the same tile: the old arrow is cleaned up below
+        if let Some(BoosterTile { marker: Some(e), .. }) = old {
+            self.orphans.push(e);
+        }
+    }
+
+    /// push direction of the booster at (x, y); up if it was never
+    /// recorded (e.g. brought back by undo)
+    #[inline]
+    pub fn dir(&self, x: usize, y: usize) -> Vec2 {
+        rotation_dir(self.tiles.get(&(x, y)).map_or(0, |b| b.rotation))
+    }
+}
+
+#[inline]
+fn rotation_dir(rotation: u8) -> Vec2 {
+    match rotation % 4 {
+        0 => Vec2::Y,
+        1 => Vec2::X,
+        2 => Vec2::NEG_Y,
+        _ => Vec2::NEG_X,
+    }
+}
+
+/// booster tile right under a body's feet, if any
+#[inline]
+fn booster_below(terrain: &Terrain, pos: Vec2, half_h: f32) -> Option<(usize, usize)> {
+    let (tx, ty) = world_to_cell(terrain.height, pos - Vec2::Y * (half_h + 1.0));
+    (tx >= 0 && ty >= 0
+        && tx < terrain.width as i32 && ty < terrain.height as i32
+        && terrain.tiles[ty as usize][tx as usize].kind == TileKind::Booster)
+        .then_some((tx as usize, ty as usize))
+}
+
+/* ===========================================================
+   push bodies / pickups standing on a booster, keep the arrows
+   in sync with the tiles (runs between input and physics)
+   =========================================================== */
+pub fn booster_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    terrain: Res<Terrain>,
+    mut boosters: ResMut<Boosters>,
+    mut player_q: Query<(&Transform, &mut Velocity, &Collider), With<Player>>,
+    mut pickups: Query<(&mut Transform, &mut Velocity), (With<ItemPickup>, Without<Player>)>,
+) {
+    let dt = time.delta_secs();
+
+    /* ---- arrows: drop mined‑out boosters, mark new ones ---- */
+    for e in boosters.orphans.drain(..) {
+        commands.entity(e).despawn();
+    }
+    boosters.tiles.retain(|&(x, y), b| {
+        let alive = terrain.tiles[y][x].kind == TileKind::Booster;
+        if !alive {
+            if let Some(e) = b.marker {
+                commands.entity(e).despawn();
+            }
+        }
+        alive
+    });
+    for (&(x, y), b) in boosters.tiles.iter_mut() {
+        let visible = terrain.tiles[y][x].visible;
+        match b.marker {
+            Some(e) if b.shown != visible => {
+                commands.entity(e).insert(
+                    if visible { Visibility::Inherited } else { Visibility::Hidden },
+                );
+                b.shown = visible;
+            }
+            Some(_) => {}
+            None => {
+                let dir    = rotation_dir(b.rotation);
+                let centre = Vec2::new(x as f32 * tile_size(), tile_to_world_y(terrain.height, y));
+                // a bar along the edge the pad pushes toward
+                let size = if dir.x == 0.0 {
+                    Vec2::new(tile_size(), BOOSTER_MARKER_THICKNESS)
+                } else {
+                    Vec2::new(BOOSTER_MARKER_THICKNESS, tile_size())
+                };
+                let at = centre + dir * (tile_size() - BOOSTER_MARKER_THICKNESS) * 0.5;
+                b.marker = Some(commands.spawn((
+                    Sprite { color: BOOSTER_MARKER_COLOR, custom_size: Some(size), ..default() },
+                    Transform::from_translation(at.extend(1.5)),
+                    if visible { Visibility::Inherited } else { Visibility::Hidden },
+                )).id());
+                b.shown = visible;
+            }
+        }
+    }
+
+    /* ---- the player ---- */
+    if let Ok((tf, mut vel, collider)) = player_q.get_single_mut() {
+        if let Some((x, y)) = booster_below(&terrain, tf.translation.truncate(), collider.half.y) {
+            let dir = boosters.dir(x, y);
+            if dir.y > 0.0 {
+                vel.0.y = vel.0.y.max(BOOSTER_LAUNCH_SPEED);
+            }
+            // input rewrites vel.x every frame, so this never piles up
+            vel.0.x += dir.x * BOOSTER_PUSH_SPEED;
+        }
+    }
+
+    /* ---- loose items ---- */
+    for (mut tf, mut vel) in &mut pickups {
+        let pos = tf.translation.truncate();
+        let Some((x, y)) = booster_below(&terrain, pos, PICKUP_SIZE * 0.5) else { continue };
+        let dir = boosters.dir(x, y);
+        if dir.y > 0.0 {
+            vel.0.y = BOOSTER_LAUNCH_SPEED;
+        }
+        if dir.x != 0.0 {
+            let next_x   = pos.x + dir.x * BOOSTER_PUSH_SPEED * dt;
+            let ahead    = Vec2::new(next_x + dir.x * PICKUP_SIZE * 0.5, pos.y);
+            let (tx, ty) = world_to_cell(terrain.height, ahead);
+            if !solid(&terrain, tx, ty) {
+                tf.translation.x = next_x;
+            }
+        }
+    }
+}
@@ -0,0 +1,419 @@
+//! the orc warlord – a one‑off boss deep underground
+//!
+//! The first time the player gets `BOSS_TRIGGER_DEPTH` tiles below the
+//! surface, an arena is blasted open around them and the warlord drops in
+//! beside them.  It is a regular `Enemy` body (bullets, lava, physics and
+//! the melee swing all apply) with its own AI instead of `enemy_ai_system`;
+//! the phase follows its remaining HP:
+//!
+//! * `Charge` – walks in, then rushes across the arena
+//! * `Volley` – adds fans of thrown shots (`BossShot`)
+//! * `Slam`   – leaps and shakes the ground on landing, alternating with volleys
+//!
+//! Thrown shots can be shot down: a bullet that meets one destroys both.
+//! In peaceful mode an awake warlord stands still and its shots vanish.
+//! A bar at the bottom of the screen tracks its HP.  Killing it drops a
+//! warlord tusk (the merchant pays well for it) and a pile of coins.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::camera::ScreenShake;
+use crate::components::*;
+use crate::constants::*;
+use crate::debug::DebugFlags;
+use crate::difficulty::Difficulty;
+use crate::enemy::{spawn_orc_body, OrcAssets};
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::player::blast_knockback;
+use crate::tile_stream::{line_of_sight, solid, solid_kind};
+use crate::world_gen::{tile_to_world_y, world_to_cell, Decal, Terrain};
+
+/// spawned once per world; `last_pos` is where the reward drops
+#[derive(Resource, Default)]
+pub struct BossState {
+    pub spawned:  bool,
+    pub defeated: bool,
+    last_pos:     Vec2,
+}
+
+#[derive(Component)]
+pub struct BossBar;
+
+#[derive(Component)]
+pub struct BossBarFill;
+
+/* ===========================================================
+   startup: hidden bar, bottom centre
+   =========================================================== */
+pub fn setup_boss_bar(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(16.0),
+                left:   Val::Percent(30.0),
+                width:  Val::Percent(40.0),
+                height: Val::Px(14.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+            Visibility::Hidden,
+            BossBar,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.6, 0.05, 0.05)),
+                BossBarFill,
+            ));
+        });
+}
+
+/* ===========================================================
+   trigger: open the arena and drop the warlord in
+   =========================================================== */
+pub fn boss_spawn_system(
+    mut state: ResMut<BossState>,
+    mut commands: Commands,
+    mut terrain: ResMut<Terrain>,
+    mut shake: ResMut<ScreenShake>,
+    assets: Option<Res<OrcAssets>>,
+    difficulty: Res<Difficulty>,
+    player_q: Query<&Transform, With<Player>>,
+) {
+    // peaceful mode only holds it back – it still waits down there
+    if state.spawned || difficulty.peaceful {
+        return;
+    }
+    let (Some(assets), Ok(player_tf)) = (assets, player_q.get_single()) else { return };
+    let pos = player_tf.translation.truncate();
+    let (px, py) = world_to_cell(terrain.height, pos);
+    if px < 0 || py < 0 || px >= terrain.width as i32 || py >= terrain.height as i32 {
+        return;
+    }
+    if py - (terrain.height_map[px as usize] as i32) < BOSS_TRIGGER_DEPTH {
+        return;
+    }
+    state.spawned = true;
+
+    /* arena: the upper half of an ellipse, floor left at the player's feet */
+    let (rx, ry) = (BOSS_ARENA_RADIUS.x, BOSS_ARENA_RADIUS.y);
+    for y in (py - ry as i32)..=py {
+        for x in (px - rx as i32)..=(px + rx as i32) {
+            if x < 0 || y < 0 || x >= terrain.width as i32 || y >= terrain.height as i32 {
+                continue;
+            }
+            let (dx, dy) = ((x - px) as f32 / rx, (y - py) as f32 / ry);
+            let (ux, uy) = (x as usize, y as usize);
+            if dx * dx + dy * dy <= 1.0 && solid_kind(terrain.tiles[uy][ux].kind) {
+                terrain.clear(ux, uy);
+            }
+        }
+    }
+    terrain.splat_decal(px, py + 1, DECAL_SLAM_RADIUS, Decal::Scorch);
+    shake.add(BOSS_SLAM_SHAKE);
+
+    /* the warlord lands on whichever side has more room */
+    let side   = if px as usize > terrain.width / 2 { -1.0 } else { 1.0 };
+    let half_h = PLAYER_HEIGHT * 0.5 * BOSS_SIZE;
+    let spawn  = Vec2::new(
+        pos.x + side * BOSS_SPAWN_OFFSET * tile_size(),
+        tile_to_world_y(terrain.height, py as usize) - tile_size() * 0.5 + half_h + 1.0,
+    );
+    let max_hp = (BOSS_HP as f32 * difficulty.hp).round() as i32;
+    let e = spawn_orc_body(
+        &mut commands, &assets, spawn, BOSS_SIZE, max_hp, BOSS_SPEED, BOSS_TINT,
+    );
+    commands.entity(e).insert(Boss {
+        phase:        BossPhase::Charge,
+        action_timer: BOSS_ACTION_INTERVAL,
+        charge:       0.0,
+        charge_dir:   0.0,
+        charge_hit:   false,
+        slamming:     false,
+        actions:      0,
+    });
+    state.last_pos = spawn;
+    info!("the orc warlord has awoken");
+}
+
+/* ===========================================================
+   phase AI – replaces enemy_ai_system for the boss; frozen
+   (no charge, slam or volley) while peaceful mode is on
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn boss_ai_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut state: ResMut<BossState>,
+    mut shake: ResMut<ScreenShake>,
+    mut pool: ResMut<ParticlePool>,
+    mut terrain: ResMut<Terrain>,
+    difficulty: Res<Difficulty>,
+    debug: Res<DebugFlags>,
+    mut boss_q: Query<
+        (&mut Transform, &mut Velocity, &Enemy, &mut Boss, &Collider),
+        (With<Active>, Without<Player>),
+    >,
+    mut player_q: Query<(&Transform, &mut Health, &Collider, &mut Player, &mut Velocity), With<Player>>,
+) {
+    let Ok((mut tf, mut vel, enemy, mut boss, collider)) = boss_q.get_single_mut() else { return };
+    let Ok((player_tf, mut health, player_box, mut ply, mut player_vel)) = player_q.get_single_mut() else { return };
+    if difficulty.peaceful {
+        vel.0.x       = 0.0;
+        boss.charge   = 0.0;
+        boss.slamming = false;
+        boss.action_timer = BOSS_ACTION_INTERVAL;
+        return;
+    }
+    let dt         = time.delta_secs();
+    let pos        = tf.translation.truncate();
+    let player_pos = player_tf.translation.truncate();
+    let to_player  = player_pos - pos;
+    let dmg_scale  = if debug.invincible { 0.0 } else { difficulty.damage };
+    state.last_pos = pos;
+
+    /* ---- phase from remaining HP ---- */
+    let frac  = enemy.hp as f32 / enemy.max_hp as f32;
+    let phase = if frac > 2.0 / 3.0 {
+        BossPhase::Charge
+    } else if frac > 1.0 / 3.0 {
+        BossPhase::Volley
+    } else {
+        BossPhase::Slam
+    };
+    if phase != boss.phase {
+        info!("warlord phase: {phase:?}");
+        boss.phase        = phase;
+        boss.action_timer = BOSS_ACTION_INTERVAL;
+    }
+
+    /* ---- an ongoing charge owns the movement ---- */
+    if boss.charge > 0.0 {
+        boss.charge -= dt;
+        vel.0.x = boss.charge_dir * BOSS_CHARGE_SPEED;
+        let reach = player_box.half + collider.half;
+        let delta = to_player.abs();
+        if !boss.charge_hit && delta.x < reach.x && delta.y < reach.y {
+            health.current = (health.current - BOSS_CHARGE_DAMAGE * dmg_scale).max(0.0);
+            health.last_damage = 0.0;
+            shake.add(BOSS_CHARGE_SHAKE);
+            boss.charge_hit = true;
+        }
+        return;
+    }
+
+    /* ---- a slam resolves on landing ---- */
+    if boss.slamming {
+        if enemy.grounded {
+            boss.slamming = false;
+            shake.add(BOSS_SLAM_SHAKE);
+            let feet = pos - Vec2::Y * collider.half.y;
+            slam_dust(&mut commands, &mut pool, feet);
+            let (fx, fy) = world_to_cell(terrain.height, feet - Vec2::Y * tile_size() * 0.5);
+            terrain.splat_decal(fx, fy, DECAL_SLAM_RADIUS, Decal::Scorch);
+            if to_player.length() < tiles(BOSS_SLAM_RADIUS) && line_of_sight(&terrain, pos, player_pos) {
+                health.current = (health.current - BOSS_SLAM_DAMAGE * dmg_scale).max(0.0);
+                health.last_damage = 0.0;
+                blast_knockback(
+                    &mut ply, &mut player_vel, player_pos, feet,
+                    tiles(BOSS_SLAM_RADIUS), BOSS_SLAM_KNOCKBACK,
+                );
+            }
+        }
+        return;
+    }
+
+    /* ---- walk in between actions ---- */
+    let facing = if to_player.x >= 0.0 { 1.0 } else { -1.0 };
+    tf.scale.x = facing * tf.scale.x.abs();
+    vel.0.x = if to_player.x.abs() > tiles(BOSS_KEEP_AWAY) { enemy.speed * facing } else { 0.0 };
+
+    boss.action_timer -= dt;
+    if boss.action_timer > 0.0 || !enemy.grounded {
+        return;
+    }
+    boss.action_timer = BOSS_ACTION_INTERVAL;
+    boss.actions     += 1;
+
+    match (boss.phase, boss.actions % 2) {
+        (BossPhase::Charge, _) => {
+            boss.charge     = BOSS_CHARGE_TIME;
+            boss.charge_dir = facing;
+            boss.charge_hit = false;
+        }
+        (BossPhase::Volley, _) | (BossPhase::Slam, 0) => {
+            let origin = pos + Vec2::Y * collider.half.y * 0.5;
+            throw_volley(&mut commands, origin, player_pos);
+        }
+        (BossPhase::Slam, _) => {
+            vel.0.y       = BOSS_SLAM_JUMP;
+            vel.0.x       = to_player.x.clamp(-BOSS_CHARGE_SPEED, BOSS_CHARGE_SPEED);
+            boss.slamming = true;
+        }
+    }
+}
+
+/// a fan of shots aimed at `target`
+fn throw_volley(commands: &mut Commands, origin: Vec2, target: Vec2) {
+    let aim = (target - origin).normalize_or(Vec2::X);
+    let n   = BOSS_VOLLEY_COUNT;
+    for i in 0..n {
+        let t   = if n > 1 { i as f32 / (n - 1) as f32 - 0.5 } else { 0.0 };
+        let dir = Vec2::from_angle(t * BOSS_VOLLEY_SPREAD).rotate(aim);
+        commands.spawn((
+            Sprite {
+                color: BOSS_SHOT_COLOR,
+                custom_size: Some(Vec2::splat(BOSS_SHOT_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(origin.extend(12.0)),
+            Velocity(dir * BOSS_SHOT_SPEED),
+            BossShot { life: BOSS_SHOT_LIFETIME },
+        ));
+    }
+}
+
+fn slam_dust(commands: &mut Commands, pool: &mut ParticlePool, feet: Vec2) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..BOSS_SLAM_DUST {
+        let vel = Vec2::new(rng.gen_range(-160.0..160.0), rng.gen_range(10.0..80.0));
+        pool.spawn(
+            commands, ParticleKind::Exhaust, Color::srgb(0.55, 0.45, 0.35), 3.0,
+            feet.extend(11.0), vel, 0.7,
+        );
+    }
+}
+
+/* ===========================================================
+   thrown shots – arc a little, break on tiles, hurt the player;
+   peaceful mode clears them out of the air
+   =========================================================== */
+pub fn boss_shot_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    terrain: Res<Terrain>,
+    difficulty: Res<Difficulty>,
+    debug: Res<DebugFlags>,
+    mut shots: Query<(Entity, &mut Transform, &mut Velocity, &mut BossShot), Without<Player>>,
+    mut player_q: Query<(&Transform, &mut Health, &Collider), With<Player>>,
+) {
+    let dt = time.delta_secs();
+    let mut player = player_q.get_single_mut().ok();
+    let dmg_scale  = if debug.invincible { 0.0 } else { difficulty.damage };
+
+    for (e, mut tf, mut vel, mut shot) in &mut shots {
+        if difficulty.peaceful {
+            commands.entity(e).despawn();
+            continue;
+        }
+        shot.life -= dt;
+        vel.0.y  += GRAVITY * dt * BOSS_SHOT_GRAVITY;
+        tf.translation += (vel.0 * dt).extend(0.0);
+        let p        = tf.translation.truncate();
+        let (tx, ty) = world_to_cell(terrain.height, p);
+        if shot.life <= 0.0 || solid(&terrain, tx, ty) {
+            commands.entity(e).despawn();
+            continue;
+        }
+        if let Some((player_tf, health, player_box)) = player.as_mut() {
+            let delta = (player_tf.translation.truncate() - p).abs();
+            if delta.x < player_box.half.x && delta.y < player_box.half.y {
+                health.current = (health.current - BOSS_SHOT_DAMAGE * dmg_scale).max(0.0);
+                health.last_damage = 0.0;
+                commands.entity(e).despawn();
+            }
+        }
+    }
+}
+
+/* ===========================================================
+   bullets vs. thrown shots – an AABB overlap (like the orc hit
+   test) pops both in a small burst of sparks.  Each bullet takes
+   out at most one shot.
+   =========================================================== */
+pub fn shot_intercept_system(
+    mut commands: Commands,
+    mut pool: ResMut<ParticlePool>,
+    shots: Query<(Entity, &Transform), With<BossShot>>,
+    bullets: Query<(Entity, &Transform), (With<Bullet>, Without<BossShot>)>,
+) {
+    if shots.is_empty() {
+        return;
+    }
+    let reach = Vec2::splat(BOSS_SHOT_SIZE * 0.5 + SHOT_INTERCEPT_SLACK);
+    let mut spent: Vec<Entity> = Vec::new();
+    let mut rng = rand::thread_rng();
+
+    for (s_ent, s_tf) in &shots {
+        let s_pos = s_tf.translation.truncate();
+        let hit = bullets.iter().find(|(b_ent, b_tf)| {
+            let delta = (b_tf.translation.truncate() - s_pos).abs();
+            !spent.contains(b_ent) && delta.x <= reach.x && delta.y <= reach.y
+        });
+        let Some((b_ent, _)) = hit else { continue };
+        spent.push(b_ent);
+        commands.entity(s_ent).despawn();
+        commands.entity(b_ent).despawn();
+
+        for _ in 0..SHOT_SPARK_COUNT {
+            let vel = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU))
+                * rng.gen_range(SHOT_SPARK_SPEED);
+            pool.spawn(
+                &mut commands, ParticleKind::Debris, SHOT_SPARK_COLOR, 2.0,
+                s_pos.extend(12.0), vel, SHOT_SPARK_LIFE,
+            );
+        }
+    }
+}
+
+/* ===========================================================
+   HP bar + reward once the warlord is gone
+   =========================================================== */
+pub fn boss_bar_system(
+    mut state: ResMut<BossState>,
+    mut commands: Commands,
+    boss_q: Query<&Enemy, With<Boss>>,
+    mut bar_q: Query<&mut Visibility, With<BossBar>>,
+    mut fill_q: Query<&mut Node, With<BossBarFill>>,
+) {
+    let Ok(mut vis) = bar_q.get_single_mut() else { return };
+    match boss_q.get_single() {
+        Ok(enemy) => {
+            *vis = Visibility::Visible;
+            if let Ok(mut node) = fill_q.get_single_mut() {
+                let pct = (enemy.hp as f32 / enemy.max_hp as f32).clamp(0.0, 1.0) * 100.0;
+                node.width = Val::Percent(pct);
+            }
+        }
+        Err(_) => {
+            *vis = Visibility::Hidden;
+            if state.spawned && !state.defeated {
+                state.defeated = true;
+                drop_reward(&mut commands, state.last_pos);
+                info!("the orc warlord is slain");
+            }
+        }
+    }
+}
+
+fn drop_reward(commands: &mut Commands, pos: Vec2) {
+    let mut rng = rand::thread_rng();
+    for (item, n) in [(ItemKind::WarlordTusk, 1), (ItemKind::Coin, BOSS_COIN_DROP)] {
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(1.0, 0.9, 0.4),
+                custom_size: Some(Vec2::splat(PICKUP_SIZE * 1.5)),
+                ..default()
+            },
+            Transform::from_translation(pos.extend(6.0)),
+            Velocity(Vec2::new(rng.gen_range(-60.0..60.0), rng.gen_range(80.0..160.0))),
+            ItemPickup { item, n },
+        ));
+    }
+}
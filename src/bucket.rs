@@ -0,0 +1,66 @@
+//! bucket – carry a liquid tile from one place to another
+//!
+//! Use (LMB / LT) with the bucket held: empty and aimed at a liquid, it
+//! scoops the tile up (leaving air); full and aimed at an open tile that
+//! rests on something solid or on more liquid, it pours it back out.  The
+//! contents live on the `Inventory` (`bucket`), so switching items keeps
+//! them.  Lava is the only liquid so far – `is_liquid` decides what fits.
+
+use bevy::input::gamepad::Gamepad;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::components::{HeldItem, Inventory, Player};
+use crate::constants::*;
+use crate::gamepad::{cursor_to_tile, pad_just_pressed, GamepadAim, PAD_USE};
+use crate::tile_stream::{is_liquid, liquid_at, solid};
+use crate::world_gen::{Terrain, TileKind, LAVA_RGB};
+
+pub fn bucket_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
+    mut terrain: ResMut<Terrain>,
+    mut q: Query<(&Transform, &mut Inventory), With<Player>>,
+) {
+    let Ok((tf, mut inv)) = q.get_single_mut() else { return };
+    if inv.selected != HeldItem::Bucket {
+        return;
+    }
+    if !(mouse.just_pressed(MouseButton::Left) || pad_just_pressed(&pads, PAD_USE)) {
+        return;
+    }
+    let Some(world) = aim.point else { return };
+    if (world - tf.translation.truncate()).length_squared() > tiles(DIG_RADIUS).powi(2) {
+        return;
+    }
+
+    let Some((ux, uy)) = cursor_to_tile(&aim, &terrain) else { return };
+    let (tx, ty) = (ux as i32, uy as i32);
+    let kind     = terrain.tiles[uy][ux].kind;
+
+    match inv.bucket {
+        /* ---- fill ---- */
+        None if is_liquid(kind) => {
+            inv.bucket = Some(kind);
+            let tile = &mut terrain.tiles[uy][ux];
+            tile.kind      = TileKind::Air;
+            tile.mine_time = 0.0;
+        }
+        /* ---- pour: open tile resting on ground or on more liquid ---- */
+        Some(liquid)
+            if matches!(kind, TileKind::Air | TileKind::Sky)
+                && (solid(&terrain, tx, ty + 1) || liquid_at(&terrain, tx, ty + 1)) =>
+        {
+            inv.bucket = None;
+            let tile = &mut terrain.tiles[uy][ux];
+            tile.kind      = liquid;
+            tile.mine_time = 0.0;
+            if liquid == TileKind::Lava {
+                tile.base_rgb = LAVA_RGB; // the redraw never re‑tints lava
+            }
+        }
+        _ => return,
+    }
+    terrain.touch(ux, uy);
+}
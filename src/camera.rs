@@ -1,7 +1,11 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::input::ButtonInput;
 use bevy::prelude::*;
+use rand::Rng;
 
-use crate::components::Player;
+use crate::components::{Player, Velocity};
 use crate::constants::*;
+use crate::settings::Settings;
 use crate::world_gen::Terrain;
 
 /// pixel snapping helper – keeps the camera on whole pixels so sprites never
@@ -11,30 +15,182 @@ fn snap(v: f32) -> f32 {
     v.round()      // 1 U = 1 screen pixel in the default 2‑D camera
 }
 
-/// simple camera follow with world clamping
+/// `v.clamp(lo, hi)`, except a world smaller than the view (`lo > hi`)
+/// is centred instead of panicking
+#[inline]
+fn clamp_or_centre(v: f32, lo: f32, hi: f32) -> f32 {
+    if lo > hi { (lo + hi) * 0.5 } else { v.clamp(lo, hi) }
+}
+
+/// like `clamp_or_centre`, but the last `ease` units before either bound
+/// compress smoothly (slope 1 → 0) so the camera glides to a stop
+fn soft_clamp(v: f32, lo: f32, hi: f32, ease: f32) -> f32 {
+    if lo > hi {
+        return (lo + hi) * 0.5;
+    }
+    let ease = ease.min((hi - lo) * 0.5);
+    if ease <= 0.0 {
+        return v.clamp(lo, hi);
+    }
+    let (inner_lo, inner_hi) = (lo + ease, hi - ease);
+    if v < inner_lo {
+        inner_lo - ease * (1.0 - (-(inner_lo - v) / ease).exp())
+    } else if v > inner_hi {
+        inner_hi + ease * (1.0 - (-(v - inner_hi) / ease).exp())
+    } else {
+        v
+    }
+}
+
+/// sent after the player is moved instantly (teleports) – the follow
+/// camera drops its eased focus and jumps straight to the player
+#[derive(Event)]
+pub struct CameraSnap;
+
+/// screen‑shake intensity in 0..=1; the offset scales with trauma²
+/// so small knocks stay subtle and big ones really rattle
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    pub trauma: f32,
+}
+
+impl ScreenShake {
+    #[inline]
+    pub fn add(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+}
+
+/// detached debug camera – F6 toggles; while active the follow system is
+/// skipped and WASD / arrows pan, the wheel zooms.  Streaming keys off the
+/// camera, so tiles load wherever it looks.
+#[derive(Resource)]
+pub struct FreeCam {
+    pub active: bool,
+    pub zoom:   f32,
+}
+
+impl Default for FreeCam {
+    fn default() -> Self {
+        Self { active: false, zoom: 1.0 }
+    }
+}
+
+/// camera follow with velocity look‑ahead and world clamping
+///
+/// The un‑snapped focus point is kept in a `Local` so the lerp stays smooth
+/// even though the visible transform is rounded to whole pixels.
 ///
 /// NOTE: runs in **PostUpdate**, so we can rely on all physics having been
 /// applied and transforms already propagated.
 pub fn camera_follow_system(
+    time:         Res<Time>,
+    mut focus:    Local<Option<Vec2>>,
+    mut shake:    ResMut<ScreenShake>,
     mut cam_q:    Query<&mut Transform, (With<Camera>, Without<Player>)>,
-    player_q:     Query<&Transform, With<Player>>,
+    player_q:     Query<(&Transform, &Velocity), With<Player>>,
     window_q:     Query<&Window>,
     terrain:      Res<Terrain>,
+    free:         Res<FreeCam>,
+    mut snaps:    EventReader<CameraSnap>,
 ) {
+    if snaps.read().count() > 0 {
+        *focus = None;
+    }
+    if free.active {
+        return;
+    }
     let Ok(mut cam_tf) = cam_q.get_single_mut() else { return };
-    let Ok(player_tf)  = player_q.get_single()      else { return };
+    let Ok((player_tf, vel)) = player_q.get_single() else { return };
     let window = window_q.single();
 
+    // lead in the direction of motion …
+    let lead   = (vel.0 * CAMERA_LOOKAHEAD_FACTOR).clamp_length_max(tiles(CAMERA_LOOKAHEAD_MAX));
+    let target = player_tf.translation.truncate() + lead;
+
+    // … and ease toward it (exponential, so frame‑rate independent)
+    let prev = focus.unwrap_or(target);
+    let t    = 1.0 - (-CAMERA_LERP_SPEED * time.delta_secs()).exp();
+    let pos  = prev.lerp(target, t);
+    *focus   = Some(pos);
+
     let half_w   = window.width()  * 0.5;
     let half_h   = window.height() * 0.5;
-    let world_w  = terrain.width  as f32 * TILE_SIZE;
-    let world_h  = terrain.height as f32 * TILE_SIZE;
+    let world_w  = terrain.width  as f32 * tile_size();
+    let world_h  = terrain.height as f32 * tile_size();
 
-    // clamp camera to world bounds …
-    let x = player_tf.translation.x.clamp(half_w,  world_w - half_w);
-    let y = player_tf.translation.y.clamp(half_h,  world_h - half_h);
+    // keep the camera inside the world, easing in near the edges …
+    let ease  = tiles(CAMERA_EDGE_EASE);
+    let mut x = soft_clamp(pos.x, half_w, world_w - half_w, ease);
+    let mut y = soft_clamp(pos.y, half_h, world_h - half_h, ease);
+
+    // … add the shake (re‑clamped so it never reveals past the edges) …
+    if shake.trauma > 0.0 {
+        let mut rng = rand::thread_rng();
+        let amp = shake.trauma * shake.trauma * SHAKE_MAX_OFFSET;
+        x = clamp_or_centre(x + rng.gen_range(-1.0f32..=1.0) * amp, half_w, world_w - half_w);
+        y = clamp_or_centre(y + rng.gen_range(-1.0f32..=1.0) * amp, half_h, world_h - half_h);
+        shake.trauma = (shake.trauma - SHAKE_DECAY * time.delta_secs()).max(0.0);
+    }
 
     // … then snap to integer pixels to eliminate sub‑pixel shimmer
     cam_tf.translation.x = snap(x);
     cam_tf.translation.y = snap(y);
-}
\ No newline at end of file
+}
+/* ===========================================================
+   free‑fly camera – F6 to detach / re‑attach
+   =========================================================== */
+pub fn free_cam_system(
+    time:       Res<Time>,
+    keys:       Res<ButtonInput<KeyCode>>,
+    settings:   Res<Settings>,
+    mut wheel:  EventReader<MouseWheel>,
+    mut free:   ResMut<FreeCam>,
+    mut cam_q:  Query<(&mut Transform, &mut OrthographicProjection), With<Camera>>,
+    window_q:   Query<&Window>,
+    terrain:    Res<Terrain>,
+) {
+    let Ok((mut cam_tf, mut proj)) = cam_q.get_single_mut() else { return };
+
+    if keys.just_pressed(settings.keys.free_cam) {
+        free.active = !free.active;
+        if !free.active {
+            // back to the player at the normal scale
+            free.zoom  = 1.0;
+            proj.scale = 1.0;
+        }
+        info!("free camera {}", if free.active { "on" } else { "off" });
+    }
+    if !free.active {
+        wheel.clear();
+        return;
+    }
+
+    /* ---------- zoom ---------- */
+    for ev in wheel.read() {
+        let notches = match ev.unit {
+            MouseScrollUnit::Line  => ev.y,
+            MouseScrollUnit::Pixel => ev.y / 100.0,
+        };
+        free.zoom = (free.zoom - notches * FREE_CAM_ZOOM_STEP)
+            .clamp(FREE_CAM_ZOOM_MIN, FREE_CAM_ZOOM_MAX);
+    }
+    proj.scale = free.zoom;
+
+    /* ---------- pan ---------- */
+    let mut dir = Vec2::ZERO;
+    if keys.any_pressed([KeyCode::KeyA, KeyCode::ArrowLeft])  { dir.x -= 1.0; }
+    if keys.any_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) { dir.x += 1.0; }
+    if keys.any_pressed([KeyCode::KeyS, KeyCode::ArrowDown])  { dir.y -= 1.0; }
+    if keys.any_pressed([KeyCode::KeyW, KeyCode::ArrowUp])    { dir.y += 1.0; }
+    let step = dir.normalize_or_zero() * FREE_CAM_SPEED * free.zoom * time.delta_secs();
+
+    let window  = window_q.single();
+    let half_w  = window.width()  * 0.5 * free.zoom;
+    let half_h  = window.height() * 0.5 * free.zoom;
+    let world_w = terrain.width  as f32 * tile_size();
+    let world_h = terrain.height as f32 * tile_size();
+
+    cam_tf.translation.x = snap(clamp_or_centre(cam_tf.translation.x + step.x, half_w, world_w - half_w));
+    cam_tf.translation.y = snap(clamp_or_centre(cam_tf.translation.y + step.y, half_h, world_h - half_h));
+}
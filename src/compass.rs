@@ -0,0 +1,114 @@
+//! compass – HUD arrow pointing home (or to a custom waypoint)
+//!
+//! The target defaults to the spawn point (surface of column `w / 2`).
+//! P drops a waypoint at the player's position, Shift+P clears it again.
+//! The distance is shown in tiles next to the arrow.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::components::Player;
+use crate::constants::*;
+use crate::world_gen::{tile_to_world_y, Terrain};
+
+/// custom waypoint; `None` = point back to spawn
+#[derive(Resource, Default)]
+pub struct Waypoint(pub Option<Vec2>);
+
+#[derive(Component)]
+pub struct CompassArrow;
+
+#[derive(Component)]
+pub struct CompassText;
+
+/* ===========================================================
+   startup: arrow + label, top centre
+   =========================================================== */
+pub fn setup_compass(mut commands: Commands) {
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top:         Val::Px(10.0),
+            left:        Val::Percent(48.0),
+            align_items: AlignItems::Center,
+            column_gap:  Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            // 32 × 32 box rotated as a whole; the shaft points along +x
+            parent
+                .spawn((
+                    Node {
+                        width:           Val::Px(32.0),
+                        height:          Val::Px(32.0),
+                        align_items:     AlignItems::Center,
+                        justify_content: JustifyContent::FlexEnd,
+                        ..default()
+                    },
+                    CompassArrow,
+                ))
+                .with_children(|arrow| {
+                    arrow.spawn((
+                        Node { width: Val::Px(22.0), height: Val::Px(4.0), ..default() },
+                        BackgroundColor(Color::srgb(1.0, 0.85, 0.2)),
+                    ));
+                    arrow.spawn((
+                        Node { width: Val::Px(8.0), height: Val::Px(10.0), ..default() },
+                        BackgroundColor(Color::srgb(1.0, 0.85, 0.2)),
+                    ));
+                });
+            parent.spawn((
+                Text::new(""),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::WHITE),
+                CompassText,
+            ));
+        });
+}
+
+/* ===========================================================
+   P / Shift+P – set or clear the waypoint
+   =========================================================== */
+pub fn waypoint_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut waypoint: ResMut<Waypoint>,
+    player_q: Query<&Transform, With<Player>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyP) {
+        return;
+    }
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        waypoint.0 = None;
+    } else if let Ok(tf) = player_q.get_single() {
+        waypoint.0 = Some(tf.translation.truncate());
+    }
+}
+
+/* ===========================================================
+   per frame: rotate the arrow, update the distance
+   =========================================================== */
+pub fn compass_system(
+    waypoint: Res<Waypoint>,
+    terrain: Res<Terrain>,
+    player_q: Query<&Transform, (With<Player>, Without<CompassArrow>)>,
+    mut arrow_q: Query<&mut Transform, With<CompassArrow>>,
+    mut text_q: Query<&mut Text, With<CompassText>>,
+) {
+    let Ok(player_tf) = player_q.get_single() else { return };
+    let (Ok(mut arrow_tf), Ok(mut text)) = (arrow_q.get_single_mut(), text_q.get_single_mut())
+    else {
+        return;
+    };
+
+    let target = waypoint.0.unwrap_or_else(|| {
+        let x = terrain.width / 2;
+        Vec2::new(x as f32 * tile_size(), tile_to_world_y(terrain.height, terrain.height_map[x]))
+    });
+    let delta = target - player_tf.translation.truncate();
+
+    // UI y grows downward, so the world angle is mirrored
+    arrow_tf.rotation = Quat::from_rotation_z(-delta.y.atan2(delta.x));
+
+    let label = if waypoint.0.is_some() { "waypoint" } else { "spawn" };
+    text.0 = format!("{label} {:.0} tiles", delta.length() / tile_size());
+}
@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::constants::BULLET_PIERCE;
+use crate::world_gen::TileKind;
 
 /* ===========================================================
    shared components
@@ -12,6 +16,12 @@ pub struct Velocity(pub Vec2);
 #[derive(Component)]
 pub struct Player {
     pub grounded: bool,
+    /// seconds left in which a jump is still allowed after leaving ground
+    pub coyote: f32,
+    /// seconds left for a buffered jump press to fire on landing
+    pub jump_buffer: f32,
+    /// sideways blast push carried on top of walking (see `blast_knockback`)
+    pub knockback: f32,
 }
 
 /* dash state --------------------------------------------------------- */
@@ -42,13 +52,40 @@ pub struct HealthBarFill;
     inventory HUD slots
     =========================================================== */
 #[derive(Component)]
-pub struct InventorySlot(pub u8);   // 1 = pickaxe, 2 = gun, 3 = stone
+pub struct InventorySlot(pub u8);   // 1 = pickaxe, 2 = gun, 3 = stone, 4 = ladder, 5 = sign, 6 = glass, 7 = mirror, 8 = bucket, 9 = booster, 10 = wall
 
 #[derive(Component)]
 pub struct Debris {
     pub life: f32,
 }
 
+/* ===========================================================
+   body box – tile collision & hit tests for player and orcs
+   =========================================================== */
+#[derive(Component, Clone, Copy)]
+pub struct Collider {
+    /// half‑extents of the body in world units (after sprite scaling)
+    pub half: Vec2,
+}
+
+/// which tiles a body collides with – a bit per kind of mover; tiles
+/// carry a mask too (`tile_stream::tile_layers`) and block a body
+/// only when the two overlap
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CollisionLayers(pub u8);
+
+impl CollisionLayers {
+    pub const NONE:   Self = Self(0);
+    pub const PLAYER: Self = Self(1 << 0);
+    pub const ENEMY:  Self = Self(1 << 1);
+    pub const ALL:    Self = Self(u8::MAX);
+
+    #[inline]
+    pub fn overlaps(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
 /* ===========================================================
    enemies
    =========================================================== */
@@ -56,6 +93,8 @@ pub struct Debris {
 pub struct Enemy {
     pub grounded: bool,
     pub hp: i32,
+    /// spawn HP (after difficulty scaling) – for the health bar
+    pub max_hp: i32,
     pub recoil: f32,
     /// seconds until the next swing is allowed
     pub attack_cooldown: f32,
@@ -65,6 +104,101 @@ pub struct Enemy {
     pub attack_sheet: Handle<Image>,
     /// set to `true` right after a swing begins; cleared once frame 4 lands
     pub hit_pending: bool,
+    /// fractional hazard damage not yet subtracted from `hp`
+    pub burn: f32,
+    /// spawn position – the orc walks back here after losing aggro
+    pub home: Vec2,
+    /// currently chasing the player
+    pub aggro: bool,
+    /// walking home; ignores the player until it arrives
+    pub returning: bool,
+    /// seconds the player has been out of sight while aggroed
+    pub lost_sight: f32,
+    /// seconds until body contact can hurt the player again
+    pub contact_cooldown: f32,
+    /// body size relative to a regular orc (1.0) – see `ORC_VARIANTS`
+    pub size_scale: f32,
+    /// walk speed of this variant
+    pub speed: f32,
+}
+
+/// orc that ignores tile collision and digs its own way to the player;
+/// `enemy_ai_system` / `enemy_physics_system` skip it
+#[derive(Component)]
+pub struct Burrower {
+    /// tiles it may still dig right now (refills at BURROWER_DIG_RATE)
+    pub dig_budget: f32,
+    /// seconds left diving away after being hurt
+    pub flee: f32,
+    /// hp last frame – a drop starts the retreat
+    pub last_hp: i32,
+}
+
+/// orc that throws the player's placed stone instead of swinging
+/// (see `thrower.rs`); `enemy_attack_system` skips it
+#[derive(Component)]
+pub struct Thrower {
+    /// seconds until it may tear out the next block
+    pub cooldown: f32,
+}
+
+/// a stone block in flight, thrown by a `Thrower`; moved by its
+/// `Velocity` and shatters on whatever it meets
+#[derive(Component)]
+pub struct ThrownBlock {
+    pub life: f32,
+}
+
+/// tamed orc fighting on the player's side (see `follower.rs`);
+/// `enemy_ai_system` / `enemy_attack_system` skip it
+#[derive(Component)]
+pub struct Follower {
+    /// seconds until its next swing
+    pub strike_cooldown: f32,
+}
+
+/// attack pattern of the orc warlord, picked from its remaining HP
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BossPhase {
+    Charge,
+    Volley,
+    Slam,
+}
+
+/// the orc warlord (see `boss.rs`); also carries a regular `Enemy`
+#[derive(Component)]
+pub struct Boss {
+    pub phase:        BossPhase,
+    /// seconds until the next charge / volley / slam
+    pub action_timer: f32,
+    /// seconds left in the current charge
+    pub charge:       f32,
+    pub charge_dir:   f32,
+    /// the current charge already hit the player
+    pub charge_hit:   bool,
+    /// airborne in a slam; lands with a shock wave
+    pub slamming:     bool,
+    /// actions taken so far – alternates slams and volleys
+    pub actions:      u32,
+}
+
+/// shot thrown by the warlord; moved by its `Velocity`
+#[derive(Component)]
+pub struct BossShot {
+    pub life: f32,
+}
+
+/* floating health bar (children of an Enemy) */
+#[derive(Component)]
+pub struct EnemyHealthBar;
+
+#[derive(Component)]
+pub struct EnemyHealthBarFill;
+
+/// "!" over an orc that just noticed the player (child of the orc)
+#[derive(Component)]
+pub struct AlertMark {
+    pub life: f32,
 }
 
 /* tag added/removed every frame by update_active_tag_system */
@@ -95,6 +229,14 @@ pub struct TileSprite {
 #[derive(Component)]
 pub struct Highlight;
 
+/// a detached tile dropping under gravity (see `support.rs`)
+#[derive(Component)]
+pub struct FallingBlock {
+    pub x:         usize,
+    pub kind:      TileKind,
+    pub mine_time: f32,
+}
+
 /* ===========================================================
    particles
    =========================================================== */
@@ -103,6 +245,86 @@ pub struct Exhaust {
     pub life: f32,
 }
 
+/// flying orc body part (head / limb) – falls, lands and fades
+#[derive(Component)]
+pub struct Gib {
+    pub life: f32,
+}
+
+/// dead orc lying where it fell – fades over its last CORPSE_FADE_TIME
+#[derive(Component)]
+pub struct Corpse {
+    pub life:   f32,
+    /// half‑height of the body lying down (for resting on the ground)
+    pub half_h: f32,
+}
+
+/// breakable loot crate resting in a cavern room (never blocks movement)
+#[derive(Component)]
+pub struct Crate {
+    pub hp: f32,
+}
+
+/// orc portal deep in a cavern (see spawner.rs); shot down, it closes
+#[derive(Component)]
+pub struct Spawner {
+    pub hp:      f32,
+    /// seconds until the next orc, counted only while the player is near
+    pub timer:   f32,
+    /// orcs it let through that are still alive
+    pub spawned: Vec<Entity>,
+}
+
+/// placed autominer; sits on tile (x, y) and digs the tile `dir` from
+/// it one bite per MINER_TICK (see `miner.rs`)
+#[derive(Component)]
+pub struct Miner {
+    pub x:     usize,
+    pub y:     usize,
+    /// grid step toward the tile being dug (row 0 is the top)
+    pub dir:   (i32, i32),
+    /// seconds until the next bite
+    pub timer: f32,
+}
+
+/// loose item stack on the ground – walk over it to collect
+#[derive(Component)]
+pub struct ItemPickup {
+    pub item: ItemKind,
+    pub n:    u32,
+}
+
+/// falling snow particle (see `weather.rs`); moved by its `Velocity`
+#[derive(Component)]
+pub struct Snowflake;
+
+/// coloured point light; gets a glow sprite while inside the active rect
+/// (see `lights.rs`)
+#[derive(Component)]
+pub struct LightEmitter {
+    pub color:  Color,
+    /// glow radius in world units
+    pub radius: f32,
+}
+
+/// decorative sprout on exposed grass; (x, y) is the grass tile it grows
+/// from (see `vegetation.rs`)
+#[derive(Component)]
+pub struct Plant {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// friendly trader near the spawn (see `merchant.rs`)
+#[derive(Component)]
+pub struct Merchant;
+
+/// fading marker left by the ore scanner
+#[derive(Component)]
+pub struct ScanPing {
+    pub life: f32,
+}
+
 /* ========================================================
    inventory & weapons
    ======================================================== */
@@ -111,15 +333,212 @@ pub enum HeldItem {
     Pickaxe,
     Gun,
     StoneBlock,
+    Ladder,
+    Sign,
+    GlassBlock,
+    Mirror,
+    /// scoops up a liquid tile and pours it elsewhere (see bucket.rs)
+    Bucket,
+    /// placed like stone; pushes in the stamp's direction (see booster.rs)
+    BoosterBlock,
+    /// background walls: LMB places, RMB hammers out (see walls.rs)
+    Wall,
+}
+
+/// player‑placed sign; (x, y) is the tile it occupies
+#[derive(Component)]
+pub struct Sign {
+    pub text: String,
+    pub x:    usize,
+    pub y:    usize,
 }
 
+/// stackable materials & crafted goods carried in the backpack
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ItemKind {
+    Dirt,
+    Stone,
+    Obsidian,
+    ReinforcedBlock,
+    ObsidianBrick,
+    StonePickaxe,
+    IronPickaxe,
+    Glass,
+    /// merchant currency
+    Coin,
+    /// dropped by the orc warlord – the merchant buys it
+    WarlordTusk,
+    Booster,
+    DirtWall,
+    StoneWall,
+    /// placeable autominer (see miner.rs)
+    Miner,
+}
+
+/// pickaxe quality – how fast it digs and the hardest tile it can break
+/// (see `TileKind::hardness`)
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub enum PickaxeTier {
+    #[default]
+    Wood,
+    Stone,
+    Iron,
+}
+
+impl PickaxeTier {
+    #[inline]
+    pub fn speed(self) -> f32 {
+        match self {
+            PickaxeTier::Wood  => 1.0,
+            PickaxeTier::Stone => 1.5,
+            PickaxeTier::Iron  => 2.5,
+        }
+    }
+
+    #[inline]
+    pub fn max_hardness(self) -> u8 {
+        match self {
+            PickaxeTier::Wood  => 1, // dirt & stone
+            PickaxeTier::Stone => 2, // + obsidian
+            PickaxeTier::Iron  => 3,
+        }
+    }
+
+    /// tier granted by a crafted pickaxe item
+    #[inline]
+    pub fn from_item(item: ItemKind) -> Option<Self> {
+        match item {
+            ItemKind::StonePickaxe => Some(PickaxeTier::Stone),
+            ItemKind::IronPickaxe  => Some(PickaxeTier::Iron),
+            _                      => None,
+        }
+    }
+}
+
+/// grid cells in the backpack screen (see backpack.rs)
+pub const BACKPACK_SLOTS: usize = 24;
+
 #[derive(Component)]
 pub struct Inventory {
-    pub selected: HeldItem,
+    pub selected:  HeldItem,
+    /// what was held before `selected` – the quick‑swap key goes back to it
+    pub previous:  HeldItem,
+    /// item → stack size (missing key = none carried)
+    pub stacks:    HashMap<ItemKind, u32>,
+    /// backpack layout – which stack sits in which cell; counts live in `stacks`
+    pub slots:     [Option<ItemKind>; BACKPACK_SLOTS],
+    /// best pickaxe owned
+    pub pick_tier: PickaxeTier,
+    /// orcs each bullet passes through (gun upgrade; 0 = base gun)
+    pub gun_pierce: u8,
+    /// ballistics of the gun in hand (wheel cycles `WeaponStats::ALL`)
+    pub weapon:    WeaponStats,
+    /// liquid carried in the bucket (`None` = empty)
+    pub bucket: Option<TileKind>,
+}
+
+impl Inventory {
+    pub fn new(selected: HeldItem) -> Self {
+        Self {
+            selected,
+            previous:  selected,
+            stacks:    HashMap::new(),
+            slots:     [None; BACKPACK_SLOTS],
+            pick_tier: PickaxeTier::default(),
+            gun_pierce: BULLET_PIERCE,
+            weapon:    WeaponStats::GUN,
+            bucket:    None,
+        }
+    }
+
+    /// can the current pickaxe break this tile at all?
+    #[inline]
+    pub fn can_mine(&self, kind: TileKind) -> bool {
+        kind.hardness() <= self.pick_tier.max_hardness()
+    }
+
+    #[inline]
+    pub fn count(&self, item: ItemKind) -> u32 {
+        self.stacks.get(&item).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    pub fn add(&mut self, item: ItemKind, n: u32) {
+        *self.stacks.entry(item).or_insert(0) += n;
+        // new stacks take the first free cell (a full backpack still counts them)
+        if !self.slots.contains(&Some(item)) {
+            if let Some(cell) = self.slots.iter_mut().find(|s| s.is_none()) {
+                *cell = Some(item);
+            }
+        }
+    }
+
+    /// removes `n` items; returns `false` (and changes nothing) if short
+    pub fn take(&mut self, item: ItemKind, n: u32) -> bool {
+        let have = self.count(item);
+        if have < n {
+            return false;
+        }
+        if have == n {
+            self.stacks.remove(&item);
+            for cell in self.slots.iter_mut().filter(|s| **s == Some(item)) {
+                *cell = None;
+            }
+        } else {
+            self.stacks.insert(item, have - n);
+        }
+        true
+    }
+
+    /// drag‑and‑drop: swaps two cells, which is a plain move if `b` is empty
+    #[inline]
+    pub fn swap_slots(&mut self, a: usize, b: usize) {
+        self.slots.swap(a, b);
+    }
+}
+
+/// per‑weapon ballistics – read by the shooting system, stamped onto
+/// each bullet and used by the aim preview, so a new weapon is just
+/// another const here
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct WeaponStats {
+    pub name:            &'static str,
+    /// px / s along the aim direction
+    pub muzzle_velocity: f32,
+    /// fraction of GRAVITY the bullet falls with
+    pub gravity_factor:  f32,
+    pub damage:          f32,
+    /// seconds between shots while the trigger is held
+    pub fire_interval:   f32,
+}
+
+impl WeaponStats {
+    pub const GUN: Self = Self {
+        name: "gun", muzzle_velocity: 1200.0, gravity_factor: 0.5, damage: 35.0, fire_interval: 0.12,
+    };
+    /// fast, flat and slow‑firing
+    pub const RIFLE: Self = Self {
+        name: "rifle", muzzle_velocity: 2400.0, gravity_factor: 0.05, damage: 60.0, fire_interval: 0.45,
+    };
+    /// slow heavy arcs for shooting over cover
+    pub const LOBBER: Self = Self {
+        name: "lobber", muzzle_velocity: 650.0, gravity_factor: 2.5, damage: 50.0, fire_interval: 0.35,
+    };
+    pub const ALL: [Self; 3] = [Self::GUN, Self::RIFLE, Self::LOBBER];
 }
 
 #[derive(Component)]
 pub struct Bullet {
-    pub damage: f32,
-    pub life:   f32,
+    pub damage:  f32,
+    /// fraction of GRAVITY, from the weapon that fired it
+    pub gravity: f32,
+    pub life:    f32,
+    /// obsidian ricochets so far (see BULLET_MAX_BOUNCES)
+    pub bounces: u8,
+    /// further orcs it may pass through; 0 = stops at the first
+    pub pierce:  u8,
+    /// orcs already hit, so one passing through isn't hit every frame
+    pub hit:     Vec<Entity>,
+    /// × HIT_KNOCKBACK on the orcs it hits (charged shots push harder)
+    pub knockback: f32,
 }
\ No newline at end of file
@@ -1,21 +1,52 @@
 use bevy::prelude::*;
 use std::ops::Range;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 /* ===========================================================
    WORLD SIZE — crank it up!
    =========================================================== */
-/// size of one square tile, in world units
-pub const TILE_SIZE: f32 = 12.0;
+/// size of one square tile, in world units, unless `RenderConfig`
+/// overrides it at start‑up – read the live value with `tile_size()`
+pub const DEFAULT_TILE_SIZE: f32 = 12.0;
+pub const TILE_SIZE_RANGE: std::ops::RangeInclusive<f32> = 4.0..=48.0;
+
+static TILE_SIZE_BITS: AtomicU32 = AtomicU32::new(0); // 0 = not set yet
+
+/// world units per tile for this run (see `RenderConfig::tile_size`)
+#[inline]
+pub fn tile_size() -> f32 {
+    match TILE_SIZE_BITS.load(Ordering::Relaxed) {
+        0    => DEFAULT_TILE_SIZE,
+        bits => f32::from_bits(bits),
+    }
+}
+
+/// `n` tiles in world units – for the distances below given in tiles
+#[inline]
+pub fn tiles(n: f32) -> f32 {
+    n * tile_size()
+}
+
+/// called once from `main` before the app runs; everything after reads
+/// `tile_size()`
+pub fn set_tile_size(size: f32) {
+    TILE_SIZE_BITS.store(size.to_bits(), Ordering::Relaxed);
+}
 
 /// single‑chunk dimensions (tiles)
 pub const CHUNK_WIDTH:  usize = 32;
 pub const CHUNK_HEIGHT: usize = 32;
 
-/// number of chunks (world dimensions)
-pub const NUM_CHUNKS_X: usize = 256;    // width  = 10 240 tiles
-pub const NUM_CHUNKS_Y: usize = 64;    // height =  1 920 tiles
+/// number of chunks (world dimensions) – defaults for `GenParams`
+pub const NUM_CHUNKS_X: usize = 256;    // width  = 8 192 tiles
+pub const NUM_CHUNKS_Y: usize = 64;     // height = 2 048 tiles
+/// allowed world size (chunks); `GenParams::dims` clamps into these
+pub const WORLD_CHUNKS_X_MIN: usize = 4;
+pub const WORLD_CHUNKS_X_MAX: usize = 512;
+pub const WORLD_CHUNKS_Y_MIN: usize = 4;
+pub const WORLD_CHUNKS_Y_MAX: usize = 128;
 /* ===========================================================
-   streamed window size (in chunks)
+   streamed window size (in chunks) – minimum; grows with the viewport
    =========================================================== */
 pub const LOADED_CHUNK_COLS: i32 = 9;
 pub const LOADED_CHUNK_ROWS: i32 = 6;
@@ -35,8 +66,24 @@ pub const GRAVITY:       f32 = -650.0;
 pub const JUMP_SPEED:    f32 =  250.0;
 pub const JET_ACCEL:     f32 = 1200.0;
 pub const WALK_SPEED:    f32 =  250.0;
+/// top speed while sprinting, × WALK_SPEED
+pub const SPRINT_MULT:   f32 =  1.6;
+/// how fast the extra sprint speed builds up and bleeds off (px / s²) –
+/// lower means more momentum: a longer slide and a slower turn
+pub const SPRINT_ACCEL:  f32 =  450.0;
+/// blast knock‑back (boss slam, shattering blocks): the push at the
+/// centre is scaled down with distance and capped at BLAST_KNOCKBACK_MAX
+/// px / s; the sideways part bleeds off at BLAST_KNOCKBACK_DECAY px / s²
+pub const BLAST_KNOCKBACK_MAX:   f32 = 600.0;
+pub const BLAST_KNOCKBACK_DECAY: f32 = 900.0;
+/// extra upward tilt, so a blast at foot level still lifts the body
+pub const BLAST_LIFT:            f32 = 0.5;
 pub const COLLISION_STEPS: i32 = 4;
-pub const MAX_STEP_HEIGHT: f32 = TILE_SIZE;
+pub const MAX_STEP_HEIGHT: f32 = 1.0;   // tiles
+/// grace period after walking off a ledge in which a jump still works (s)
+pub const COYOTE_TIME:      f32 = 0.10;
+/// how long a jump press is remembered before landing (s)
+pub const JUMP_BUFFER_TIME: f32 = 0.12;
 
 pub const DASH_SPEED:        f32 = WALK_SPEED * 3.0; // 5 × walk speed
 pub const DASH_DURATION:     f32 = 0.1;              // seconds
@@ -50,6 +97,17 @@ pub const DASH_PUFF_LIFETIME: f32 = 0.60;
 /// sprite size for dash puffs (px)
 pub const DASH_PUFF_SIZE:     f32 = 5.0;
 
+/* thin air up by the sky islands (see env_zone.rs) */
+/// map‑height fraction above which the sky zone applies (surface ≈ 0.35)
+pub const SKY_ZONE_TOP_FRAC: f32 = 0.22;
+pub const SKY_ZONE_GRAVITY:  f32 = 0.45;  // × GRAVITY
+pub const SKY_ZONE_DRAG:     f32 = 0.6;   // 1 / s on vertical velocity
+/// tiles over which neighbouring zones blend into each other
+pub const ENV_ZONE_BLEND:    f32 = 40.0;
+
+/// fly speed with the debug noclip toggle on (px / s)
+pub const NOCLIP_SPEED:     f32 = 600.0;
+
 // pixels‑per‑second you can land without harm
 pub const SAFE_FALL_SPEED:  f32 = 500.0;
 // damage points per px/s above the safe speed
@@ -65,12 +123,61 @@ pub const EXHAUST_COLOR: Color = Color::srgba(1.0, 0.6, 0.2, 1.0);
 pub const EXHAUST_SPEED_Y: Range<f32> = -300.0..-120.0;
 pub const EXHAUST_SPEED_X: Range<f32> =  -50.0..  50.0;
 
+/* ===========================================================
+   footstep & landing dust (tinted by the tile underfoot)
+   =========================================================== */
+pub const DUST_LIFETIME:       f32 = 0.5;
+pub const DUST_SIZE:           f32 = 2.5;
+/// landings slower than this (px/s) kick up nothing – resting contact
+/// re‑lands every frame at ~gravity·dt
+pub const DUST_MIN_LAND_SPEED: f32 = 150.0;
+/// puffs per px/s of landing speed, capped at DUST_LAND_MAX
+pub const DUST_PER_LAND_SPEED: f32 = 0.03;
+pub const DUST_LAND_MAX:       usize = 24;
+/// seconds between footstep puffs while running
+pub const DUST_STEP_INTERVAL:  f32 = 0.18;
+pub const DUST_STEP_COUNT:     usize = 2;
+pub const DUST_SPEED_X: Range<f32> = -40.0..40.0;
+pub const DUST_SPEED_Y: Range<f32> =  10.0..45.0;
+
 /* ------------ NEW: inventory & combat ------------------ */
 pub const PICKAXE_SPEED: f32   =  4.0;     // tiles / sec
-pub const BULLET_SPEED:  f32   = 1200.0;     // px / sec (initial horizontal)
 pub const BULLET_LIFETIME: f32 =  3.0;     // sec
-pub const BULLET_DAMAGE:  f32   = 35.0;    // arbitrary
-pub const MINING_RADIUS: f32 = 2.0 * TILE_SIZE;    // radius of blocks actually mined
+pub const BULLET_PIERCE:  u8    = 0;       // orcs a base‑gun bullet passes through
+pub const BULLET_MAX_BOUNCES: u8 = 2;      // ricochets off obsidian
+pub const LIQUID_BULLET_DRAG: f32 = 4.0;   // 1 / s velocity loss inside a liquid
+pub const LIQUID_BULLET_SINK: f32 = 2.0;   // extra gravity multiplier inside a liquid
+pub const SPLASH_DROPLETS:    usize = 2;   // droplets per debris particle entering a liquid
+pub const GUN_SPREAD_RADIANS: f32 = 0.02;  // ± deviation of a fresh (tap) shot
+pub const GUN_BLOOM_RADIANS:  f32 = 0.20;  // extra ± deviation at full bloom
+pub const GUN_BLOOM_PER_SHOT: f32 = 0.15;  // bloom gained per shot (0‥1)
+pub const GUN_BLOOM_RECOVERY: f32 = 1.5;   // bloom lost per second when not firing
+pub const AIM_DOT_COUNT:      usize = 24;  // trajectory preview dots
+pub const AIM_DOT_INTERVAL:   f32 = 0.02;  // seconds of flight between dots
+pub const AIM_DOT_SIZE:       f32 = 2.0;
+pub const RETICLE_SIZE:       f32 = 10.0;
+/* charged shot (RMB with the gun): everything lerps from the `.start`
+   at GUN_CHARGE_MIN to the `.end` at GUN_CHARGE_MAX seconds held */
+pub const GUN_CHARGE_MIN:     f32 = 0.15;  // shorter holds fizzle
+pub const GUN_CHARGE_MAX:     f32 = 1.2;
+pub const GUN_CHARGE_SPEED:     Range<f32> = 1.2..1.8;   // × muzzle velocity
+pub const GUN_CHARGE_DAMAGE:    Range<f32> = 2.0..5.0;   // × weapon damage
+pub const GUN_CHARGE_SIZE:      Range<f32> = 8.0..14.0;  // px
+pub const GUN_CHARGE_KNOCKBACK: Range<f32> = 1.5..3.0;   // × HIT_KNOCKBACK
+pub const GUN_CHARGE_PIERCE:  u8  = 3;     // extra orcs on top of the gun's own
+pub const GUN_CHARGE_BAR:     Vec2 = Vec2::new(14.0, 2.0);
+/// mining radii are in tiles (scaled by `tiles()` where used)
+pub const MINING_RADIUS: f32 = 2.0;        // default radius of blocks actually mined
+pub const MINING_RADIUS_MIN:  f32 = 0.5;   // just the tile under the cursor
+pub const MINING_RADIUS_MAX:  f32 = 4.0;
+pub const MINING_RADIUS_STEP: f32 = 0.5;   // per +/- press
+pub const MINING_FALLOFF_MIN: f32 = 0.25;            // speed factor at the rim
+
+/* ------------ mining cracks (see cracks.rs) ------------ */
+pub const CRACK_STAGES:          usize = 4;
+pub const CRACK_LINES_PER_STAGE: usize = 2;
+pub const CRACK_REGEN_DELAY:     f32   = 2.0;   // s without a hit before a tile heals
+pub const CRACK_REGEN_RATE:      f32   = 0.5;   // fraction of the full mine time per s
 
 /* ------------ particle spray (mining debris) ----------- */
 pub const DEBRIS_LIFETIME: f32 = 0.2;
@@ -81,22 +188,105 @@ pub const DEBRIS_SPEED_Y:  std::ops::Range<f32> =  -24.0..24.0;
 /* ===========================================================
    digging
    =========================================================== */
-pub const DIG_RADIUS: f32 = 8.0 * TILE_SIZE;    // maximum reach: 8 tiles
+pub const DIG_RADIUS: f32 = 8.0;           // maximum reach, tiles
+pub const SIGN_MAX_LEN:     usize = 64;       // characters per sign
+pub const SIGN_READ_RADIUS: i32   = 2;        // tiles – how close to stand to read a sign
+
+/* ===========================================================
+   merchant
+   =========================================================== */
+pub const MERCHANT_SPAWN_OFFSET: usize = 6;               // tiles right of the player start
+pub const MERCHANT_REACH:        f32   = 4.0;  // talk distance, tiles
+pub const MERCHANT_IDLE_FRAME:   f32   = 0.25;            // s per idle frame
+pub const MERCHANT_TINT:         Color = Color::srgb(0.85, 0.70, 0.45);
 
 /* ===========================================================
    enemy behaviour
    =========================================================== */
-pub const AGGRO_RADIUS:    f32 = 32.0 * TILE_SIZE;
+pub const ENEMY_MAX_HP:    i32 = 100;   // before difficulty scaling
+pub const ENEMY_COUNT:     usize = 64;  // before difficulty scaling
+pub const SPAWN_SAFE_RADIUS: usize = 48;   // tiles either side of the player start kept orc‑free
+pub const ENEMY_SPAWN_ATTEMPTS: usize = 5_000; // re‑rolls before the fallback placement
+pub const ENEMY_SPAWN_INTERVAL: f32 = 8.0;   // seconds between reinforcement orcs
+pub const ENEMY_SPAWN_MARGIN:   i32 = 4;     // tiles outside the ActiveRect they appear
+pub const ENEMY_MELEE_DAMAGE: f32 = 10.0;
+pub const ENEMY_CONTACT_DAMAGE:   f32 = 3.0;  // per touch, on top of swings
+pub const ENEMY_CONTACT_COOLDOWN: f32 = 0.75; // seconds between touches, per orc
+pub const AGGRO_RADIUS:    f32 = 32.0;  // tiles
 pub const ENEMY_SPEED:     f32 = WALK_SPEED * 0.8;
-pub const ENEMY_KEEP_AWAY: f32 = 4.0 * TILE_SIZE;
+pub const ORC_SPRITE_SCALE: f32 = 1.8;  // transform scale of a regular orc
+pub const ENEMY_KEEP_AWAY: f32 = 4.0;   // tiles
+pub const ENEMY_LEASH_RADIUS: f32 = 48.0;            // max chase distance from home, tiles
+pub const ENEMY_LOS_TIMEOUT:  f32 = 4.0;              // seconds out of sight before giving up
+pub const ENEMY_HAZARD_PROBE: i32 = 4;                // tiles below the next step checked for lava
+pub const ALERT_MARK_LIFE:    f32 = 0.9;              // s the "!" stays up
+pub const ALERT_MARK_POP:     f32 = 1.6;              // start scale, eases to 1
+pub const ALERT_MARK_COLOR: Color = Color::srgb(1.0, 0.85, 0.1);
 pub const RECOIL_TIME: f32 = 2.0;   // stun duration after a bullet hit
 
+/* ------------ orc warlord boss (see boss.rs) ------------ */
+pub const BOSS_TRIGGER_DEPTH:  i32   = 150;   // tiles below the surface
+pub const BOSS_ARENA_RADIUS:   Vec2  = Vec2::new(22.0, 10.0); // tiles, half‑ellipse
+pub const BOSS_SPAWN_OFFSET:   f32   = 14.0;  // tiles beside the player
+pub const BOSS_SIZE:           f32   = 3.0;   // × a regular orc
+pub const BOSS_HP:             i32   = 2_000; // before difficulty scaling
+pub const BOSS_SPEED:          f32   = ENEMY_SPEED * 0.6;
+pub const BOSS_TINT:           Color = Color::srgb(0.85, 0.45, 0.40);
+pub const BOSS_KEEP_AWAY:      f32   = 3.0;   // tiles
+pub const BOSS_ACTION_INTERVAL: f32  = 2.5;   // s between charges / volleys / slams
+pub const BOSS_CHARGE_TIME:    f32   = 0.9;
+pub const BOSS_CHARGE_SPEED:   f32   = WALK_SPEED * 1.8;
+pub const BOSS_CHARGE_DAMAGE:  f32   = 25.0;
+pub const BOSS_CHARGE_SHAKE:   f32   = 0.4;
+pub const BOSS_VOLLEY_COUNT:   usize = 5;
+pub const BOSS_VOLLEY_SPREAD:  f32   = 0.6;   // radians across the whole fan
+pub const BOSS_SHOT_SPEED:     f32   = 320.0;
+pub const BOSS_SHOT_GRAVITY:   f32   = 0.3;   // × GRAVITY
+pub const BOSS_SHOT_LIFETIME:  f32   = 3.0;
+pub const BOSS_SHOT_DAMAGE:    f32   = 8.0;
+pub const BOSS_SHOT_SIZE:      f32   = 4.0;
+pub const BOSS_SHOT_COLOR:     Color = Color::srgb(0.45, 0.35, 0.25);
+/// extra px around a thrown shot that still counts as a bullet hit
+pub const SHOT_INTERCEPT_SLACK: f32   = 4.0;
+pub const SHOT_SPARK_COUNT:    usize = 8;
+pub const SHOT_SPARK_SPEED:    std::ops::Range<f32> = 60.0..180.0;
+pub const SHOT_SPARK_LIFE:     f32   = 0.25;
+pub const SHOT_SPARK_COLOR:    Color = Color::srgb(1.0, 0.85, 0.4);
+pub const BOSS_SLAM_JUMP:      f32   = 420.0; // px / s upward
+pub const BOSS_SLAM_RADIUS:    f32   = 10.0;  // tiles
+pub const BOSS_SLAM_DAMAGE:    f32   = 30.0;
+pub const BOSS_SLAM_KNOCKBACK: f32   = 520.0; // px / s at the centre
+pub const BOSS_SLAM_SHAKE:     f32   = 0.8;
+pub const BOSS_SLAM_DUST:      usize = 40;
+pub const BOSS_COIN_DROP:      u32   = 40;
+
 /* ------------ blood explosion (orc death) --------------- */
 pub const BLOOD_LIFETIME: f32 = 0.6;
 pub const BLOOD_RATE:     usize = 128;
 pub const BLOOD_SPEED_X:  std::ops::Range<f32> = -180.0..180.0;
 pub const BLOOD_SPEED_Y:  std::ops::Range<f32> =  -100.0..100.0;
 pub const BLOOD_COLOR: Color = Color::srgb(0.8, 0.0, 0.0);
+pub const GIBS_PER_DEATH: usize = 5;       // head + four limbs
+pub const GIB_MAX_ALIVE:  usize = 60;      // global cap; oldest are cut short
+pub const GIB_LIFETIME:   f32 = 1.6;
+pub const GIB_SPEED_X:    std::ops::Range<f32> = -140.0..140.0;
+pub const GIB_SPEED_Y:    std::ops::Range<f32> =  120.0..260.0;
+pub const GIB_COLOR:      Color = Color::srgb(0.35, 0.55, 0.25);
+pub const CORPSE_LIFETIME:  f32 = 6.0;
+pub const CORPSE_FADE_TIME: f32 = 1.5;     // fades out over the last part of its life
+pub const CORPSE_Z:         f32 = 9.0;     // just behind living orcs
+pub const CORPSE_TINT:      Color = Color::srgb(0.55, 0.55, 0.55);
+pub const CORPSE_LOOT_CHANCE: f64 = 0.35;
+pub const CORPSE_LOOT_COINS:  std::ops::RangeInclusive<u32> = 1..=3;
+
+/* ------------ decals (stains on the tile tint, see world_gen::Decal) -- */
+pub const DECAL_BLOOD_RGB:     Vec3 = Vec3::new(0.45, 0.05, 0.04);
+pub const DECAL_BLOOD_AMOUNT:  f32  = 0.55;
+pub const DECAL_SCORCH_RGB:    Vec3 = Vec3::new(0.06, 0.05, 0.04);
+pub const DECAL_SCORCH_AMOUNT: f32  = 0.6;
+/// tiles scorched around a boss slam / a closing portal
+pub const DECAL_SLAM_RADIUS:   f32  = 2.5;
+pub const DECAL_PORTAL_RADIUS: f32  = 2.0;
 
 /* ------------ hit feedback ----------------------------- */
 pub const HIT_KNOCKBACK:  f32 = 240.0;      // px / s impulse on X axis
@@ -109,4 +299,308 @@ pub const HIT_BLOOD_LIFE: f32 = 0.4;
    =========================================================== */
 pub const COLOR_NOISE_SCALE: f64 = 0.05;
 pub const COLOR_VARIATION_LEVELS: i32 = 4;
-pub const COLOR_VARIATION_STRENGTH: f32 = 0.2;
\ No newline at end of file
+pub const COLOR_VARIATION_STRENGTH: f32 = 0.2;
+/* temperature tint – low‑frequency, on top of the steps above */
+pub const TEMP_NOISE_SCALE: f64  = 0.004;
+pub const TEMP_NOISE_AMOUNT: f32 = 0.3;    // ± wobble of the depth curve
+pub const TEMP_HEAT_BAND: f32    = 0.10;   // map fraction above the lava layer that warms up
+pub const COLD_TINT: Vec3 = Vec3::new(0.80, 0.90, 1.15);
+pub const WARM_TINT: Vec3 = Vec3::new(1.20, 0.90, 0.75);
+/* ===========================================================
+   gamepad
+   =========================================================== */
+/// stick deflection below this is ignored
+pub const GAMEPAD_DEADZONE:  f32 = 0.2;
+/// distance of the right‑stick aim point from the player at full tilt
+pub const GAMEPAD_AIM_REACH: f32 = 6.0;   // tiles
+
+/* ===========================================================
+   lava
+   =========================================================== */
+/// damage per second while standing in lava (player & enemies)
+pub const LAVA_DPS: f32 = 40.0;
+/// seconds between tint updates of the pulsing glow
+pub const LAVA_PULSE_INTERVAL: f32 = 0.1;
+/// angular speed of the glow pulse (rad / s)
+pub const LAVA_PULSE_SPEED: f32 = 2.5;
+/// ± brightness swing of the glow pulse
+pub const LAVA_PULSE_AMOUNT: f32 = 0.18;
+
+/* ===========================================================
+   coloured glows (see lights.rs)
+   =========================================================== */
+pub const GLOW_TEXTURE_SIZE:  u32   = 64;     // px, baked once at startup
+pub const GLOW_Z:             f32   = 30.0;   // above tiles, bodies and snow
+pub const GLOW_MAX:           usize = 256;    // sprites alive at once
+/// seconds between rescans of lava inside the active rect
+pub const GLOW_LAVA_REFRESH:  f32   = 0.5;
+/// lava is lit per LAVA_GLOW_CELL × LAVA_GLOW_CELL block of tiles
+pub const LAVA_GLOW_CELL:     usize = 4;
+pub const LAVA_GLOW_RADIUS:   f32   = 6.0;    // tiles
+pub const LAVA_LIGHT_COLOR:   Color = Color::srgba(1.0, 0.45, 0.10, 0.30);
+pub const PLAYER_LIGHT_RADIUS: f32  = 5.0;    // tiles
+pub const PLAYER_LIGHT_COLOR: Color = Color::srgba(0.85, 0.90, 1.0, 0.12);
+
+/* ===========================================================
+   depth vignette (see vignette.rs)
+   =========================================================== */
+pub const VIGNETTE_TEXTURE_SIZE:    u32 = 128;
+pub const VIGNETTE_Z:               f32 = 25.0;  // above tiles & bodies, below glows
+/// fraction of the half‑screen left untouched around the centre
+pub const VIGNETTE_CLEAR_RADIUS:    f32 = 0.45;
+pub const VIGNETTE_MAX_ALPHA:       f32 = 0.85;
+/// depth below the surface (map fraction) at which the vignette peaks
+pub const VIGNETTE_FULL_DEPTH_FRAC: f32 = 0.45;
+pub const VIGNETTE_FADE_SPEED:      f32 = 0.5;   // alpha per second
+
+/* ===========================================================
+   debug tile grid (F2, see debug.rs)
+   =========================================================== */
+pub const DEBUG_GRID_Z:        f32 = 40.0;   // over everything in the world
+pub const DEBUG_GRID_COLOR:    Color = Color::srgba(1.0, 1.0, 1.0, 0.15);
+pub const DEBUG_ACTIVE_COLOR:  Color = Color::srgba(1.0, 0.85, 0.1, 0.8);
+pub const DEBUG_LOADED_COLOR:  Color = Color::srgba(0.2, 0.8, 1.0, 0.8);
+/// grid lines are left out once a tile is smaller than this on screen
+pub const DEBUG_GRID_MIN_PX:   f32 = 4.0;
+pub const DEBUG_RECT_WIDTH_PX: f32 = 2.0;
+
+/* ===========================================================
+   rendering (see render.rs)
+   =========================================================== */
+pub const DEFAULT_VSYNC:   bool        = true;
+/// frame cap applied on top of (or instead of) vsync; `None` = uncapped
+pub const DEFAULT_FPS_CAP: Option<u32> = Some(144);
+
+/* ===========================================================
+   camera
+   =========================================================== */
+/// seconds of player velocity the camera leads by
+pub const CAMERA_LOOKAHEAD_FACTOR: f32 = 0.25;
+/// upper bound on the look‑ahead offset (px)
+pub const CAMERA_LOOKAHEAD_MAX:    f32 = 8.0;   // tiles
+/// how quickly the camera catches up with its target (1 / s)
+pub const CAMERA_LERP_SPEED:       f32 = 6.0;
+/// the follow camera starts slowing this far before a world edge
+pub const CAMERA_EDGE_EASE:        f32 = 6.0;   // tiles
+/// free‑fly debug camera pan speed at zoom 1 (px / s)
+pub const FREE_CAM_SPEED:          f32 = 900.0;
+/// projection scale change per mouse‑wheel notch
+pub const FREE_CAM_ZOOM_STEP:      f32 = 0.1;
+/// zoom limits – the loaded chunk window grows with zoom, so the cap
+/// only bounds how many tile sprites get streamed in
+pub const FREE_CAM_ZOOM_MIN:       f32 = 0.5;
+pub const FREE_CAM_ZOOM_MAX:       f32 = 1.5;
+/// largest screen‑shake offset at full trauma (px)
+pub const SHAKE_MAX_OFFSET:        f32 = 10.0;
+/// trauma lost per second
+pub const SHAKE_DECAY:             f32 = 1.5;
+/// trauma per point of fall damage
+pub const SHAKE_PER_FALL_DAMAGE:   f32 = 0.03;
+/// trauma added when an orc dies
+pub const SHAKE_ORC_DEATH:         f32 = 0.25;
+
+/* ===========================================================
+   ore scanner
+   =========================================================== */
+pub const SCAN_RADIUS:        i32   = 40;     // tiles
+pub const SCAN_COOLDOWN:      f32   = 3.0;    // s between pulses
+pub const SCAN_PING_LIFETIME: f32   = 2.0;    // s until a marker fades out
+pub const SCAN_MAX_PINGS:     usize = 200;
+
+/* ===========================================================
+   lighting transitions
+   =========================================================== */
+/// brightness units per second a tile fades toward its target
+pub const BRIGHTNESS_FADE_SPEED: f32 = 6.0;
+/// close enough – snap and stop redrawing
+pub const BRIGHTNESS_EPSILON:    f32 = 0.01;
+/// open tiles (cave air, walls) darken this much when fully boxed in by
+/// solid neighbours – edges count 1, diagonals ½ (see tile_stream.rs)
+pub const AO_STRENGTH:           f32 = 0.35;
+/// side of one baked tile texture, in pixels (see tile_atlas.rs)
+pub const TILE_ATLAS_CELL:       usize = 8;
+
+/* ===========================================================
+   crates & loot pickups
+   =========================================================== */
+/// fraction of carved cavern rooms that get a crate
+pub const CRATE_ROOM_CHANCE: f32 = 0.3;
+/// two base‑gun bullets (`WeaponStats::GUN`) or half a second of wood pickaxe
+pub const CRATE_HP:          f32 = 70.0;
+pub const CRATE_MINE_RATE:   f32 = 140.0;   // hp / s while the pickaxe is on it
+pub const CRATE_COLOR: Color = Color::srgb(0.55, 0.35, 0.15);
+pub const CRATE_LOOT_ROLLS:  std::ops::RangeInclusive<u32> = 1..=3;
+pub const PICKUP_SIZE:       f32 = 6.0;
+/// pickups this close to the player's centre are collected
+pub const PICKUP_RADIUS:     f32 = 1.5;   // tiles
+
+/* ===========================================================
+   orc portals (see spawner.rs)
+   =========================================================== */
+pub const SPAWNER_COUNT:          usize = 12;
+/// portals only go in cavern rooms below this fraction of the map
+pub const SPAWNER_MIN_DEPTH_FRAC: f32   = 0.35;
+pub const SPAWNER_MIN_SPACING:    usize = 60;    // tiles between portals
+pub const SPAWNER_HP:             f32   = 300.0;
+pub const SPAWNER_INTERVAL:       f32   = 6.0;   // s between orcs
+pub const SPAWNER_MAX_ALIVE:      usize = 3;     // its own orcs at once
+pub const SPAWNER_RANGE:          f32   = 40.0;  // tiles – player must be this close
+pub const SPAWNER_SIZE:           Vec2  = Vec2::new(2.0, 3.0); // tiles
+pub const SPAWNER_COLOR:          Color = Color::srgb(0.55, 0.15, 0.85);
+pub const SPAWNER_PULSE_SPEED:    f32   = 3.0;   // rad / s of the alpha throb
+pub const SPAWNER_BURST:          usize = 64;    // particles when it closes
+pub const SPAWNER_BURST_LIFE:     f32   = 0.6;
+
+/* ===========================================================
+   ladders
+   =========================================================== */
+pub const CLIMB_SPEED: f32 = 140.0;   // px / s while holding W / S
+
+/* ===========================================================
+   undo (Ctrl+Z) of player tile edits
+   =========================================================== */
+pub const CHANGE_HISTORY_LEN: usize = 256; // undo entries (one per action); oldest fall off
+
+/* ===========================================================
+   stats & achievements (see stats.rs)
+   =========================================================== */
+/// tiles moved in one frame beyond which it counts as a teleport
+pub const STATS_MAX_STEP:          f32 = 8.0;
+pub const ACHIEVEMENT_BANNER_TIME: f32 = 4.0;   // s on screen
+
+/* ===========================================================
+   magic mirror (return to the surface spawn)
+   =========================================================== */
+pub const MIRROR_CHANNEL_TIME: f32 = 3.0;   // seconds standing still before the jump
+pub const MIRROR_CANCEL_DIST:  f32 = 2.0;   // px of drift that breaks the channel
+pub const MIRROR_COLOR: Color = Color::srgb(0.55, 0.75, 1.0);
+
+/* ===========================================================
+   portals (placed in linked pairs with RMB while holding the mirror)
+   =========================================================== */
+pub const PORTAL_COOLDOWN: f32 = 0.75;  // s before any portal fires again
+pub const PORTAL_COLOR: Color = Color::srgb(0.70, 0.45, 1.0);
+
+/* ===========================================================
+   autominers (crafted, placed with RMB while holding the pickaxe)
+   =========================================================== */
+pub const MINER_MAX:          usize = 8;     // placed at once
+pub const MINER_TICK:         f32 = 0.25;    // s between bites
+pub const MINER_BITE:         f32 = 0.15;    // mine time taken per bite
+pub const MINER_MAX_HARDNESS: u8  = 1;       // stone, not obsidian
+pub const MINER_COLOR: Color = Color::srgb(0.45, 0.50, 0.55);
+pub const MINER_DRILL_COLOR: Color = Color::srgb(0.85, 0.75, 0.30);
+
+/* ===========================================================
+   burrowing orcs (tunnel through rock toward the player)
+   =========================================================== */
+pub const BURROWER_COUNT:    usize = 8;     // before difficulty scaling
+pub const BURROWER_DEPTH:    std::ops::Range<usize> = 20..60; // tiles below the surface
+pub const BURROWER_SPEED:    f32 = ENEMY_SPEED * 0.5;
+/// tiles per second it may dig, banked up to BURROWER_DIG_BURST
+pub const BURROWER_DIG_RATE:  f32 = 4.0;
+pub const BURROWER_DIG_BURST: f32 = 3.0;
+/// hardest tile it can dig through (stone yes, obsidian no)
+pub const BURROWER_MAX_HARDNESS: u8 = 1;
+pub const BURROWER_FLEE_TIME: f32 = 2.5;   // seconds spent diving away after a hit
+pub const BURROWER_STOP_DIST: f32 = 3.0;    // tiles – surfaces this close and swings
+pub const BURROWER_TINT: Color = Color::srgb(0.75, 0.6, 0.45);
+
+/* ===========================================================
+   block throwers (tear placed stone out and hurl it, see thrower.rs)
+   =========================================================== */
+pub const THROWER_COUNT:    usize = 6;     // before difficulty scaling
+pub const THROWER_TINT:     Color = Color::srgb(0.65, 0.75, 0.55);
+/// placed stone this close (tiles) to the orc can be picked up
+pub const THROWER_REACH:    f32   = 6.0;
+/// seconds between two throws – caps how fast one orc strips a wall
+pub const THROWER_INTERVAL: f32   = 2.5;
+pub const THROWER_SPEED:    f32   = 280.0; // average flight speed, px / s
+pub const THROWER_GRAVITY:  f32   = 0.6;   // × GRAVITY
+pub const THROWN_LIFETIME:  f32   = 3.0;
+pub const THROWN_DAMAGE:    f32   = 10.0;  // before difficulty scaling
+pub const THROWN_SHARDS:    usize = 14;
+pub const THROWN_BLAST_RADIUS: f32 = 2.0;  // tiles of knock‑back around a shatter
+pub const THROWN_KNOCKBACK: f32   = 260.0; // px / s at the centre
+
+/* ===========================================================
+   walk‑cycle pacing (see `anim_rate`)
+   =========================================================== */
+/// animation speed multiplier bounds, 1.0 at the body's walk speed
+pub const ANIM_RATE_RANGE: std::ops::RangeInclusive<f32> = 0.4..=2.0;
+/// below this an orc counts as standing still and holds its first frame
+pub const ANIM_STILL_SPEED: f32 = 5.0;   // px / s
+
+/* ===========================================================
+   background walls (see walls.rs)
+   =========================================================== */
+/// walls stay this bright outside the FOV, so walled rooms never go black
+pub const WALL_MIN_BRIGHTNESS: f32 = 0.18;
+
+/* ===========================================================
+   booster pads (see booster.rs)
+   =========================================================== */
+pub const BOOSTER_LAUNCH_SPEED: f32 = JUMP_SPEED * 1.8;
+/// sideways push, kept below WALK_SPEED so it can be walked against
+pub const BOOSTER_PUSH_SPEED:   f32 = WALK_SPEED * 0.6;
+pub const BOOSTER_MARKER_THICKNESS: f32 = 3.0;
+pub const BOOSTER_MARKER_COLOR: Color = Color::srgb(1.0, 0.85, 0.35);
+
+/* ===========================================================
+   orc follower (see follower.rs)
+   =========================================================== */
+pub const TAME_HP_FRAC:   f32 = 0.35;             // orc must be this hurt to be tamed
+pub const TAME_RANGE:     f32 = 3.0;               // tiles
+pub const FOLLOWER_TINT:  Color = Color::srgb(0.65, 1.0, 0.7);
+/// follower distances are in tiles
+pub const FOLLOWER_FOLLOW_DIST:   f32 = 3.0;   // trails the player this far behind
+pub const FOLLOWER_TELEPORT_DIST: f32 = 30.0;  // further than this: snaps back
+pub const FOLLOWER_AGGRO_RADIUS:  f32 = 16.0;
+pub const FOLLOWER_STRIKE_RANGE:  f32 = 3.0;
+pub const FOLLOWER_STRIKE_COOLDOWN: f32 = 1.2;
+pub const FOLLOWER_SWING_TIME:    f32 = 0.5;   // attack sheet shown this long
+pub const FOLLOWER_DAMAGE:        i32 = 20;
+
+/* ===========================================================
+   weather (snow over the mountain caps)
+   =========================================================== */
+pub const WEATHER_SPELL: std::ops::Range<f32> = 40.0..120.0; // seconds per weather state
+pub const SNOW_FLAKES_PER_SEC: f32 = 60.0;   // spawn attempts while snowing
+pub const STORM_FLAKES_PER_SEC: f32 = 220.0;
+pub const SNOW_MAX_FLAKES:  usize = 600;
+pub const SNOW_FALL_SPEED:  f32 = 40.0;      // px / s
+/// strongest calm‑weather wind (px / s); storms blow WIND_STORM_MULT × harder
+pub const WIND_MAX:          f32 = 40.0;
+pub const WIND_STORM_MULT:   f32 = 2.5;
+pub const WIND_GUST_INTERVAL: std::ops::Range<f32> = 4.0..12.0; // s per new target
+pub const WIND_CHANGE_RATE:  f32 = 8.0;       // px / s² toward the target
+/// 1 / s – how quickly light things take on the wind's speed
+pub const WIND_PARTICLE_COUPLING: f32 = 0.6;
+pub const WIND_SNOW_COUPLING:     f32 = 1.5;
+pub const SNOW_FLAKE_SIZE:  f32 = 2.0;
+/// surface tiles per second that may gain a snow layer (snow / storm)
+pub const SNOW_SETTLE_RATE:  f32 = 0.5;
+pub const STORM_SETTLE_RATE: f32 = 2.0;
+
+/* ===========================================================
+   vegetation (grass regrowth, see vegetation.rs)
+   =========================================================== */
+pub const GRASS_TICK:          f32   = 0.5;   // s between growth passes
+pub const GRASS_SAMPLES:       usize = 400;   // random window tiles checked per pass
+pub const GRASS_SPREAD_CHANCE: f32   = 0.25;  // per qualifying dirt tile sampled
+pub const PLANT_SPROUT_CHANCE: f32   = 0.02;  // per exposed grass tile sampled
+pub const PLANT_MAX_ALIVE:     usize = 150;
+/// width, and the range the height is rolled from (px)
+pub const PLANT_SIZE: Vec2 = Vec2::new(4.0, 9.0);
+pub const PLANT_RGB:  Vec3 = Vec3::new(0.20, 0.55, 0.12);
+
+/* ===========================================================
+   particle budget (see particles.rs)
+   =========================================================== */
+/// smoothed frame time above which bursts start thinning out
+pub const PARTICLE_AUTO_FRAME_MS:       f32 = 20.0;
+/// recover only once frames are comfortably below the threshold
+pub const PARTICLE_AUTO_RECOVER_FRAC:   f32 = 0.8;
+pub const PARTICLE_AUTO_DROP:           f32 = 0.5;   // factor lost per second
+pub const PARTICLE_AUTO_RECOVER:        f32 = 0.1;   // factor regained per second
+pub const PARTICLE_AUTO_MIN:            f32 = 0.25;
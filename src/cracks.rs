@@ -0,0 +1,143 @@
+//! mining cracks – visible damage on tiles the pickaxe is working on
+//!
+//! `pickaxe_mining_system` reports every tile it chips at (`MiningCracks::
+//! touch`), remembering the tile's mine time when the first hit landed.
+//! Here each damaged tile gets an overlay picked from `CRACK_STAGES` baked
+//! crack textures, deeper the closer it is to breaking.  Left alone for
+//! `CRACK_REGEN_DELAY`, a tile knits back together and the overlay goes
+//! once it is whole again (or the tile is gone).
+
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use rand::Rng;
+use std::collections::HashMap;
+
+use crate::constants::*;
+use crate::tile_stream::minable_kind;
+use crate::world_gen::{tile_to_world_y, Terrain};
+
+/// one damaged tile
+pub struct Crack {
+    /// mine time when the first hit landed
+    full:   f32,
+    /// seconds since the last hit
+    idle:   f32,
+    sprite: Option<Entity>,
+}
+
+#[derive(Resource, Default)]
+pub struct MiningCracks(HashMap<(usize, usize), Crack>);
+
+impl MiningCracks {
+    /// the pickaxe is about to chip at (x, y), whose mine time is `mine_time`;
+    /// returns the mine time the tile had when the first hit landed
+    pub fn touch(&mut self, x: usize, y: usize, mine_time: f32) -> f32 {
+        let crack = self.0
+            .entry((x, y))
+            .or_insert(Crack { full: mine_time, idle: 0.0, sprite: None });
+        crack.idle = 0.0;
+        crack.full
+    }
+}
+
+/// overlay per stage, each with more cracks than the last
+#[derive(Resource)]
+pub struct CrackTextures(Vec<Handle<Image>>);
+
+/* ===========================================================
+   startup: bake the stages – random‑walk cracks from the centre,
+   every stage keeps the previous one's and adds more
+   =========================================================== */
+pub fn setup_crack_textures(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let size = tile_size() as i32;
+    let mut rng    = rand::thread_rng();
+    let mut pixels = vec![0u8; (size * size) as usize];
+    let mut stages = Vec::with_capacity(CRACK_STAGES);
+
+    for _ in 0..CRACK_STAGES {
+        for _ in 0..CRACK_LINES_PER_STAGE {
+            let (mut x, mut y) = (size / 2, size / 2);
+            let dir = (rng.gen_range(-1..=1), rng.gen_range(-1..=1));
+            for _ in 0..size / 2 {
+                pixels[(y * size + x) as usize] = 255;
+                x = (x + dir.0 + rng.gen_range(-1..=1)).clamp(0, size - 1);
+                y = (y + dir.1 + rng.gen_range(-1..=1)).clamp(0, size - 1);
+            }
+        }
+        let data = pixels.iter().flat_map(|&a| [0, 0, 0, a]).collect();
+        let mut image = Image::new(
+            Extent3d { width: size as u32, height: size as u32, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        image.sampler = ImageSampler::nearest();
+        stages.push(images.add(image));
+    }
+    commands.insert_resource(CrackTextures(stages));
+}
+
+/* ===========================================================
+   overlay, regeneration & clean‑up
+   =========================================================== */
+pub fn crack_overlay_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut cracks: ResMut<MiningCracks>,
+    mut terrain: ResMut<Terrain>,
+    textures: Option<Res<CrackTextures>>,
+    mut sprites: Query<&mut Sprite>,
+) {
+    let Some(textures) = textures else { return };
+    let dt = time.delta_secs();
+
+    cracks.0.retain(|&(x, y), crack| {
+        let tile = &mut terrain.tiles[y][x];
+        let minable = minable_kind(tile.kind);
+
+        crack.idle += dt;
+        if minable && crack.idle > CRACK_REGEN_DELAY {
+            tile.mine_time = (tile.mine_time + crack.full * CRACK_REGEN_RATE * dt).min(crack.full);
+        }
+        let progress = 1.0 - tile.mine_time / crack.full.max(f32::EPSILON);
+
+        if !minable || progress <= 0.0 {
+            if let Some(e) = crack.sprite {
+                commands.entity(e).despawn();
+            }
+            return false;
+        }
+
+        let stage = ((progress * CRACK_STAGES as f32) as usize).min(CRACK_STAGES - 1);
+        let image = textures.0[stage].clone();
+        match crack.sprite.and_then(|e| sprites.get_mut(e).ok()) {
+            Some(mut sprite) => {
+                if sprite.image != image {
+                    sprite.image = image;
+                }
+            }
+            None => {
+                crack.sprite = Some(
+                    commands
+                        .spawn((
+                            Sprite {
+                                image,
+                                custom_size: Some(Vec2::splat(tile_size())),
+                                ..default()
+                            },
+                            Transform::from_xyz(
+                                x as f32 * tile_size(),
+                                tile_to_world_y(terrain.height, y),
+                                1.0,
+                            ),
+                        ))
+                        .id(),
+                );
+            }
+        }
+        true
+    });
+}
@@ -0,0 +1,209 @@
+//! crafting – recipe table, craft input & recipe panel
+//!
+//! C toggles the panel, ↑/↓ pick a recipe, Enter crafts it.
+//! Inputs come from the stacked `Inventory` (filled by mining).
+//! Ore tiles do not exist yet, so only stone / obsidian recipes are listed.
+//! Crafted pickaxes are equipped at once and raise `Inventory::pick_tier`.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::components::{Inventory, ItemKind, PickaxeTier, Player};
+use crate::settings::Settings;
+
+/* ===========================================================
+   recipe table
+   =========================================================== */
+pub struct CraftingRecipe {
+    pub name:   &'static str,
+    pub inputs: &'static [(ItemKind, u32)],
+    pub output: (ItemKind, u32),
+}
+
+pub const RECIPES: &[CraftingRecipe] = &[
+    CraftingRecipe {
+        name:   "Reinforced block",
+        inputs: &[(ItemKind::Stone, 5)],
+        output: (ItemKind::ReinforcedBlock, 1),
+    },
+    CraftingRecipe {
+        name:   "Obsidian brick",
+        inputs: &[(ItemKind::Obsidian, 4), (ItemKind::Stone, 2)],
+        output: (ItemKind::ObsidianBrick, 1),
+    },
+    CraftingRecipe {
+        // no sand tiles yet – smelted from dirt instead
+        name:   "Glass",
+        inputs: &[(ItemKind::Dirt, 3)],
+        output: (ItemKind::Glass, 2),
+    },
+    CraftingRecipe {
+        name:   "Dirt wall",
+        inputs: &[(ItemKind::Dirt, 1)],
+        output: (ItemKind::DirtWall, 4),
+    },
+    CraftingRecipe {
+        name:   "Stone wall",
+        inputs: &[(ItemKind::Stone, 1)],
+        output: (ItemKind::StoneWall, 4),
+    },
+    CraftingRecipe {
+        name:   "Booster",
+        inputs: &[(ItemKind::Stone, 4), (ItemKind::Obsidian, 1)],
+        output: (ItemKind::Booster, 2),
+    },
+    CraftingRecipe {
+        name:   "Autominer",
+        inputs: &[(ItemKind::ObsidianBrick, 2), (ItemKind::ReinforcedBlock, 2), (ItemKind::Booster, 1)],
+        output: (ItemKind::Miner, 1),
+    },
+    CraftingRecipe {
+        name:   "Stone pickaxe",
+        inputs: &[(ItemKind::Stone, 10)],
+        output: (ItemKind::StonePickaxe, 1),
+    },
+    CraftingRecipe {
+        // no iron ore yet – forged from obsidian instead
+        name:   "Iron pickaxe",
+        inputs: &[(ItemKind::ObsidianBrick, 3), (ItemKind::ReinforcedBlock, 2)],
+        output: (ItemKind::IronPickaxe, 1),
+    },
+];
+
+impl CraftingRecipe {
+    #[inline]
+    pub fn affordable(&self, inv: &Inventory) -> bool {
+        self.inputs.iter().all(|&(item, n)| inv.count(item) >= n)
+    }
+
+    /// consumes the inputs and adds the output; refuses if anything is short
+    pub fn craft(&self, inv: &mut Inventory) -> bool {
+        if !self.affordable(inv) {
+            return false;
+        }
+        for &(item, n) in self.inputs {
+            inv.take(item, n);
+        }
+        inv.add(self.output.0, self.output.1);
+        true
+    }
+}
+
+/* ===========================================================
+   UI state & markers
+   =========================================================== */
+#[derive(Resource, Default)]
+pub struct CraftingMenu {
+    pub open:     bool,
+    pub selected: usize,
+}
+
+#[derive(Component)]
+pub struct CraftingPanel;
+
+#[derive(Component)]
+pub struct CraftingPanelText;
+
+/* ===========================================================
+   startup: hidden panel on the left edge
+   =========================================================== */
+pub fn setup_crafting_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left:    Val::Px(10.0),
+                top:     Val::Px(44.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            Visibility::Hidden,
+            CraftingPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::WHITE),
+                CraftingPanelText,
+            ));
+        });
+}
+
+/* ===========================================================
+   input: toggle, select, craft
+   =========================================================== */
+pub fn crafting_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut menu: ResMut<CraftingMenu>,
+    mut inv_q: Query<&mut Inventory, With<Player>>,
+) {
+    if keys.just_pressed(settings.keys.crafting) {
+        menu.open = !menu.open;
+    }
+    if !menu.open || RECIPES.is_empty() {
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        menu.selected = (menu.selected + RECIPES.len() - 1) % RECIPES.len();
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        menu.selected = (menu.selected + 1) % RECIPES.len();
+    }
+
+    if keys.just_pressed(KeyCode::Enter) {
+        let Ok(mut inv) = inv_q.get_single_mut() else { return };
+        let recipe = &RECIPES[menu.selected];
+        if !recipe.craft(&mut inv) {
+            info!("cannot craft {}: not enough materials", recipe.name);
+            return;
+        }
+        // tools don't stack – equip the pickaxe straight away
+        let (item, n) = recipe.output;
+        if let Some(tier) = PickaxeTier::from_item(item) {
+            inv.take(item, n);
+            inv.pick_tier = inv.pick_tier.max(tier);
+        }
+    }
+}
+
+/* ===========================================================
+   panel redraw
+   =========================================================== */
+pub fn crafting_panel_system(
+    menu: Res<CraftingMenu>,
+    inv_q: Query<&Inventory, With<Player>>,
+    mut panel_q: Query<&mut Visibility, With<CraftingPanel>>,
+    mut text_q: Query<&mut Text, With<CraftingPanelText>>,
+) {
+    let Ok(mut vis) = panel_q.get_single_mut() else { return };
+    *vis = if menu.open { Visibility::Visible } else { Visibility::Hidden };
+    if !menu.open {
+        return;
+    }
+
+    let (Ok(inv), Ok(mut text)) = (inv_q.get_single(), text_q.get_single_mut()) else {
+        return;
+    };
+
+    let mut out = format!("Crafting  (Up/Down, Enter)   pickaxe: {:?}\n", inv.pick_tier);
+    for (i, recipe) in RECIPES.iter().enumerate() {
+        let cursor = if i == menu.selected { ">" } else { " " };
+        let status = if recipe.affordable(inv) { "ok" } else { "--" };
+        let needs: Vec<String> = recipe
+            .inputs
+            .iter()
+            .map(|&(item, n)| format!("{n} {item:?} ({})", inv.count(item)))
+            .collect();
+        out.push_str(&format!(
+            "{cursor} [{status}] {} x{}  <-  {}\n",
+            recipe.name,
+            recipe.output.1,
+            needs.join(", "),
+        ));
+    }
+    text.0 = out;
+}
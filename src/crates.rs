@@ -0,0 +1,168 @@
+//! crates – breakable loot boxes in cavern rooms & the item pickups they drop
+//!
+//! Spots are picked during generation (`Terrain::crate_spots`); the entities
+//! are spawned once the world is in.  Crates sit above the tiles, never
+//! collide, and break to either the pickaxe (same reach / radius test as
+//! mining) or bullets (same box test as orcs).  Loot pops out as
+//! `ItemPickup`s that fall, rest on the ground and go into the inventory
+//! when the player walks over them.
+
+use bevy::input::ButtonInput;
+use bevy::input::gamepad::Gamepad;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::{Bullet, Crate, HeldItem, Inventory, ItemKind, ItemPickup, Player, Velocity};
+use crate::constants::*;
+use crate::gamepad::{pad_pressed, GamepadAim, PAD_USE};
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::player::{mining_falloff, MiningRadius};
+use crate::tile_stream::solid;
+use crate::world_gen::{tile_to_world_y, world_to_tile_y, Terrain};
+
+/// (item, min, max) – one roll per `CRATE_LOOT_ROLLS`
+const LOOT_TABLE: &[(ItemKind, u32, u32)] = &[
+    (ItemKind::Stone,           3, 8),
+    (ItemKind::Dirt,            3, 8),
+    (ItemKind::Obsidian,        1, 3),
+    (ItemKind::ReinforcedBlock, 1, 2),
+    (ItemKind::ObsidianBrick,   1, 1),
+];
+
+/* ===========================================================
+   on enter: one crate per generated spot
+   =========================================================== */
+pub fn spawn_crates(mut commands: Commands, terrain: Res<Terrain>) {
+    for &(x, y) in &terrain.crate_spots {
+        commands.spawn((
+            Sprite {
+                color: CRATE_COLOR,
+                custom_size: Some(Vec2::splat(tile_size())),
+                ..default()
+            },
+            Transform::from_xyz(x as f32 * tile_size(), tile_to_world_y(terrain.height, y), 7.0),
+            Crate { hp: CRATE_HP },
+        ));
+    }
+    info!("spawned {} crates", terrain.crate_spots.len());
+}
+
+/* ===========================================================
+   damage: pickaxe within reach, or a bullet inside the box
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn crate_damage_system(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
+    player_q: Query<(&Transform, &Inventory), With<Player>>,
+    bullets: Query<(Entity, &Transform, &Bullet)>,
+    mut crates: Query<(Entity, &Transform, &mut Crate)>,
+    mut pool: ResMut<ParticlePool>,
+    radius: Res<MiningRadius>,
+    mut commands: Commands,
+) {
+    /* mining: same reach + radius test as pickaxe_mining_system */
+    let mining = player_q.get_single().ok().and_then(|(tf, inv)| {
+        let using = mouse.pressed(MouseButton::Left) || pad_pressed(&pads, PAD_USE);
+        let world = aim.point?;
+        let in_reach = (world - tf.translation.truncate()).length_squared()
+            <= tiles(DIG_RADIUS).powi(2);
+        (inv.selected == HeldItem::Pickaxe && using && in_reach)
+            .then(|| (world, inv.pick_tier.speed()))
+    });
+
+    let half = tile_size() * 0.5;
+    for (e, tf, mut crate_) in &mut crates {
+        let pos = tf.translation.truncate();
+
+        if let Some((world, speed)) = mining {
+            let dist = (pos - world).length();
+            if dist < radius.0 {
+                crate_.hp -= CRATE_MINE_RATE * speed * mining_falloff(dist, radius.0)
+                    * time.delta_secs();
+            }
+        }
+        for (b_ent, b_tf, bullet) in &bullets {
+            let delta = (b_tf.translation.truncate() - pos).abs();
+            if delta.x <= half && delta.y <= half {
+                crate_.hp -= bullet.damage;
+                commands.entity(b_ent).despawn();
+            }
+        }
+
+        if crate_.hp <= 0.0 {
+            break_crate(&mut commands, &mut pool, pos);
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+/// splinter burst + a few loot stacks popping upward
+fn break_crate(commands: &mut Commands, pool: &mut ParticlePool, pos: Vec2) {
+    let mut rng = rand::thread_rng();
+    let origin = pos.extend(6.0);
+
+    for _ in 0..pool.scaled(DEBRIS_RATE * 2) {
+        let vel = Vec2::new(
+            rng.gen_range(DEBRIS_SPEED_X.clone()),
+            rng.gen_range(DEBRIS_SPEED_Y.clone()),
+        ) * 3.0;
+        pool.spawn(commands, ParticleKind::Debris, CRATE_COLOR, 2.5, origin, vel, DEBRIS_LIFETIME * 2.0);
+    }
+
+    for _ in 0..rng.gen_range(CRATE_LOOT_ROLLS) {
+        let (item, lo, hi) = LOOT_TABLE[rng.gen_range(0..LOOT_TABLE.len())];
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(1.0, 0.9, 0.4),
+                custom_size: Some(Vec2::splat(PICKUP_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(origin),
+            Velocity(Vec2::new(rng.gen_range(-60.0..60.0), rng.gen_range(80.0..160.0))),
+            ItemPickup { item, n: rng.gen_range(lo..=hi) },
+        ));
+    }
+}
+
+/* ===========================================================
+   pickups: fall, rest on the ground, collect on contact
+   =========================================================== */
+pub fn pickup_system(
+    time: Res<Time>,
+    terrain: Res<Terrain>,
+    mut commands: Commands,
+    mut player_q: Query<(&Transform, &mut Inventory), (With<Player>, Without<ItemPickup>)>,
+    mut q: Query<(Entity, &mut Transform, &mut Velocity, &ItemPickup)>,
+) {
+    let dt = time.delta_secs();
+    let player = player_q.get_single_mut().ok();
+    let (player_pos, mut inv) = match player {
+        Some((tf, inv)) => (Some(tf.translation.truncate()), Some(inv)),
+        None => (None, None),
+    };
+
+    for (e, mut tf, mut vel, pickup) in &mut q {
+        if let (Some(p), Some(inv)) = (player_pos, inv.as_mut()) {
+            if (tf.translation.truncate() - p).length_squared() < tiles(PICKUP_RADIUS).powi(2) {
+                inv.add(pickup.item, pickup.n);
+                commands.entity(e).despawn();
+                continue;
+            }
+        }
+
+        // resting pickups keep testing the tile below, so mining it out
+        // from under them drops them again
+        vel.0.y += GRAVITY * dt;
+        let next = tf.translation.truncate() + vel.0 * dt;
+        let tx   = (next.x / tile_size()).round() as i32;
+        let ty   = world_to_tile_y(terrain.height, next.y - PICKUP_SIZE * 0.5);
+        if solid(&terrain, tx, ty) {
+            vel.0 = Vec2::ZERO; // landed / resting
+        } else {
+            tf.translation = next.extend(tf.translation.z);
+        }
+    }
+}
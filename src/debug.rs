@@ -0,0 +1,147 @@
+//! debug toggles for testing – noclip, invincibility, tile grid
+//!
+//! F7 noclip (fly through tiles with WASD), F8 invincible (no orc, fall or
+//! lava damage), F2 tile grid (lines on tile edges across the view, the
+//! ActiveRect in yellow and the loaded chunk window in cyan).  Only
+//! available in debug builds or when started with `--debug`; a release
+//! build without the flag ignores the keys entirely.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::constants::*;
+use crate::tile_stream::LoadedWindow;
+use crate::world_gen::{ActiveRect, Terrain};
+
+#[derive(Resource, Default, Debug)]
+pub struct DebugFlags {
+    /// keys are live (debug build or `--debug`)
+    pub allowed:       bool,
+    pub noclip:        bool,
+    pub invincible:    bool,
+    pub grid:          bool,
+}
+
+impl DebugFlags {
+    pub fn from_args() -> Self {
+        let allowed = cfg!(debug_assertions) || std::env::args().any(|a| a == "--debug");
+        Self { allowed, ..default() }
+    }
+}
+
+/* ===========================================================
+   F7 / F8 / F2 toggles
+   =========================================================== */
+pub fn debug_toggle_system(keys: Res<ButtonInput<KeyCode>>, mut flags: ResMut<DebugFlags>) {
+    if !flags.allowed {
+        return;
+    }
+    let mut toggle = |key: KeyCode, name: &str, flag: fn(&mut DebugFlags) -> &mut bool| {
+        if keys.just_pressed(key) {
+            let on = flag(&mut flags);
+            *on = !*on;
+            info!("debug: {name} {}", if *on { "on" } else { "off" });
+        }
+    };
+    toggle(KeyCode::F7, "noclip",        |f| &mut f.noclip);
+    toggle(KeyCode::F8, "invincible",    |f| &mut f.invincible);
+    toggle(KeyCode::F2, "tile grid",     |f| &mut f.grid);
+}
+
+/* ===========================================================
+   F2 tile grid – one thin sprite per line, reused frame to frame;
+   lines sit on the rendered tile edges (sprites are centred on
+   `x · tile`, so edges are half a tile off)
+   =========================================================== */
+#[derive(Component)]
+pub struct DebugGridLine;
+
+/// world‑space edges of tile rows / columns (y grows down in tiles)
+#[inline]
+fn col_left(tx: i32) -> f32 {
+    (tx as f32 - 0.5) * tile_size()
+}
+#[inline]
+fn row_top(terrain: &Terrain, ty: i32) -> f32 {
+    (terrain.height as f32 - 0.5 - ty as f32) * tile_size()
+}
+
+/// four edges of the tile rectangle `min..=max` as (centre, size)
+fn outline(terrain: &Terrain, min: IVec2, max: IVec2, width: f32) -> [(Vec2, Vec2); 4] {
+    let (l, r) = (col_left(min.x), col_left(max.x + 1));
+    let (t, b) = (row_top(terrain, min.y), row_top(terrain, max.y + 1));
+    let mid = Vec2::new((l + r) * 0.5, (t + b) * 0.5);
+    [
+        (Vec2::new(mid.x, t), Vec2::new(r - l + width, width)),
+        (Vec2::new(mid.x, b), Vec2::new(r - l + width, width)),
+        (Vec2::new(l, mid.y), Vec2::new(width, t - b + width)),
+        (Vec2::new(r, mid.y), Vec2::new(width, t - b + width)),
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn debug_grid_system(
+    mut commands: Commands,
+    flags: Res<DebugFlags>,
+    terrain: Res<Terrain>,
+    active: Option<Res<ActiveRect>>,
+    loaded: Option<Res<LoadedWindow>>,
+    window_q: Query<&Window>,
+    cam_q: Query<(&Transform, &OrthographicProjection), (With<Camera>, Without<DebugGridLine>)>,
+    mut lines: Query<(Entity, &mut Transform, &mut Sprite), With<DebugGridLine>>,
+) {
+    let mut wanted: Vec<(Vec2, Vec2, Color)> = Vec::new();
+
+    if flags.grid {
+        if let (Ok((cam_tf, proj)), Ok(window)) = (cam_q.get_single(), window_q.get_single()) {
+            let px   = proj.scale; // one screen pixel in world units
+            let view = Vec2::new(window.width(), window.height()) * px;
+            let c    = cam_tf.translation.truncate();
+            let (lo, hi) = (c - view * 0.5, c + view * 0.5);
+
+            if tile_size() / px >= DEBUG_GRID_MIN_PX {
+                let x0 = (lo.x / tile_size() + 0.5).floor() as i32;
+                let x1 = (hi.x / tile_size() + 0.5).ceil() as i32;
+                for tx in x0..=x1 {
+                    wanted.push((Vec2::new(col_left(tx), c.y), Vec2::new(px, view.y), DEBUG_GRID_COLOR));
+                }
+                let y0 = (terrain.height as f32 - 0.5 - hi.y / tile_size()).floor() as i32;
+                let y1 = (terrain.height as f32 - 0.5 - lo.y / tile_size()).ceil() as i32;
+                for ty in y0..=y1 {
+                    wanted.push((Vec2::new(c.x, row_top(&terrain, ty)), Vec2::new(view.x, px), DEBUG_GRID_COLOR));
+                }
+            }
+
+            let width = DEBUG_RECT_WIDTH_PX * px;
+            if let Some(r) = active {
+                let (min, max) = (IVec2::new(r.min_x, r.min_y), IVec2::new(r.max_x, r.max_y));
+                wanted.extend(outline(&terrain, min, max, width).map(|(p, s)| (p, s, DEBUG_ACTIVE_COLOR)));
+            }
+            if let Some(w) = loaded {
+                let min = IVec2::new(w.origin_cx * CHUNK_WIDTH as i32, w.origin_cy * CHUNK_HEIGHT as i32);
+                let max = min + IVec2::new(w.cols * CHUNK_WIDTH as i32, w.rows * CHUNK_HEIGHT as i32) - 1;
+                wanted.extend(outline(&terrain, min, max, width).map(|(p, s)| (p, s, DEBUG_LOADED_COLOR)));
+            }
+        }
+    }
+
+    /* reuse what's there, spawn the shortfall, drop the rest */
+    let mut wanted = wanted.into_iter();
+    for (e, mut tf, mut sprite) in &mut lines {
+        match wanted.next() {
+            Some((pos, size, color)) => {
+                tf.translation = pos.extend(DEBUG_GRID_Z);
+                sprite.custom_size = Some(size);
+                sprite.color = color;
+            }
+            None => commands.entity(e).despawn(),
+        }
+    }
+    for (pos, size, color) in wanted {
+        commands.spawn((
+            Sprite { color, custom_size: Some(size), ..default() },
+            Transform::from_translation(pos.extend(DEBUG_GRID_Z)),
+            DebugGridLine,
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+//! difficulty presets – chosen in `settings.ron` or on the command line
+//!
+//!     cargo run -- --difficulty hard
+//!
+//! The multipliers scale enemy count, melee damage, aggro radius and HP.
+//! `--peaceful` (or `peaceful` in the settings file) starts with enemies
+//! switched off; the peaceful key flips it mid‑game (see `enemy.rs`).
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum DifficultyLevel {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct Difficulty {
+    pub level:       DifficultyLevel,
+    pub enemy_count: f32,
+    pub damage:      f32,
+    pub aggro:       f32,
+    pub hp:          f32,
+    /// no orcs spawn, the ones around are sent away and nothing attacks
+    pub peaceful:    bool,
+}
+
+impl Difficulty {
+    pub fn new(level: DifficultyLevel) -> Self {
+        let (enemy_count, damage, aggro, hp) = match level {
+            DifficultyLevel::Easy   => (0.5, 0.5, 0.75, 0.75),
+            DifficultyLevel::Normal => (1.0, 1.0, 1.00, 1.00),
+            DifficultyLevel::Hard   => (2.0, 2.0, 1.25, 1.50),
+        };
+        Self { level, enemy_count, damage, aggro, hp, peaceful: false }
+    }
+
+    /// reads `--difficulty <easy|normal|hard>`; anything else → Normal,
+    /// no flag at all → `fallback` (the settings file).  `--peaceful`
+    /// turns peaceful mode on regardless of `peaceful`
+    pub fn from_args(fallback: DifficultyLevel, peaceful: bool) -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let level = args
+            .iter()
+            .position(|a| a == "--difficulty")
+            .and_then(|i| args.get(i + 1))
+            .map(|v| match v.to_ascii_lowercase().as_str() {
+                "easy" => DifficultyLevel::Easy,
+                "hard" => DifficultyLevel::Hard,
+                _      => DifficultyLevel::Normal,
+            })
+            .unwrap_or(fallback);
+        let peaceful = peaceful || args.iter().any(|a| a == "--peaceful");
+        Self { peaceful, ..Self::new(level) }
+    }
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self::new(DifficultyLevel::Normal)
+    }
+}
@@ -1,4 +1,5 @@
-//! orc‑spawn, AI, and physics (enemies “sleep” when outside ActiveRect)
+//! orc‑spawn, AI, and physics (enemies “sleep” when outside ActiveRect),
+//! plus the burrowing orcs that dig their own tunnels
 
 use bevy::prelude::*;
 use rand::Rng;
@@ -6,71 +7,361 @@ use rand::Rng;
 use crate::{
     components::*,
     constants::*,
-    world_gen::{tile_to_world_y, world_to_tile_y, ActiveRect, Terrain},
-    tile_stream::solid,
+    world_gen::{tile_to_world_y, world_to_cell, world_to_tile_y, ActiveRect, Terrain},
+    player::{anim_rate, spawn_debris},
+    particles::ParticlePool,
+    tile_stream::{line_of_sight, liquid_at, solid, solid_for, solid_kind, Approach},
     visibility::VisibleTiles,
+    difficulty::Difficulty,
+    debug::DebugFlags,
+    settings::Settings,
+    walls::has_wall,
 };
-/// horizontal distance within which an orc can hit the player
-const STRIKE_RANGE: f32 = TILE_SIZE * 6.0;
-/// distance at which an orc will **start** swinging (may still miss)
-const ATTACK_RANGE: f32 = TILE_SIZE * 32.0;
+/// horizontal distance (tiles) within which an orc can hit the player
+const STRIKE_RANGE: f32 = 6.0;
+/// distance (tiles) at which an orc will **start** swinging (may still miss)
+const ATTACK_RANGE: f32 = 32.0;
+/// health‑bar size & offset in the orc's local (pre‑scale) space
+const HP_BAR_SIZE:   Vec2 = Vec2::new(14.0, 1.6);
+const HP_BAR_OFFSET: f32  = 14.0;
+/// "!" stroke + dot (local space), and its height above the health bar
+const ALERT_BAR_SIZE: Vec2 = Vec2::new(1.6, 4.5);
+const ALERT_DOT_SIZE: Vec2 = Vec2::splat(1.6);
+const ALERT_OFFSET:   f32  = HP_BAR_OFFSET + 7.0;
+
+/// (size_scale, hp ×, speed ×, spawn weight) – 1.0 is the regular orc,
+/// whose box matches the player's
+const ORC_VARIANTS: &[(f32, f32, f32, u32)] = &[
+    (0.75, 0.6, 1.35, 3), // small: quick and fragile
+    (1.00, 1.0, 1.00, 5), // regular
+    (1.40, 2.0, 0.70, 2), // large: slow and tough
+];
+
+/// weighted pick from `ORC_VARIANTS`
+fn roll_variant(rng: &mut impl Rng) -> (f32, f32, f32) {
+    let total: u32 = ORC_VARIANTS.iter().map(|v| v.3).sum();
+    let mut roll = rng.gen_range(0..total);
+    for &(size, hp, speed, weight) in ORC_VARIANTS {
+        if roll < weight {
+            return (size, hp, speed);
+        }
+        roll -= weight;
+    }
+    (1.0, 1.0, 1.0)
+}
+
+/* -----------------------------------------------------------
+   spawn columns outside the safe zone around the player start
+   (`w / 2`, see world_gen).  Random re‑rolls are capped; any
+   orcs still missing are placed directly in the allowed range
+   so the full count always spawns.
+   ----------------------------------------------------------- */
+fn spawn_columns(width: usize, count: usize, rng: &mut impl Rng) -> Vec<usize> {
+    let spawn_x  = width / 2;
+    let safe_min = spawn_x.saturating_sub(SPAWN_SAFE_RADIUS);
+    let safe_max = (spawn_x + SPAWN_SAFE_RADIUS).min(width - 1);
+    let outside  = |x: usize| x < safe_min || x > safe_max;
+
+    let mut cols     = Vec::with_capacity(count);
+    let mut attempts = 0usize;
+    while cols.len() < count && attempts < ENEMY_SPAWN_ATTEMPTS {
+        attempts += 1;
+        let x = rng.gen_range(0..width);
+        if outside(x) {
+            cols.push(x);
+        }
+    }
+
+    /* fallback: pick straight from the columns left and right of the zone */
+    let allowed = safe_min + (width - 1 - safe_max);
+    while cols.len() < count {
+        if allowed == 0 {
+            cols.push(rng.gen_range(0..width)); // world narrower than the zone
+            continue;
+        }
+        let k = rng.gen_range(0..allowed);
+        cols.push(if k < safe_min { k } else { safe_max + 1 + (k - safe_min) });
+    }
+    cols
+}
+
+/// orc sprite sheets, loaded once by `spawn_enemies` and reused by
+/// `spawn_timer_system`
+#[derive(Resource)]
+pub struct OrcAssets {
+    idle:   Handle<Image>,
+    attack: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+}
+
+/// one orc of a random variant (+ health‑bar children) standing on the
+/// surface of column `x_tile`; `base_hp` is the difficulty‑scaled HP of
+/// a regular orc
+fn spawn_orc(
+    commands: &mut Commands,
+    assets: &OrcAssets,
+    terrain: &Terrain,
+    x_tile: usize,
+    base_hp: i32,
+    rng: &mut impl Rng,
+) {
+    let (size_scale, hp_mul, speed_mul) = roll_variant(rng);
+    let half   = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) * 0.5 * size_scale;
+    let max_hp = ((base_hp as f32 * hp_mul).round() as i32).max(1);
+    let pos    = surface_pos(terrain, x_tile, half.y);
+    spawn_orc_body(
+        commands, assets, pos, size_scale, max_hp, ENEMY_SPEED * speed_mul, Color::WHITE,
+    );
+}
+
+/// the shared orc bundle (+ health‑bar children); returns the entity so
+/// special kinds can add their own components
+pub fn spawn_orc_body(
+    commands: &mut Commands,
+    assets: &OrcAssets,
+    pos: Vec2,
+    size_scale: f32,
+    max_hp: i32,
+    speed: f32,
+    tint: Color,
+) -> Entity {
+    let half = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) * 0.5 * size_scale;
+    let mut sprite = Sprite::from_atlas_image(
+        assets.idle.clone(),
+        TextureAtlas {
+            layout: assets.layout.clone(),
+            index: 0,
+        },
+    );
+    sprite.color = tint;
+
+    commands.spawn((
+        sprite,
+        Transform {
+            translation: pos.extend(10.0),
+            scale: Vec3::splat(ORC_SPRITE_SCALE * size_scale),
+            ..default()
+        },
+        Enemy {
+            grounded: false,
+            hp: max_hp,
+            max_hp,
+            recoil: 0.0,
+            attack_cooldown: 0.0,
+            idle_sheet: assets.idle.clone(),
+            attack_sheet: assets.attack.clone(),
+            hit_pending: false,
+            burn: 0.0,
+            home: pos,
+            aggro: false,
+            returning: false,
+            lost_sight: 0.0,
+            contact_cooldown: 0.0,
+            size_scale,
+            speed,
+        },
+        Collider { half },
+        CollisionLayers::ENEMY,
+        Velocity(Vec2::ZERO),
+        AnimationIndices { first: 0, last: 5 },
+        AnimationTimer(Timer::from_seconds(
+            0.12,
+            TimerMode::Repeating,
+        )),
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            Sprite {
+                color: Color::srgb(0.15, 0.15, 0.15),
+                custom_size: Some(HP_BAR_SIZE),
+                ..default()
+            },
+            Transform::from_xyz(0.0, HP_BAR_OFFSET, 1.0),
+            Visibility::Hidden,
+            EnemyHealthBar,
+        ));
+        parent.spawn((
+            Sprite {
+                color: Color::srgb(0.1, 0.85, 0.1),
+                custom_size: Some(HP_BAR_SIZE),
+                ..default()
+            },
+            Transform::from_xyz(0.0, HP_BAR_OFFSET, 1.1),
+            Visibility::Hidden,
+            EnemyHealthBarFill,
+        ));
+    })
+    .id()
+}
+
+/// world position of an orc (half‑height `half_h`) standing on the
+/// surface of column `x_tile`
+#[inline]
+fn surface_pos(terrain: &Terrain, x_tile: usize, half_h: f32) -> Vec2 {
+    let y_tile = terrain.height_map[x_tile];
+    Vec2::new(
+        x_tile as f32 * tile_size(),
+        tile_to_world_y(terrain.height, y_tile)
+            + tile_size() * 0.5
+            + half_h,
+    )
+}
 
 /* ===========================================================
-   start‑up: drop orcs on the surface
+   start‑up: drop orcs and throwers on the surface, bury the burrowers
    =========================================================== */
 pub fn spawn_enemies(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     terrain: Res<Terrain>,
+    difficulty: Res<Difficulty>,
 ) {
-    let sheet = asset_server.load("textures/orc_sheet.png");
-    let attack_sheet = asset_server.load("textures/Orc-Attack01.png");
     let layout =
         TextureAtlasLayout::from_grid(UVec2::new(100, 100), 6, 1, None, None);
-    let layout_handle = atlas_layouts.add(layout);
+    let assets = OrcAssets {
+        idle:   asset_server.load("textures/orc_sheet.png"),
+        attack: asset_server.load("textures/Orc-Attack01.png"),
+        layout: atlas_layouts.add(layout),
+    };
+
+    // the sheets stay loaded so leaving peaceful mode can spawn again
+    if difficulty.peaceful {
+        commands.insert_resource(assets);
+        return;
+    }
+
+    let count   = (ENEMY_COUNT as f32 * difficulty.enemy_count).round() as usize;
+    let base_hp = (ENEMY_MAX_HP as f32 * difficulty.hp).round() as i32;
 
     let mut rng = rand::thread_rng();
-    for _ in 0..64 {
-        let x_tile = rng.gen_range(0..terrain.width);
-        let y_tile = terrain.height_map[x_tile];
-
-        let pos = Vec2::new(
-            x_tile as f32 * TILE_SIZE,
-            tile_to_world_y(terrain.height, y_tile)
-                + TILE_SIZE * 0.5
-                + PLAYER_HEIGHT * 0.5,
+    for x_tile in spawn_columns(terrain.width, count, &mut rng) {
+        spawn_orc(&mut commands, &assets, &terrain, x_tile, base_hp, &mut rng);
+    }
+
+    let burrowers = (BURROWER_COUNT as f32 * difficulty.enemy_count).round() as usize;
+    for x_tile in spawn_columns(terrain.width, burrowers, &mut rng) {
+        let y_tile = (terrain.height_map[x_tile] + rng.gen_range(BURROWER_DEPTH))
+            .min(terrain.height - 2);
+        let pos = Vec2::new(x_tile as f32 * tile_size(), tile_to_world_y(terrain.height, y_tile));
+        let e = spawn_orc_body(
+            &mut commands, &assets, pos, 1.0, base_hp, BURROWER_SPEED, BURROWER_TINT,
         );
+        commands
+            .entity(e)
+            .insert(Burrower { dig_budget: BURROWER_DIG_BURST, flee: 0.0, last_hp: base_hp });
+    }
 
-        commands.spawn((
-            Sprite::from_atlas_image(
-                sheet.clone(),
-                TextureAtlas {
-                    layout: layout_handle.clone(),
-                    index: 0,
-                },
-            ),
-            Transform {
-                translation: pos.extend(10.0),
-                scale: Vec3::splat(1.8),
-                ..default()
-            },
-        Enemy {
-            grounded: false,
-            hp: 100,
-            recoil: 0.0,
-            attack_cooldown: 0.0,
-            idle_sheet: sheet.clone(),
-            attack_sheet: attack_sheet.clone(),
-            hit_pending: false,
-        },
-            Velocity(Vec2::ZERO),
-            AnimationIndices { first: 0, last: 5 },
-            AnimationTimer(Timer::from_seconds(
-                0.12,
-                TimerMode::Repeating,
-            )),
-        ));
+    let throwers = (THROWER_COUNT as f32 * difficulty.enemy_count).round() as usize;
+    for x_tile in spawn_columns(terrain.width, throwers, &mut rng) {
+        let half = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) * 0.5;
+        let pos  = surface_pos(&terrain, x_tile, half.y);
+        let e = spawn_orc_body(
+            &mut commands, &assets, pos, 1.0, base_hp, ENEMY_SPEED, THROWER_TINT,
+        );
+        commands.entity(e).insert(Thrower { cooldown: THROWER_INTERVAL });
+    }
+    commands.insert_resource(assets);
+}
+
+/* ===========================================================
+   reinforcements – every ENEMY_SPAWN_INTERVAL a new orc lands
+   just left or right of the ActiveRect (off‑screen), as long as
+   the population is below the difficulty‑scaled cap and no
+   background wall stands where it would land
+   =========================================================== */
+pub fn spawn_timer_system(
+    time: Res<Time>,
+    mut since_last: Local<f32>,
+    mut commands: Commands,
+    assets: Option<Res<OrcAssets>>,
+    rect: Res<ActiveRect>,
+    terrain: Res<Terrain>,
+    difficulty: Res<Difficulty>,
+    enemies: Query<(), With<Enemy>>,
+) {
+    if difficulty.peaceful {
+        return;
+    }
+    *since_last += time.delta_secs();
+    if *since_last < ENEMY_SPAWN_INTERVAL {
+        return;
+    }
+    *since_last = 0.0;
+
+    let Some(assets) = assets else { return };
+    let cap = (ENEMY_COUNT as f32 * difficulty.enemy_count).round() as usize;
+    if enemies.iter().count() >= cap {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    let x = if rng.gen_bool(0.5) {
+        rect.min_x - ENEMY_SPAWN_MARGIN
+    } else {
+        rect.max_x + ENEMY_SPAWN_MARGIN
+    };
+    if x < 0 || x >= terrain.width as i32 {
+        return; // that side is past the world edge – try again next tick
+    }
+    // walled‑in spots are safe from drop‑ins (see walls.rs)
+    let stand = terrain.height_map[x as usize] as i32 - 1;
+    if has_wall(&terrain, x, stand) || has_wall(&terrain, x, stand - 1) {
+        return;
+    }
+
+    let base_hp = (ENEMY_MAX_HP as f32 * difficulty.hp).round() as i32;
+    spawn_orc(&mut commands, &assets, &terrain, x as usize, base_hp, &mut rng);
+}
+
+/* ===========================================================
+   peaceful toggle – switching on sends every hostile orc away
+   (followers stay, an awake warlord freezes – see boss.rs);
+   switching off lets `spawn_timer_system` refill the world
+   =========================================================== */
+pub fn peaceful_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut difficulty: ResMut<Difficulty>,
+    mut commands: Commands,
+    enemies: Query<Entity, (With<Enemy>, Without<Follower>, Without<Boss>)>,
+) {
+    if keys.just_pressed(settings.keys.peaceful) {
+        difficulty.peaceful = !difficulty.peaceful;
+        info!("peaceful mode {}", if difficulty.peaceful { "on" } else { "off" });
+    }
+    if !difficulty.peaceful {
+        return;
+    }
+    for e in &enemies {
+        commands.entity(e).despawn_recursive();
+    }
+}
+
+/* ===========================================================
+   floating health bars – hidden at full HP; otherwise they
+   inherit the orc's visibility (see enemy_visibility_system)
+   =========================================================== */
+pub fn enemy_health_bar_system(
+    enemies: Query<(&Enemy, &Children), Changed<Enemy>>,
+    mut bars: Query<&mut Visibility, (With<EnemyHealthBar>, Without<EnemyHealthBarFill>)>,
+    mut fills: Query<(&mut Visibility, &mut Transform), With<EnemyHealthBarFill>>,
+) {
+    for (enemy, children) in &enemies {
+        let pct  = (enemy.hp as f32 / enemy.max_hp as f32).clamp(0.0, 1.0);
+        let show = if pct < 1.0 { Visibility::Inherited } else { Visibility::Hidden };
+
+        for &child in children.iter() {
+            if let Ok(mut vis) = bars.get_mut(child) {
+                *vis = show;
+            }
+            if let Ok((mut vis, mut tf)) = fills.get_mut(child) {
+                *vis = show;
+                // shrink toward the left edge of the bar
+                tf.scale.x       = pct;
+                tf.translation.x = -HP_BAR_SIZE.x * (1.0 - pct) * 0.5;
+            }
+        }
     }
 }
 
@@ -86,8 +377,7 @@ pub fn update_active_tag_system(
     let rect = *rect_res; // copy to avoid repeated deref
 
     for (e, tf, has_tag) in &mut q {
-        let tx = (tf.translation.x / TILE_SIZE).floor() as i32;
-        let ty = world_to_tile_y(terrain.height, tf.translation.y);
+        let (tx, ty) = world_to_cell(terrain.height, tf.translation.truncate());
 
         let inside = tx >= rect.min_x
             && tx <= rect.max_x
@@ -108,19 +398,31 @@ pub fn update_active_tag_system(
 
 /* ===========================================================
    AI (runs only for Active enemies)
+
+   Aggro needs line of sight (the player's FOV is symmetric, so
+   "the orc's tile is visible" means the orc can see the player).
+   An aggroed orc gives up and walks home when it strays beyond
+   ENEMY_LEASH_RADIUS or loses sight for ENEMY_LOS_TIMEOUT.
    =========================================================== */
 pub fn enemy_ai_system(
+    time: Res<Time>,
+    mut commands: Commands,
     mut enemies: Query<
-        (&mut Velocity, &mut Transform, &Enemy),
-        (With<Active>, Without<Player>),
+        (Entity, &mut Velocity, &mut Transform, &mut Enemy),
+        (With<Active>, Without<Player>, Without<Burrower>, Without<Boss>, Without<Follower>),
     >,
     player_q: Query<&Transform, With<Player>>,
+    vis: Res<VisibleTiles>,
+    terrain: Res<Terrain>,
+    difficulty: Res<Difficulty>,
 ) {
     let Ok(player_tf) = player_q.get_single() else { return };
     let player_pos = player_tf.translation.truncate();
+    let aggro_radius = tiles(AGGRO_RADIUS) * difficulty.aggro;
+    let dt = time.delta_secs();
     let mut rng = rand::thread_rng();
 
-    for (mut vel, mut tf, enemy) in &mut enemies {
+    for (e, mut vel, mut tf, mut enemy) in &mut enemies {
         let pos = tf.translation.truncate();
         // pause AI steering during knock‑back
         if enemy.recoil > 0.0 {
@@ -135,19 +437,55 @@ pub fn enemy_ai_system(
         let to_player = player_pos - pos;
         let dist = to_player.length();
 
-        /* ---- aggro zone ---- */
-        if dist < AGGRO_RADIUS {
+        let (tx, ty) = world_to_cell(terrain.height, pos);
+        let in_sight = tx >= 0 && ty >= 0 && vis.set.contains(&(tx as usize, ty as usize));
+        let leashed  = (pos - enemy.home).length() > tiles(ENEMY_LEASH_RADIUS);
+
+        /* ---- aggro bookkeeping ---- */
+        if enemy.aggro {
+            enemy.lost_sight = if in_sight { 0.0 } else { enemy.lost_sight + dt };
+            if leashed || enemy.lost_sight > ENEMY_LOS_TIMEOUT {
+                enemy.aggro     = false;
+                enemy.returning = true;
+            }
+        } else if !enemy.returning && !leashed && in_sight && dist < aggro_radius {
+            enemy.aggro      = true;
+            enemy.lost_sight = 0.0;
+            spawn_alert_mark(&mut commands, e);
+        }
+
+        /* ---- walk home after losing aggro ---- */
+        if enemy.returning {
+            let dx = enemy.home.x - pos.x;
+            if dx.abs() <= tile_size() {
+                enemy.returning = false;
+                vel.0.x = 0.0;
+            } else if hazard_ahead(&terrain, pos, dx.signum()) {
+                vel.0.x = 0.0; // wait at the edge rather than wade in
+            } else {
+                vel.0.x = enemy.speed * dx.signum();
+                tf.scale.x = dx.signum() * tf.scale.x.abs();
+                // hop over steps on the way back
+                if enemy.grounded && rng.gen_bool(0.02) {
+                    vel.0.y = JUMP_SPEED;
+                }
+            }
+            continue;
+        }
+
+        /* ---- aggro: chase the player ---- */
+        if enemy.aggro {
             let dx = to_player.x;
 
-            if dx.abs() > ENEMY_KEEP_AWAY {
-                vel.0.x = ENEMY_SPEED * dx.signum();
+            if dx.abs() > tiles(ENEMY_KEEP_AWAY) && !hazard_ahead(&terrain, pos, dx.signum()) {
+                vel.0.x = enemy.speed * dx.signum();
                 tf.scale.x = dx.signum() * tf.scale.x.abs();
             } else {
                 vel.0.x = 0.0;
             }
 
             if enemy.grounded
-                && to_player.y > TILE_SIZE * 0.5
+                && to_player.y > tile_size() * 0.5
                 && rng.gen_bool(0.15)
             {
                 vel.0.y = JUMP_SPEED;
@@ -158,33 +496,102 @@ pub fn enemy_ai_system(
         /* ---- idle wandering ---- */
         if rng.gen_bool(0.02) {
             vel.0.x = if rng.gen_bool(0.5) {
-                -ENEMY_SPEED
+                -enemy.speed
             } else {
-                ENEMY_SPEED
+                enemy.speed
             };
             tf.scale.x = vel.0.x.signum() * tf.scale.x.abs();
         }
+        // wandering into lava: turn round instead
+        if vel.0.x != 0.0 && hazard_ahead(&terrain, pos, vel.0.x.signum()) {
+            vel.0.x = -vel.0.x;
+            tf.scale.x = vel.0.x.signum() * tf.scale.x.abs();
+        }
         if enemy.grounded && rng.gen_bool(0.005) {
             vel.0.y = JUMP_SPEED;
         }
     }
 }
 
+/// pops a short‑lived "!" above the orc's health bar
+fn spawn_alert_mark(commands: &mut Commands, orc: Entity) {
+    let mark = commands
+        .spawn((
+            Sprite { color: ALERT_MARK_COLOR, custom_size: Some(ALERT_BAR_SIZE), ..default() },
+            Transform::from_xyz(0.0, ALERT_OFFSET, 2.0).with_scale(Vec3::splat(ALERT_MARK_POP)),
+            AlertMark { life: ALERT_MARK_LIFE },
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Sprite { color: ALERT_MARK_COLOR, custom_size: Some(ALERT_DOT_SIZE), ..default() },
+                Transform::from_xyz(0.0, -(ALERT_BAR_SIZE.y * 0.5 + ALERT_DOT_SIZE.y), 0.0),
+            ));
+        })
+        .id();
+    commands.entity(orc).add_child(mark);
+}
+
+/* ===========================================================
+   alert marks – pop in, settle, fade over the last third
+   =========================================================== */
+pub fn alert_mark_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut Transform, &mut AlertMark, &Children)>,
+    mut sprites: Query<&mut Sprite>,
+) {
+    let dt = time.delta_secs();
+    for (e, mut tf, mut mark, children) in &mut q {
+        mark.life -= dt;
+        if mark.life <= 0.0 {
+            commands.entity(e).despawn_recursive();
+            continue;
+        }
+        let age = 1.0 - mark.life / ALERT_MARK_LIFE;
+        let pop = 1.0 + (ALERT_MARK_POP - 1.0) * (1.0 - (age * 4.0).min(1.0));
+        tf.scale = Vec3::splat(pop);
+
+        let alpha = (mark.life / (ALERT_MARK_LIFE / 3.0)).min(1.0);
+        for part in std::iter::once(e).chain(children.iter().copied()) {
+            if let Ok(mut sprite) = sprites.get_mut(part) {
+                sprite.color.set_alpha(alpha);
+            }
+        }
+    }
+}
+
+/// would the next step in `dir` put the orc in lava?  Looks at the
+/// column one tile ahead, from body height down to the first solid
+/// tile (at most ENEMY_HAZARD_PROBE tiles), so a short drop into a
+/// pool counts too.
+fn hazard_ahead(terrain: &Terrain, pos: Vec2, dir: f32) -> bool {
+    let (tx, ty) = world_to_cell(terrain.height, pos + Vec2::X * dir * tile_size());
+    for d in 0..=ENEMY_HAZARD_PROBE {
+        if liquid_at(terrain, tx, ty + d) {
+            return true;
+        }
+        if solid(terrain, tx, ty + d) {
+            return false;
+        }
+    }
+    false
+}
+
 /* ===========================================================
    physics (gravity + tile collision) only for Active enemies
    =========================================================== */
 pub fn enemy_physics_system(
     time: Res<Time>,
     mut q: Query<
-        (&mut Transform, &mut Velocity, &mut Enemy),
-        With<Active>,
+        (&mut Transform, &mut Velocity, &mut Enemy, &Collider, &CollisionLayers),
+        (With<Active>, Without<Burrower>),
     >,
     terrain: Res<Terrain>,
 ) {
     let dt = time.delta_secs();
-    let half = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) / 2.0;
 
-    for (mut tf, mut vel, mut enemy) in &mut q {
+    for (mut tf, mut vel, mut enemy, collider, &layers) in &mut q {
+        let half = collider.half;
         vel.0.y += GRAVITY * dt;
         let step_dt = dt / COLLISION_STEPS as f32;
         enemy.grounded = false;
@@ -195,7 +602,7 @@ pub fn enemy_physics_system(
                 let new_x = tf.translation.x + vel.0.x * step_dt;
                 let dir = vel.0.x.signum();
                 let probe_x = new_x + dir * half.x;
-                let tx = (probe_x / TILE_SIZE).floor() as i32;
+                let tx = (probe_x / tile_size()).floor() as i32;
 
                 let y_top = world_to_tile_y(
                     terrain.height,
@@ -208,7 +615,7 @@ pub fn enemy_physics_system(
                 let (y_min, y_max) =
                     if y_top <= y_bot { (y_top, y_bot) } else { (y_bot, y_top) };
 
-                if (y_min..=y_max).any(|ty| solid(&terrain, tx, ty)) {
+                if (y_min..=y_max).any(|ty| solid_for(&terrain, tx, ty, layers, Approach::Side)) {
                     vel.0.x = 0.0;
                 } else {
                     tf.translation.x = new_x;
@@ -223,11 +630,16 @@ pub fn enemy_physics_system(
                 let ty = world_to_tile_y(terrain.height, probe_y);
 
                 let x_left =
-                    ((tf.translation.x - half.x + 0.1) / TILE_SIZE).floor() as i32;
+                    ((tf.translation.x - half.x + 0.1) / tile_size()).floor() as i32;
                 let x_right =
-                    ((tf.translation.x + half.x - 0.1) / TILE_SIZE).floor() as i32;
+                    ((tf.translation.x + half.x - 0.1) / tile_size()).floor() as i32;
 
-                if (x_left..=x_right).any(|tx| solid(&terrain, tx, ty)) {
+                let approach = if vel.0.y < 0.0 {
+                    Approach::Falling { feet: tf.translation.y - half.y, drop_through: false }
+                } else {
+                    Approach::Rising
+                };
+                if (x_left..=x_right).any(|tx| solid_for(&terrain, tx, ty, layers, approach)) {
                     if vel.0.y < 0.0 {
                         enemy.grounded = true;
                     }
@@ -244,34 +656,165 @@ pub fn enemy_physics_system(
     }
 }
 
+/* ===========================================================
+   burrowers – no gravity, no tile collision.  They steer straight
+   at the player (or away and down after a hit) and dig out the
+   soft tiles their body would overlap, at most BURROWER_DIG_RATE
+   tiles per second; anything too hard stops that axis only, so
+   they slide along obsidian.  Swings come from enemy_attack_system
+   once they've surfaced within BURROWER_STOP_DIST.
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn burrower_system(
+    time: Res<Time>,
+    mut terrain: ResMut<Terrain>,
+    mut pool: ResMut<ParticlePool>,
+    mut commands: Commands,
+    difficulty: Res<Difficulty>,
+    player_q: Query<&Transform, (With<Player>, Without<Burrower>)>,
+    mut q: Query<
+        (&mut Transform, &mut Velocity, &Enemy, &mut Burrower, &Collider),
+        With<Active>,
+    >,
+) {
+    let Ok(player_tf) = player_q.get_single() else { return };
+    let player_pos   = player_tf.translation.truncate();
+    let aggro_radius = tiles(AGGRO_RADIUS) * difficulty.aggro;
+    let dt = time.delta_secs();
+
+    for (mut tf, mut vel, enemy, mut burrow, collider) in &mut q {
+        burrow.dig_budget =
+            (burrow.dig_budget + BURROWER_DIG_RATE * dt).min(BURROWER_DIG_BURST);
+        if enemy.hp < burrow.last_hp {
+            burrow.flee = BURROWER_FLEE_TIME;
+        }
+        burrow.last_hp = enemy.hp;
+        burrow.flee    = (burrow.flee - dt).max(0.0);
+
+        let to_player = player_pos - tf.translation.truncate();
+        let dist      = to_player.length();
+        let dir = if burrow.flee > 0.0 {
+            (Vec2::NEG_Y - to_player.normalize_or_zero()).normalize_or_zero()
+        } else if dist < aggro_radius && dist > tiles(BURROWER_STOP_DIST) {
+            to_player / dist
+        } else {
+            Vec2::ZERO
+        };
+        vel.0 = dir * enemy.speed;
+        if dir.x != 0.0 {
+            tf.scale.x = dir.x.signum() * tf.scale.x.abs();
+        }
+
+        // one axis at a time so a hard tile only blocks its own component
+        for axis in [Vec2::X, Vec2::Y] {
+            let step = vel.0 * axis * dt;
+            if step == Vec2::ZERO {
+                continue;
+            }
+            let next = tf.translation.truncate() + step;
+            let budget = &mut burrow.dig_budget;
+            if dig_out(&mut terrain, &mut commands, &mut pool, budget, next, collider.half) {
+                tf.translation.x = next.x;
+                tf.translation.y = next.y;
+            }
+        }
+    }
+}
+
+/// clear every tile a body (half‑extents `half`) centred at `pos` would
+/// overlap; `false` (nothing dug) if one is too hard, off the map, or the
+/// dig budget can't cover them all yet
+fn dig_out(
+    terrain: &mut Terrain,
+    commands: &mut Commands,
+    pool: &mut ParticlePool,
+    budget: &mut f32,
+    pos: Vec2,
+    half: Vec2,
+) -> bool {
+    let x_left  = ((pos.x - half.x + 0.1) / tile_size()).floor() as i32;
+    let x_right = ((pos.x + half.x - 0.1) / tile_size()).floor() as i32;
+    let y_top   = world_to_tile_y(terrain.height, pos.y + half.y - 0.1);
+    let y_bot   = world_to_tile_y(terrain.height, pos.y - half.y + 0.1);
+
+    let mut dig = Vec::new();
+    for ty in y_top.min(y_bot)..=y_top.max(y_bot) {
+        for tx in x_left..=x_right {
+            if tx < 0 || ty < 0 || tx >= terrain.width as i32 || ty >= terrain.height as i32 {
+                return false;
+            }
+            let kind = terrain.tiles[ty as usize][tx as usize].kind;
+            if !solid_kind(kind) {
+                continue;
+            }
+            if kind.hardness() > BURROWER_MAX_HARDNESS {
+                return false;
+            }
+            dig.push((tx as usize, ty as usize));
+        }
+    }
+    if dig.len() as f32 > *budget {
+        return false; // wait for the budget to refill
+    }
+
+    *budget -= dig.len() as f32;
+    for (x, y) in dig {
+        spawn_debris(commands, pool, terrain, x, y);
+        terrain.clear(x, y);
+    }
+    true
+}
+
 /* ===========================================================
    reuse player animation code
    =========================================================== */
 pub fn enemy_attack_system(
     time: Res<Time>,
     mut enemies: Query<
-        (&mut Enemy, &Transform, &mut Sprite),
-        (With<Enemy>, With<Active>),
+        (&mut Enemy, &Transform, &mut Sprite, &Collider),
+        (With<Enemy>, With<Active>, Without<Follower>, Without<Thrower>),
     >,
-    mut player_q: Query<(&Transform, &mut Health), With<Player>>,
+    mut player_q: Query<(&Transform, &mut Health, &Collider), With<Player>>,
+    difficulty: Res<Difficulty>,
+    terrain: Res<Terrain>,
+    debug: Res<DebugFlags>,
 ) {
+    if difficulty.peaceful {
+        return;
+    }
     let dt = time.delta_secs();
-    let Ok((player_tf, mut health)) = player_q.get_single_mut() else { return };
+    let Ok((player_tf, mut health, player_box)) = player_q.get_single_mut() else { return };
+    // swings still play out, they just don't land
+    let dmg_scale = if debug.invincible { 0.0 } else { difficulty.damage };
     let player_pos = player_tf.translation.truncate();
-    let half_player = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) / 2.0;
 
-    for (mut enemy, tf, mut sprite) in &mut enemies {
+    for (mut enemy, tf, mut sprite, collider) in &mut enemies {
         /* ---------- timers ---------- */
         if enemy.attack_cooldown > 0.0 {
             enemy.attack_cooldown -= dt;
         }
+        if enemy.contact_cooldown > 0.0 {
+            enemy.contact_cooldown -= dt;
+        }
 
         /* ---------- ranges ---------- */
         let delta = (player_pos - tf.translation.truncate()).abs();
         let in_anim_range =
-            delta.x <= ATTACK_RANGE && delta.y <= half_player.y;
+            delta.x <= tiles(ATTACK_RANGE) && delta.y <= collider.half.y;
         let in_hit_range =
-            delta.x <= STRIKE_RANGE && delta.y <= half_player.y;
+            delta.x <= tiles(STRIKE_RANGE) && delta.y <= collider.half.y;
+        // AABBs overlap when the centres are closer than the summed
+        // half‑extents on both axes
+        let reach    = player_box.half + collider.half;
+        let touching = delta.x < reach.x && delta.y < reach.y;
+
+        /* ---------- contact damage, independent of the swing ---------- */
+        if touching && enemy.contact_cooldown <= 0.0 {
+            health.current =
+                (health.current - ENEMY_CONTACT_DAMAGE * dmg_scale).max(0.0);
+            health.last_damage = 0.0;
+            enemy.contact_cooldown = ENEMY_CONTACT_COOLDOWN;
+        }
 
         /* ---------- start a swing ---------- */
         if in_anim_range && enemy.attack_cooldown <= 0.0 {
@@ -291,8 +834,12 @@ pub fn enemy_attack_system(
         if enemy.hit_pending {
             if let Some(atlas) = sprite.texture_atlas.as_ref() {
                 if atlas.index == 3 { // sheet index 3 == “number 4”
-                if in_hit_range {
-                    health.current = (health.current - 10.0).max(0.0);
+                // no hitting through walls
+                if in_hit_range
+                    && line_of_sight(&terrain, tf.translation.truncate(), player_pos)
+                {
+                    health.current =
+                        (health.current - ENEMY_MELEE_DAMAGE * dmg_scale).max(0.0);
                     health.last_damage = 0.0;
                 }
                     enemy.hit_pending = false; // strike resolved
@@ -318,8 +865,7 @@ pub fn enemy_attack_system(
     let (w, h) = (terrain.width as i32, terrain.height as i32);
 
     for (tf, mut visib) in &mut q {
-        let tx = (tf.translation.x / TILE_SIZE).floor() as i32;
-        let ty = world_to_tile_y(terrain.height, tf.translation.y);
+        let (tx, ty) = world_to_cell(terrain.height, tf.translation.truncate());
 
         if tx < 0 || tx >= w || ty < 0 || ty >= h {
             *visib = Visibility::Hidden;
@@ -336,15 +882,27 @@ pub fn enemy_attack_system(
 }
 
 
+/// walk cycle paced by horizontal speed; an orc standing still holds its
+/// first frame.  Swings always play at full rate – the hit lands on a frame.
 pub fn animate_enemy_system(
     time: Res<Time>,
     mut q: Query<
-        (&AnimationIndices, &mut AnimationTimer, &mut Sprite),
-        (With<Enemy>, With<Active>),
+        (&AnimationIndices, &mut AnimationTimer, &mut Sprite, &Velocity, &Enemy),
+        With<Active>,
     >,
 ) {
-    for (indices, mut timer, mut sprite) in &mut q {
-        if timer.tick(time.delta()).just_finished() {
+    for (indices, mut timer, mut sprite, vel, enemy) in &mut q {
+        let swinging = sprite.image == enemy.attack_sheet;
+        let rate = if swinging { 1.0 } else { anim_rate(vel.0.x, enemy.speed) };
+
+        if !swinging && vel.0.x.abs() < ANIM_STILL_SPEED {
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                atlas.index = indices.first;
+            }
+            timer.reset();
+            continue;
+        }
+        if timer.tick(time.delta().mul_f32(rate)).just_finished() {
             if let Some(atlas) = sprite.texture_atlas.as_mut() {
                 atlas.index = if atlas.index == indices.last {
                     indices.first
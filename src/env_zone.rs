@@ -0,0 +1,56 @@
+//! environment zones – altitude bands with their own gravity and drag
+//!
+//! The air up by the sky islands is thin: above `SKY_ZONE_TOP_FRAC` of the
+//! map height gravity is scaled down and a little drag keeps jumps floaty
+//! instead of just taller.  Bands are measured in map‑height fractions so
+//! they follow `GenParams`‑sized worlds, and neighbouring bands are
+//! smooth‑stepped over `ENV_ZONE_BLEND` tiles so crossing one never jolts
+//! the player's velocity.
+
+use bevy::prelude::*;
+
+use crate::constants::*;
+use crate::world_gen::world_to_tile_y;
+
+/// one altitude band; applies from `top_frac` of the map height downward
+/// until the next band takes over
+#[derive(Clone, Copy, Debug)]
+pub struct EnvZone {
+    pub top_frac: f32,
+    /// multiplier on `GRAVITY`
+    pub gravity:  f32,
+    /// vertical velocity lost per second (1 / s)
+    pub drag:     f32,
+}
+
+/// bands ordered top to bottom; the first must start at 0.0
+#[derive(Resource, Clone, Debug)]
+pub struct EnvZones(pub Vec<EnvZone>);
+
+impl Default for EnvZones {
+    fn default() -> Self {
+        Self(vec![
+            EnvZone { top_frac: 0.0,               gravity: SKY_ZONE_GRAVITY, drag: SKY_ZONE_DRAG },
+            EnvZone { top_frac: SKY_ZONE_TOP_FRAC, gravity: 1.0,              drag: 0.0 },
+        ])
+    }
+}
+
+impl EnvZones {
+    /// (gravity multiplier, drag) at world height `world_y`, blended across
+    /// band boundaries
+    pub fn at(&self, terrain_h: usize, world_y: f32) -> (f32, f32) {
+        let Some(first) = self.0.first() else { return (1.0, 0.0) };
+        let row = world_to_tile_y(terrain_h, world_y) as f32;
+
+        let (mut gravity, mut drag) = (first.gravity, first.drag);
+        for zone in &self.0[1..] {
+            let edge = zone.top_frac * terrain_h as f32;
+            let t    = ((row - edge) / ENV_ZONE_BLEND + 0.5).clamp(0.0, 1.0);
+            let t    = t * t * (3.0 - 2.0 * t); // smoothstep
+            gravity += (zone.gravity - gravity) * t;
+            drag    += (zone.drag    - drag)    * t;
+        }
+        (gravity, drag)
+    }
+}
@@ -0,0 +1,174 @@
+//! orc follower – beat an orc down to `TAME_HP_FRAC` of its health, stand
+//! next to it and press *interact*: it switches sides.
+//!
+//! A tamed orc keeps its `Enemy` body (physics, animation, health bar,
+//! lava) but gains a `Follower` tag, which takes it out of
+//! `enemy_ai_system`, `enemy_attack_system` and the player's bullets.
+//! `follower_ai_system` steers it instead: pick the nearest untamed orc in
+//! sight within `FOLLOWER_AGGRO_RADIUS` and hit it, otherwise trail the
+//! player.  Only one follower at a time; a follower that falls too far
+//! behind is pulled back to the player.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::camera::ScreenShake;
+use crate::components::*;
+use crate::constants::*;
+use crate::merchant::TradeMenu;
+use crate::particles::ParticlePool;
+use crate::player::{spawn_blood, spawn_corpse, spawn_hit_blood};
+use crate::settings::Settings;
+use crate::stats::Stats;
+use crate::tile_stream::{line_of_sight, solid};
+use crate::world_gen::{world_to_cell, Terrain};
+
+/* ===========================================================
+   taming – interact next to a badly hurt orc
+   =========================================================== */
+pub fn tame_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    trade: Res<TradeMenu>,
+    mut commands: Commands,
+    player_q: Query<&Transform, With<Player>>,
+    followers: Query<(), With<Follower>>,
+    mut orcs: Query<
+        (Entity, &Transform, &mut Enemy, &mut Sprite),
+        (With<Active>, Without<Player>, Without<Follower>, Without<Boss>, Without<Burrower>),
+    >,
+) {
+    // the merchant owns the key while the player is next to it
+    if !keys.just_pressed(settings.keys.interact) || trade.near || !followers.is_empty() {
+        return;
+    }
+    let Ok(player_tf) = player_q.get_single() else { return };
+    let player_pos = player_tf.translation.truncate();
+
+    let nearest = orcs
+        .iter_mut()
+        .filter(|(_, _, enemy, _)| (enemy.hp as f32) <= enemy.max_hp as f32 * TAME_HP_FRAC)
+        .map(|(e, tf, enemy, sprite)| {
+            (tf.translation.truncate().distance(player_pos), e, enemy, sprite)
+        })
+        .filter(|(d, ..)| *d <= tiles(TAME_RANGE))
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    let Some((_, e, mut enemy, mut sprite)) = nearest else { return };
+
+    enemy.hp           = enemy.max_hp; // patched up as a welcome
+    enemy.aggro        = false;
+    enemy.returning    = false;
+    enemy.hit_pending  = false;
+    enemy.recoil       = 0.0;
+    sprite.color       = FOLLOWER_TINT;
+    sprite.image       = enemy.idle_sheet.clone();
+    commands.entity(e).insert(Follower { strike_cooldown: 0.0 });
+    info!("an orc joins you");
+}
+
+/* ===========================================================
+   follower AI – fight nearby orcs, otherwise stay with the player
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn follower_ai_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    terrain: Res<Terrain>,
+    mut shake: ResMut<ScreenShake>,
+    mut pool: ResMut<ParticlePool>,
+    mut stats: ResMut<Stats>,
+    player_q: Query<&Transform, With<Player>>,
+    mut followers: Query<
+        (&mut Transform, &mut Velocity, &Enemy, &mut Sprite, &mut Follower),
+        Without<Player>,
+    >,
+    mut foes: Query<
+        (Entity, &Transform, &mut Enemy, &mut Velocity, &Sprite, &Collider),
+        (Without<Player>, Without<Follower>),
+    >,
+) {
+    let Ok(player_tf) = player_q.get_single() else { return };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+    let mut rng = rand::thread_rng();
+
+    for (mut tf, mut vel, me, mut sprite, mut follower) in &mut followers {
+        follower.strike_cooldown = (follower.strike_cooldown - dt).max(0.0);
+        if follower.strike_cooldown < FOLLOWER_STRIKE_COOLDOWN - FOLLOWER_SWING_TIME {
+            sprite.image = me.idle_sheet.clone();
+        }
+
+        /* ---- left behind: catch up at once ---- */
+        let pos = tf.translation.truncate();
+        if pos.distance(player_pos) > tiles(FOLLOWER_TELEPORT_DIST) {
+            tf.translation.x = player_pos.x;
+            tf.translation.y = player_pos.y;
+            vel.0 = Vec2::ZERO;
+            continue;
+        }
+        if me.recoil > 0.0 {
+            continue; // knocked back – let physics play out
+        }
+
+        /* ---- nearest untamed orc it can see ---- */
+        let target = foes
+            .iter()
+            .map(|(e, ftf, ..)| (e, ftf.translation.truncate()))
+            .filter(|(_, p)| p.distance(pos) <= tiles(FOLLOWER_AGGRO_RADIUS))
+            .filter(|(_, p)| line_of_sight(&terrain, pos, *p))
+            .min_by(|a, b| a.1.distance(pos).total_cmp(&b.1.distance(pos)));
+
+        let (goal, keep) = match target {
+            Some((_, p)) => (p, tiles(FOLLOWER_STRIKE_RANGE) * 0.5),
+            None         => (player_pos, tiles(FOLLOWER_FOLLOW_DIST)),
+        };
+        let to_goal = goal - pos;
+
+        /* ---- walk, and hop over steps or up toward the goal ---- */
+        if to_goal.x.abs() > keep {
+            let dir = to_goal.x.signum();
+            vel.0.x    = me.speed * dir;
+            tf.scale.x = dir * tf.scale.x.abs();
+
+            let (ahead, feet) =
+                world_to_cell(terrain.height, pos + Vec2::new(dir, -0.5) * tile_size());
+            if me.grounded && solid(&terrain, ahead, feet) {
+                vel.0.y = JUMP_SPEED;
+            }
+        } else {
+            vel.0.x = 0.0;
+        }
+        if me.grounded && to_goal.y > tile_size() * 0.5 && rng.gen_bool(0.15) {
+            vel.0.y = JUMP_SPEED;
+        }
+
+        /* ---- swing at the target ---- */
+        let Some((foe_ent, foe_pos)) = target else { continue };
+        if follower.strike_cooldown > 0.0
+            || (foe_pos - pos).abs().x > tiles(FOLLOWER_STRIKE_RANGE)
+            || (foe_pos - pos).abs().y > tile_size() * 2.0
+        {
+            continue;
+        }
+        let Ok((_, foe_tf, mut foe, mut foe_vel, foe_sprite, foe_box)) = foes.get_mut(foe_ent) else {
+            continue;
+        };
+
+        follower.strike_cooldown = FOLLOWER_STRIKE_COOLDOWN;
+        sprite.image = me.attack_sheet.clone();
+        tf.scale.x   = (foe_pos.x - pos.x).signum() * tf.scale.x.abs();
+
+        foe.hp     -= FOLLOWER_DAMAGE;
+        foe.recoil  = RECOIL_TIME;
+        foe_vel.0.x = (foe_pos.x - pos.x).signum() * HIT_KNOCKBACK;
+        spawn_hit_blood(&mut commands, &mut pool, foe_tf.translation);
+
+        if foe.hp <= 0 {
+            spawn_blood(&mut commands, &mut pool, foe_tf.translation + Vec3::Z * 2.0);
+            spawn_corpse(&mut commands, foe_sprite, foe_tf, foe_box.half);
+            commands.entity(foe_ent).despawn_recursive(); // + health bar
+            shake.add(SHAKE_ORC_DEATH * foe.size_scale);
+            stats.orcs_killed += 1;
+        }
+    }
+}
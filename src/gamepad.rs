@@ -0,0 +1,206 @@
+//! gamepad control scheme + shared aim point
+//!
+//! Left stick moves, South jumps, RT = jet‑pack, RB = dash,
+//! LT = use held item, Start = its secondary use (RMB),
+//! West/North/East/D‑pad‑up/right/left, LB and the
+//! left stick click pick inventory slots 1–8,
+//! D‑pad‑down digs straight down (tunnel).
+//! The right stick steers a world‑space aim point; without a pad the
+//! aim point simply follows the mouse cursor.
+
+use bevy::input::gamepad::{Gamepad, GamepadButton};
+use bevy::prelude::*;
+
+use crate::components::Player;
+use crate::constants::*;
+use crate::world_gen::{world_to_tile, Terrain};
+
+/* ===========================================================
+   button mapping
+   =========================================================== */
+pub const PAD_JUMP:   GamepadButton = GamepadButton::South;
+pub const PAD_JET:    GamepadButton = GamepadButton::RightTrigger2;
+pub const PAD_DASH:   GamepadButton = GamepadButton::RightTrigger;
+pub const PAD_USE:    GamepadButton = GamepadButton::LeftTrigger2;
+pub const PAD_ALT_USE: GamepadButton = GamepadButton::Start;
+pub const PAD_SLOT_1: GamepadButton = GamepadButton::West;
+pub const PAD_SLOT_2: GamepadButton = GamepadButton::North;
+pub const PAD_SLOT_3: GamepadButton = GamepadButton::East;
+pub const PAD_SLOT_4: GamepadButton = GamepadButton::DPadUp;
+pub const PAD_SLOT_5: GamepadButton = GamepadButton::DPadRight;
+pub const PAD_SLOT_6: GamepadButton = GamepadButton::DPadLeft;
+pub const PAD_SLOT_7: GamepadButton = GamepadButton::LeftTrigger;
+pub const PAD_SLOT_8: GamepadButton = GamepadButton::LeftThumb;
+pub const PAD_SLOT_9: GamepadButton = GamepadButton::RightThumb;
+pub const PAD_SLOT_10: GamepadButton = GamepadButton::Select;
+pub const PAD_TUNNEL: GamepadButton = GamepadButton::DPadDown;
+
+/* ===========================================================
+   aim resource
+   =========================================================== */
+/// world‑space point the pickaxe / gun / build highlight aim at
+#[derive(Resource, Default)]
+pub struct GamepadAim {
+    /// `None` when neither a pad nor the mouse gives a usable aim
+    pub point: Option<Vec2>,
+    /// last right‑stick offset from the player (kept while the stick rests)
+    offset: Vec2,
+}
+
+/// in‑bounds tile under the aim point – the one shared cursor → tile
+/// conversion for mining, building, walls, signs and the bucket
+pub fn cursor_to_tile(aim: &GamepadAim, terrain: &Terrain) -> Option<(usize, usize)> {
+    let (tx, ty) = world_to_tile(terrain.height, aim.point?);
+    (tx >= 0 && ty >= 0 && tx < terrain.width as i32 && ty < terrain.height as i32)
+        .then_some((tx as usize, ty as usize))
+}
+
+/* ===========================================================
+   helpers
+   =========================================================== */
+#[inline]
+pub fn pad_pressed(pads: &Query<&Gamepad>, button: GamepadButton) -> bool {
+    pads.iter().any(|p| p.pressed(button))
+}
+
+#[inline]
+pub fn pad_just_pressed(pads: &Query<&Gamepad>, button: GamepadButton) -> bool {
+    pads.iter().any(|p| p.just_pressed(button))
+}
+
+/// left‑stick X of the first connected pad, dead‑zone filtered
+#[inline]
+pub fn pad_move_x(pads: &Query<&Gamepad>) -> f32 {
+    let Some(pad) = pads.iter().next() else { return 0.0 };
+    let x = pad.left_stick().x;
+    if x.abs() < GAMEPAD_DEADZONE { 0.0 } else { x }
+}
+
+/// left‑stick Y of the first connected pad (up = +), dead‑zone filtered
+#[inline]
+pub fn pad_move_y(pads: &Query<&Gamepad>) -> f32 {
+    let Some(pad) = pads.iter().next() else { return 0.0 };
+    let y = pad.left_stick().y;
+    if y.abs() < GAMEPAD_DEADZONE { 0.0 } else { y }
+}
+
+/* ===========================================================
+   aim update – runs before every system that consumes the aim
+   =========================================================== */
+pub fn gamepad_aim_system(
+    mut aim: ResMut<GamepadAim>,
+    pads: Query<&Gamepad>,
+    windows: Query<&Window>,
+    cam_q: Query<(&Camera, &GlobalTransform)>,
+    player_q: Query<&Transform, With<Player>>,
+) {
+    let Ok(player_tf) = player_q.get_single() else {
+        aim.point = None;
+        return;
+    };
+    let player_pos = player_tf.translation.truncate();
+
+    /* ---------- pad connected: right stick steers ---------- */
+    if let Some(pad) = pads.iter().next() {
+        let stick = pad.right_stick();
+        if stick.length() >= GAMEPAD_DEADZONE {
+            aim.offset = stick.clamp_length_max(1.0) * tiles(GAMEPAD_AIM_REACH);
+        } else if aim.offset == Vec2::ZERO {
+            aim.offset = Vec2::X * tiles(GAMEPAD_AIM_REACH) * 0.5;
+        }
+        aim.point = Some(player_pos + aim.offset);
+        return;
+    }
+
+    /* ---------- no pad: fall back to the mouse cursor ---------- */
+    let window = windows.single();
+    let (cam, cam_tf) = cam_q.single();
+    aim.point = window
+        .cursor_position()
+        .and_then(|c| cam.viewport_to_world_2d(cam_tf, c).ok());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world_gen::{tile_to_world_y, ChangedTiles, GenCounts, Tile, TileKind};
+    use noise::Perlin;
+
+    const W: usize = 8;
+    const H: usize = 6;
+
+    fn terrain() -> Terrain {
+        let tile = Tile {
+            kind: TileKind::Air, wall: None, visible: true, explored: true,
+            mine_time: 0.0, base_rgb: Vec3::ZERO, display_brightness: 1.0, decal: None,
+        };
+        Terrain {
+            tiles:           vec![vec![tile; W]; H],
+            sprite_entities: vec![None; W * H],
+            changed_tiles:   ChangedTiles::new(W, H),
+            kind_changed:    Vec::new(),
+            free_sprites:    Vec::new(),
+            width:           W,
+            height:          H,
+            height_map:      vec![0; W],
+            color_noise:     Perlin::new(0),
+            temp_noise:      Perlin::new(0),
+            crate_spots:     Vec::new(),
+            spawner_spots:   Vec::new(),
+            gen_counts:      GenCounts::default(),
+            lava:            Default::default(),
+        }
+    }
+
+    fn aim_at(point: Vec2) -> GamepadAim {
+        GamepadAim { point: Some(point), offset: Vec2::ZERO }
+    }
+
+    fn centre(x: usize, y: usize) -> Vec2 {
+        Vec2::new(x as f32 * tile_size(), tile_to_world_y(H, y))
+    }
+
+    #[test]
+    fn cursor_picks_the_sprite_under_it() {
+        let terrain = terrain();
+        for y in 0..H {
+            for x in 0..W {
+                assert_eq!(cursor_to_tile(&aim_at(centre(x, y)), &terrain), Some((x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_at_the_map_corners() {
+        let terrain = terrain();
+        let half = tile_size() * 0.5 - 0.01;
+        assert_eq!(cursor_to_tile(&aim_at(Vec2::ZERO), &terrain), Some((0, H - 1)));
+        assert_eq!(
+            cursor_to_tile(&aim_at(centre(0, 0) + Vec2::new(-half, half)), &terrain),
+            Some((0, 0)),
+        );
+        assert_eq!(
+            cursor_to_tile(&aim_at(centre(W - 1, H - 1) + Vec2::new(half, -half)), &terrain),
+            Some((W - 1, H - 1)),
+        );
+    }
+
+    #[test]
+    fn cursor_off_the_map_is_none() {
+        let terrain = terrain();
+        let half = tile_size() * 0.5 + 0.01;
+        // left of column 0 / below the last row (negative world x / y)
+        assert_eq!(cursor_to_tile(&aim_at(Vec2::new(-half, 0.0)), &terrain), None);
+        assert_eq!(cursor_to_tile(&aim_at(Vec2::new(0.0, -half)), &terrain), None);
+        assert_eq!(cursor_to_tile(&aim_at(Vec2::new(-half, -half)), &terrain), None);
+        // right of the last column / above row 0
+        let right = centre(W - 1, 0) + Vec2::new(half, 0.0);
+        assert_eq!(cursor_to_tile(&aim_at(right), &terrain), None);
+        assert_eq!(cursor_to_tile(&aim_at(centre(0, 0) + Vec2::new(0.0, half)), &terrain), None);
+    }
+
+    #[test]
+    fn no_aim_point_is_none() {
+        assert_eq!(cursor_to_tile(&GamepadAim::default(), &terrain()), None);
+    }
+}
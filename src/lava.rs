@@ -0,0 +1,110 @@
+//! lava hazards – damage over time & pulsing glow
+//!
+//! Lava is generated inside the obsidian layer (see `world_gen.rs`).
+//! It is non‑solid, burns anything standing in it and lights its
+//! surroundings (`brightness()` + the FOV light field).  There is no
+//! liquid simulation yet, so lava does not flow and has nothing to
+//! quench it.
+
+use bevy::prelude::*;
+
+use crate::camera::ScreenShake;
+use crate::debug::DebugFlags;
+use crate::components::{Collider, Enemy, Health, Player};
+use crate::constants::*;
+use crate::player::spawn_corpse;
+use crate::stats::Stats;
+use crate::tile_stream::LoadedWindow;
+use crate::world_gen::{world_to_cell, Terrain, TileKind, LAVA_RGB};
+
+/* -----------------------------------------------------------
+   helper: does the body (half‑extents `half`) centred at `pos`
+   touch a lava tile?
+   ----------------------------------------------------------- */
+#[inline]
+fn in_lava(terrain: &Terrain, pos: Vec2, half: Vec2) -> bool {
+    [pos, pos - Vec2::Y * (half.y - 0.1)].iter().any(|&p| {
+        let (tx, ty) = world_to_cell(terrain.height, p);
+        tx >= 0
+            && ty >= 0
+            && tx < terrain.width as i32
+            && ty < terrain.height as i32
+            && terrain.tiles[ty as usize][tx as usize].kind == TileKind::Lava
+    })
+}
+
+/* ===========================================================
+   damage tick – scaled by delta‑time so it is frame‑rate independent
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn lava_damage_system(
+    time: Res<Time>,
+    terrain: Res<Terrain>,
+    mut commands: Commands,
+    mut player_q: Query<(&Transform, &mut Health, &Collider), With<Player>>,
+    mut enemy_q: Query<(Entity, &Transform, &mut Enemy, &Collider, &Sprite), Without<Player>>,
+    mut shake: ResMut<ScreenShake>,
+    mut stats: ResMut<Stats>,
+    debug: Res<DebugFlags>,
+) {
+    let dt = time.delta_secs();
+
+    if let Ok((tf, mut health, collider)) = player_q.get_single_mut() {
+        if !debug.invincible && in_lava(&terrain, tf.translation.truncate(), collider.half) {
+            health.current = (health.current - LAVA_DPS * dt).max(0.0);
+            health.last_damage = 0.0;
+        }
+    }
+
+    for (e, tf, mut enemy, collider, sprite) in &mut enemy_q {
+        if !in_lava(&terrain, tf.translation.truncate(), collider.half) {
+            continue;
+        }
+        // `hp` is integral – bank the fractional part until it adds up
+        enemy.burn += LAVA_DPS * dt;
+        let whole = enemy.burn.floor();
+        enemy.burn -= whole;
+        enemy.hp -= whole as i32;
+
+        if enemy.hp <= 0 {
+            spawn_corpse(&mut commands, sprite, tf, collider.half);
+            commands.entity(e).despawn_recursive();
+            shake.add(SHAKE_ORC_DEATH * enemy.size_scale);
+            stats.orcs_killed += 1;
+        }
+    }
+}
+
+/* ===========================================================
+   pulsing orange tint (only lava inside the streamed window)
+   =========================================================== */
+pub fn lava_pulse_system(
+    time: Res<Time>,
+    mut since_last: Local<f32>,
+    loaded: Option<Res<LoadedWindow>>,
+    mut terrain: ResMut<Terrain>,
+) {
+    *since_last += time.delta_secs();
+    if *since_last < LAVA_PULSE_INTERVAL {
+        return;
+    }
+    *since_last = 0.0;
+    let Some(loaded) = loaded else { return };
+
+    let (w, h) = (terrain.width as i32, terrain.height as i32);
+    let min_x  = (loaded.origin_cx * CHUNK_WIDTH as i32).clamp(0, w - 1);
+    let max_x  = ((loaded.origin_cx + loaded.cols) * CHUNK_WIDTH as i32 - 1).clamp(0, w - 1);
+    let min_y  = (loaded.origin_cy * CHUNK_HEIGHT as i32).clamp(0, h - 1);
+    let max_y  = ((loaded.origin_cy + loaded.rows) * CHUNK_HEIGHT as i32 - 1).clamp(0, h - 1);
+
+    let t = time.elapsed_secs();
+    let lava: Vec<_> = terrain
+        .lava_in((min_x as usize, max_x as usize), (min_y as usize, max_y as usize))
+        .collect();
+    for (x, y) in lava {
+        // neighbouring tiles drift slightly out of phase
+        let phase = t * LAVA_PULSE_SPEED + (x + y) as f32 * 0.35;
+        terrain.tiles[y][x].base_rgb = LAVA_RGB * (1.0 + phase.sin() * LAVA_PULSE_AMOUNT);
+        terrain.changed_tiles.push((x, y));
+    }
+}
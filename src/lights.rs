@@ -0,0 +1,145 @@
+//! coloured point lights – soft glow sprites over the FOV darkness
+//!
+//! Brightness (`brightness()` + the FOV light field) only says how lit a
+//! tile is; this adds colour on top.  Every `LightEmitter` inside the
+//! active rect gets a radial‑gradient glow sprite at high Z, and lava
+//! is lit in coarse `LAVA_GLOW_CELL` blocks (one glow per block, not
+//! per tile).  Bevy's sprite pipeline only alpha‑blends, so the gradient is
+//! kept faint to read as an additive tint.  Glow sprites are pooled: spare
+//! ones are hidden, never despawned.  There are no torches yet – anything
+//! that should glow only needs a `LightEmitter`.
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::components::{LightEmitter, Player};
+use crate::constants::*;
+use crate::world_gen::{tile_to_world_y, world_to_tile_y, ActiveRect, Terrain, TileKind};
+
+/// pooled glow sprite
+#[derive(Component)]
+pub struct Glow;
+
+/// the shared soft radial gradient every glow is drawn with
+#[derive(Resource)]
+pub struct GlowTexture(pub Handle<Image>);
+
+/* ===========================================================
+   startup: bake the gradient (white, alpha falls off to the rim)
+   =========================================================== */
+pub fn setup_glow_texture(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let size = GLOW_TEXTURE_SIZE;
+    let half = size as f32 * 0.5;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let d = Vec2::new(x as f32 + 0.5 - half, y as f32 + 0.5 - half).length() / half;
+            let a = (1.0 - d).clamp(0.0, 1.0).powi(2);
+            data.extend_from_slice(&[255, 255, 255, (a * 255.0) as u8]);
+        }
+    }
+    let image = Image::new(
+        Extent3d { width: size, height: size, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    commands.insert_resource(GlowTexture(images.add(image)));
+}
+
+/// the player carries a faint aura
+pub fn add_player_light_system(mut commands: Commands, q: Query<Entity, Added<Player>>) {
+    if let Ok(player) = q.get_single() {
+        commands.entity(player).insert(LightEmitter {
+            color:  PLAYER_LIGHT_COLOR,
+            radius: tiles(PLAYER_LIGHT_RADIUS),
+        });
+    }
+}
+
+/* ===========================================================
+   place glows for this frame's emitters inside the active rect
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn glow_system(
+    time: Res<Time>,
+    mut since_last: Local<f32>,
+    mut lava_cells: Local<Vec<Vec2>>,
+    mut commands: Commands,
+    texture: Option<Res<GlowTexture>>,
+    terrain: Res<Terrain>,
+    rect: Option<Res<ActiveRect>>,
+    emitters: Query<(&Transform, &LightEmitter), Without<Glow>>,
+    mut glows: Query<(&mut Transform, &mut Sprite, &mut Visibility), With<Glow>>,
+) {
+    let (Some(texture), Some(rect)) = (texture, rect) else { return };
+
+    /* ---- lava blocks – rescanned on a timer or when the rect moves ---- */
+    *since_last += time.delta_secs();
+    if *since_last >= GLOW_LAVA_REFRESH || rect.is_changed() {
+        *since_last = 0.0;
+        lava_cells.clear();
+        let cell = LAVA_GLOW_CELL;
+        for by in (rect.min_y..=rect.max_y).step_by(cell) {
+            for bx in (rect.min_x..=rect.max_x).step_by(cell) {
+                let lit = (by..(by + cell as i32).min(rect.max_y + 1)).any(|y| {
+                    (bx..(bx + cell as i32).min(rect.max_x + 1)).any(|x| {
+                        terrain.tiles[y as usize][x as usize].kind == TileKind::Lava
+                    })
+                });
+                if lit {
+                    let centre = bx as f32 + cell as f32 * 0.5 - 0.5;
+                    let row    = by as usize + cell / 2;
+                    lava_cells.push(Vec2::new(
+                        centre * tile_size(),
+                        tile_to_world_y(terrain.height, row.min(terrain.height - 1)),
+                    ));
+                }
+            }
+        }
+    }
+
+    /* ---- this frame's lights: emitters in the rect, then lava ---- */
+    let in_rect = |p: Vec2| {
+        let tx = (p.x / tile_size()).round() as i32;
+        let ty = world_to_tile_y(terrain.height, p.y);
+        tx >= rect.min_x && tx <= rect.max_x && ty >= rect.min_y && ty <= rect.max_y
+    };
+    let lights = emitters
+        .iter()
+        .map(|(tf, light)| (tf.translation.truncate(), light.color, light.radius))
+        .filter(|&(p, _, _)| in_rect(p))
+        .chain(lava_cells.iter().map(|&p| (p, LAVA_LIGHT_COLOR, tiles(LAVA_GLOW_RADIUS))))
+        .take(GLOW_MAX);
+
+    /* ---- reuse pooled sprites, spawn the shortfall, hide the rest ---- */
+    let mut pool = glows.iter_mut();
+    for (pos, color, radius) in lights {
+        let size = Some(Vec2::splat(radius * 2.0));
+        match pool.next() {
+            Some((mut tf, mut sprite, mut vis)) => {
+                tf.translation     = pos.extend(GLOW_Z);
+                sprite.color       = color;
+                sprite.custom_size = size;
+                *vis = Visibility::Visible;
+            }
+            None => {
+                commands.spawn((
+                    Sprite {
+                        image: texture.0.clone(),
+                        color,
+                        custom_size: size,
+                        ..default()
+                    },
+                    Transform::from_translation(pos.extend(GLOW_Z)),
+                    Glow,
+                ));
+            }
+        }
+    }
+    for (_, _, mut vis) in pool {
+        *vis = Visibility::Hidden;
+    }
+}
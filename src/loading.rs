@@ -0,0 +1,114 @@
+//! loading screen – world generation on a background task
+//!
+//! Entered from the options screen once the `GenParams` are settled.
+//! `generate_terrain` runs on the `AsyncComputeTaskPool` so the window stays
+//! responsive; a progress bar tracks its per‑mille counter.  When the task
+//! finishes the `Terrain` resource is inserted and the app enters
+//! `AppState::InGame`, which spawns the player, orcs and FOV state.
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use crate::world_gen::{generate_terrain, GenParams, Terrain};
+
+#[derive(States, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum AppState {
+    #[default]
+    Options,
+    Loading,
+    InGame,
+}
+
+/// the running generator and its per‑mille progress
+#[derive(Resource)]
+pub struct WorldGenTask {
+    task:     Task<Terrain>,
+    progress: Arc<AtomicU32>,
+}
+
+#[derive(Component)]
+pub struct LoadingScreen;
+
+#[derive(Component)]
+pub struct LoadingBarFill;
+
+/* ===========================================================
+   on enter: kick off generation + show the loading screen
+   =========================================================== */
+pub fn start_world_generation(mut commands: Commands, params: Res<GenParams>) {
+    let progress = Arc::new(AtomicU32::new(0));
+    let shared   = progress.clone();
+    let params   = *params;
+    let task     = AsyncComputeTaskPool::get()
+        .spawn(async move { generate_terrain(&params, &shared) });
+    commands.insert_resource(WorldGenTask { task, progress });
+
+    commands
+        .spawn((
+            Node {
+                width:           Val::Percent(100.0),
+                height:          Val::Percent(100.0),
+                flex_direction:  FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items:     AlignItems::Center,
+                row_gap:         Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.08)),
+            GlobalZIndex(100), // above the HUD spawned at startup
+            LoadingScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Generating world…"),
+                TextFont { font_size: 24.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width:  Val::Px(400.0),
+                        height: Val::Px(20.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width:  Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.1, 0.8, 0.2)),
+                        LoadingBarFill,
+                    ));
+                });
+        });
+}
+
+/* ===========================================================
+   while loading: advance the bar, hand over when done
+   =========================================================== */
+pub fn loading_progress_system(
+    mut commands: Commands,
+    mut gen: ResMut<WorldGenTask>,
+    mut fill_q: Query<&mut Node, With<LoadingBarFill>>,
+    screen_q: Query<Entity, With<LoadingScreen>>,
+    mut next: ResMut<NextState<AppState>>,
+) {
+    let permille = gen.progress.load(Ordering::Relaxed).min(1000);
+    if let Ok(mut node) = fill_q.get_single_mut() {
+        node.width = Val::Percent(permille as f32 / 10.0);
+    }
+
+    let Some(terrain) = block_on(future::poll_once(&mut gen.task)) else { return };
+    commands.insert_resource(terrain);
+    commands.remove_resource::<WorldGenTask>();
+    for e in &screen_q {
+        commands.entity(e).despawn_recursive();
+    }
+    next.set(AppState::InGame);
+}
@@ -3,44 +3,91 @@
 //! Updated for inventory, pickaxe mining, gun shooting, debris & bullets.
 //! Works with **Bevy 0.15**, Rust 1.77.
 
+mod backpack;
+mod booster;
+mod boss;
+mod bucket;
 mod camera;
+mod compass;
 mod components;
 mod constants;
+mod crafting;
+mod crates;
+mod cracks;
+mod debug;
+mod difficulty;
 mod enemy;
+mod env_zone;
+mod follower;
+mod gamepad;
+mod lava;
+mod lights;
+mod merchant;
+mod miner;
+mod loading;
+mod mirror;
+mod options;
+mod overlay;
+mod particles;
 mod player;
+mod portal;
+mod render;
+mod scanner;
+mod settings;
+mod signs;
+mod spawner;
+mod stats;
+mod support;
+mod thrower;
+mod tile_atlas;
 mod world_gen;          // ← generation
+mod world_stats;
 mod tile_stream;        // ← streaming / runtime
+mod vegetation;
+mod vignette;
 mod visibility;
+mod walls;
+mod weather;
 
 use bevy::diagnostic::{
     EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin,
 };
 use bevy::ecs::schedule::common_conditions::resource_changed;
-use bevy::input::ButtonInput;
+use bevy::input::{ButtonInput, InputSystem};
 use bevy::prelude::*;
-use bevy::window::{MonitorSelection, PrimaryWindow, WindowMode};
+use bevy::ui::UiSystem;
+use bevy::window::{PrimaryWindow, WindowMode};
 
 /* generation + streaming APIs ------------------------------------------- */
-use world_gen::{generate_world_and_player, ActiveRect};
+use world_gen::{spawn_player, ActiveRect, GenParams};
+use loading::AppState;
+use settings::Settings;
 use tile_stream::{
-    shift_loaded_window_system, redraw_changed_tiles_system, stream_tiles_system,
-    sync_tile_sprite_entities_system, update_active_rect_system,
+    brightness_fade_system, shift_loaded_window_system, redraw_changed_tiles_system,
+    stream_tiles_system, sync_tile_sprite_entities_system, update_active_rect_system,
+    FadingTiles, RedrawStats,
 };
 
 /* game‑logic helpers ---------------------------------------------------- */
-use camera::camera_follow_system;
+use camera::{camera_follow_system, free_cam_system, CameraSnap, FreeCam, ScreenShake};
+use particles::ParticlePool;
+use gamepad::{gamepad_aim_system, GamepadAim};
+use crafting::{crafting_panel_system, crafting_system, setup_crafting_panel, CraftingMenu};
 use player::{
     animate_player_system, bullet_update_system, cursor_highlight_system,
-    dash_start_system, dash_update_system, debris_update_system,
-    exhaust_update_system, gun_shoot_system, inventory_input_system,
-    physics_and_collision_system, pickaxe_mining_system, place_stone_system,
-    player_input_system, health_regen_system,
+    corpse_update_system, dash_start_system, dash_update_system, debris_update_system,
+    gib_update_system, exhaust_update_system, gun_charge_system, gun_shoot_system,
+    inventory_input_system,
+    undo_system, BuildDrag, BuildStamp, ChangeHistory, MiningMode, MiningRadius,
+    physics_and_collision_system, pickaxe_mining_system, place_ladder_system, place_stone_system,
+    player_input_system, player_anim_state_system, health_regen_system, tunnel_system,
 };
 use components::{
     Health, HealthBarFill, HeldItem, Inventory, InventorySlot, Player, ToolbarText,
 };
 use visibility::{
-    detect_player_tile_change_system, recompute_fov_system, startup_fov_system,
+    detect_player_tile_change_system, lighting_input_system, recompute_fov_system,
+    startup_fov_system, LightingConfig,
 };
 
 /* ------------------------------------------------------------------------ */
@@ -51,20 +98,18 @@ fn setup_camera(mut commands: Commands) {
 }
 
 /* ------------------------------------------------------------------------ */
-/* Escape = toggle borderless‑fullscreen                                    */
+/* Escape (rebindable) = toggle borderless‑fullscreen, saved right away     */
 /* ------------------------------------------------------------------------ */
 fn toggle_fullscreen(
     keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<Settings>,
     mut window_q: Query<&mut Window, With<PrimaryWindow>>,
 ) {
-    if keys.just_pressed(KeyCode::Escape) {
+    if keys.just_pressed(settings.keys.fullscreen) {
         let mut window = window_q.single_mut();
-        window.mode = match window.mode {
-            WindowMode::Windowed => {
-                WindowMode::BorderlessFullscreen(MonitorSelection::Primary)
-            }
-            _ => WindowMode::Windowed,
-        };
+        settings.fullscreen = window.mode == WindowMode::Windowed;
+        window.mode = settings.window_mode();
+        settings.save();
     }
 }
 
@@ -73,7 +118,7 @@ fn toggle_fullscreen(
 /* ------------------------------------------------------------------------ */
 fn setup_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
     // ── inventory slots ────────────────────────────────────────────────
-    for i in 0..3 {
+    for i in 0..10 {
         commands.spawn((
             Node {
                 position_type: PositionType::Absolute,
@@ -84,7 +129,7 @@ fn setup_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ..default()
             },
             BackgroundColor(Color::srgb(0.0, 1.0, 0.0)),   // bright green
-            InventorySlot(i + 1),                          // 1, 2, 3
+            InventorySlot(i + 1),                          // 1 … 9, then 0
         ));
     }
 
@@ -137,6 +182,13 @@ fn update_inventory_hud_system(
             HeldItem::Pickaxe    => 1,
             HeldItem::Gun        => 2,
             HeldItem::StoneBlock => 3,
+            HeldItem::Ladder     => 4,
+            HeldItem::Sign       => 5,
+            HeldItem::GlassBlock => 6,
+            HeldItem::Mirror     => 7,
+            HeldItem::Bucket     => 8,
+            HeldItem::BoosterBlock => 9,
+            HeldItem::Wall       => 10,
         };
         for (slot, mut bg) in &mut q {
             bg.0 = if slot.0 == selected {
@@ -164,6 +216,8 @@ fn update_health_bar_system(
 /* main                                                                     */
 /* ------------------------------------------------------------------------ */
 fn main() {
+    let settings = Settings::load();
+
     App::new()
         /* diagnostics ----------------------------------------------------- */
         .add_plugins((
@@ -173,68 +227,312 @@ fn main() {
         ))
         /* engine core ----------------------------------------------------- */
         .insert_resource(ClearColor(Color::srgb(0.15, 0.55, 0.90)))
+        .insert_resource(difficulty::Difficulty::from_args(settings.difficulty, settings.peaceful))
+        .insert_resource(debug::DebugFlags::from_args())
+        .init_resource::<GamepadAim>()
+        .init_resource::<CraftingMenu>()
+        .init_resource::<merchant::TradeMenu>()
+        .init_resource::<BuildDrag>()
+        .init_resource::<BuildStamp>()
+        .init_resource::<MiningRadius>()
+        .init_resource::<MiningMode>()
+        .init_resource::<ChangeHistory>()
+        .init_resource::<thrower::PlacedBlocks>()
+        .init_resource::<cracks::MiningCracks>()
+        .init_resource::<weather::Weather>()
+        .init_resource::<weather::Wind>()
+        .init_resource::<FadingTiles>()
+        .init_resource::<RedrawStats>()
+        .init_resource::<ScreenShake>()
+        .init_resource::<FreeCam>()
+        .init_resource::<backpack::BackpackUi>()
+        .init_resource::<ParticlePool>()
+        .init_resource::<signs::SignEditor>()
+        .init_resource::<booster::Boosters>()
+        .init_resource::<portal::Portals>()
+        .insert_resource(render::RenderConfig::from_args())
+        .init_resource::<LightingConfig>()
+        .init_resource::<compass::Waypoint>()
+        .init_resource::<boss::BossState>()
+        .init_resource::<GenParams>()
+        .init_resource::<env_zone::EnvZones>()
+        .add_event::<CameraSnap>()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
-                resolution: (1920., 1080.).into(),
-                mode: WindowMode::Windowed,
+                resolution: settings.resolution.into(),
+                mode: settings.window_mode(),
                 ..default()
             }),
             ..default()
         }))
+        .insert_resource(settings)
+        .add_systems(Update, settings::track_window_size_system)
+        .add_systems(Update, particles::particle_budget_system)
+        .add_systems(Last, settings::save_settings_on_exit)
+        .add_systems(Last, stats::save_stats_on_exit)
         /* startup systems ------------------------------------------------- */
-        .add_systems(Startup, generate_world_and_player)
+        .init_state::<AppState>()
+        .add_systems(Startup, setup_camera)
+        .add_systems(Startup, render::apply_render_config)
+        .add_systems(Startup, lights::setup_glow_texture)
+        .add_systems(Startup, cracks::setup_crack_textures)
+        .add_systems(Startup, tile_atlas::setup_tile_atlas)
+        .add_systems(Startup, vignette::setup_vignette)
+        .add_systems(Update, render::render_config_input_system)
+        .add_systems(Update, lighting_input_system)
+        .add_systems(Last, render::frame_limiter_system)
+        .add_systems(Startup, setup_hud.after(setup_camera))
+        .add_systems(Startup, setup_crafting_panel.after(setup_camera))
+        .add_systems(Startup, overlay::setup_diagnostics_overlay.after(setup_camera))
+        .add_systems(Startup, signs::setup_interaction_prompt.after(setup_camera))
+        .add_systems(Startup, compass::setup_compass.after(setup_camera))
+        .add_systems(Startup, backpack::setup_backpack.after(setup_camera))
+        .add_systems(Startup, merchant::setup_trade_panel.after(setup_camera))
+        .add_systems(Startup, boss::setup_boss_bar.after(setup_camera))
+        .add_systems(Startup, stats::load_stats)
+        .add_systems(Startup, stats::setup_stats_screen.after(setup_camera))
+        /* text entry swallows the keyboard before any gameplay reads it */
+        .add_systems(PreUpdate, signs::sign_text_entry_system.after(InputSystem))
+        /* backpack eats the mouse while open (needs fresh hover state) */
         .add_systems(
-            Startup,
-            enemy::spawn_enemies.after(generate_world_and_player),
+            PreUpdate,
+            backpack::backpack_input_system
+                .after(signs::sign_text_entry_system)
+                .after(UiSystem::Focus),
         )
-        .add_systems(Startup, add_player_health_system.after(generate_world_and_player))
-        .add_systems(Startup, setup_camera)
+        /* options → loading screen → world‑dependent setup ------------ */
+        .add_systems(OnEnter(AppState::Options), options::setup_options_screen)
+        .add_systems(OnExit(AppState::Options), options::cleanup_options_screen)
         .add_systems(
-            Startup,
-            update_active_rect_system.after(setup_camera),
-        ) // ensure ActiveRect exists
-        .add_systems(Startup, setup_hud.after(setup_camera))
-        .add_systems(Startup, startup_fov_system.after(setup_camera))
+            Update,
+            options::options_menu_system.run_if(in_state(AppState::Options)),
+        )
+        .add_systems(OnEnter(AppState::Loading), loading::start_world_generation)
+        .add_systems(
+            Update,
+            loading::loading_progress_system.run_if(in_state(AppState::Loading)),
+        )
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (
+                spawn_player,
+                enemy::spawn_enemies.after(spawn_player),
+                add_player_health_system.after(spawn_player),
+                lights::add_player_light_system.after(spawn_player),
+                update_active_rect_system, // ensure ActiveRect exists
+                startup_fov_system.after(spawn_player),
+                world_stats::world_stats_system,
+                crates::spawn_crates,
+                spawner::spawn_spawners,
+                merchant::spawn_merchant,
+            ),
+        )
         /* frame‑update systems ------------------------------------------- */
         .add_systems(
             Update,
             (
                 /* player -------------------------------------------------- */
                 inventory_input_system,
-                cursor_highlight_system,
+                gamepad_aim_system,
+                cursor_highlight_system.after(gamepad_aim_system),
                 player_input_system,
                 dash_start_system,
                 dash_update_system,
                 physics_and_collision_system,
-                pickaxe_mining_system,
-                place_stone_system,
-                gun_shoot_system,
+                pickaxe_mining_system.after(gamepad_aim_system),
+                place_stone_system.after(gamepad_aim_system),
+                place_ladder_system.after(gamepad_aim_system),
+                tunnel_system,
+                signs::place_sign_system.after(gamepad_aim_system),
+                gun_shoot_system.after(gamepad_aim_system),
                 bullet_update_system,
                 debris_update_system,
+                gib_update_system,
                 exhaust_update_system,
-                animate_player_system,
-            ),
+                player_anim_state_system.after(physics_and_collision_system),
+                animate_player_system.after(player_anim_state_system),
+                mirror::mirror_system.after(physics_and_collision_system),
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                /* orc warlord ----------------------------------------- */
+                boss::boss_spawn_system.before(redraw_changed_tiles_system),
+                boss::boss_ai_system.before(enemy::enemy_physics_system),
+                boss::boss_shot_system,
+                boss::shot_intercept_system
+                    .after(boss::boss_shot_system)
+                    .after(bullet_update_system),
+                boss::boss_bar_system,
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (stats::stats_tracking_system, stats::stats_screen_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                follower::tame_system.after(merchant::trade_system),
+                follower::follower_ai_system.before(enemy::enemy_physics_system),
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            cracks::crack_overlay_system
+                .after(pickaxe_mining_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            corpse_update_system.run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            enemy::alert_mark_system.run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            enemy::peaceful_system.run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                thrower::thrower_system.before(redraw_changed_tiles_system),
+                thrower::thrown_block_system,
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            weather::wind_system
+                .before(debris_update_system)
+                .before(exhaust_update_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                portal::place_portal_system
+                    .after(gamepad_aim_system)
+                    .before(redraw_changed_tiles_system),
+                portal::portal_system.after(physics_and_collision_system),
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                miner::place_miner_system.after(gamepad_aim_system),
+                miner::miner_system.before(redraw_changed_tiles_system),
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            gun_charge_system
+                .after(gamepad_aim_system)
+                .after(cursor_highlight_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            spawner::spawner_system
+                .before(bullet_update_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            booster::booster_system
+                .after(player_input_system)
+                .before(physics_and_collision_system)
+                .before(crates::pickup_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            walls::wall_system
+                .after(gamepad_aim_system)
+                .before(redraw_changed_tiles_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            bucket::bucket_system
+                .after(gamepad_aim_system)
+                .before(redraw_changed_tiles_system)
+                .run_if(in_state(AppState::InGame)),
         )
         .add_systems(
             Update,
             (
                 /* world & enemies ---------------------------------------- */
-                shift_loaded_window_system,
+                /* after the mirror / portals so a teleport streams in the same frame */
+                shift_loaded_window_system
+                    .after(mirror::mirror_system)
+                    .after(portal::portal_system),
                 stream_tiles_system.after(shift_loaded_window_system),
                 redraw_changed_tiles_system,
+                brightness_fade_system
+                    .before(redraw_changed_tiles_system)
+                    .after(lava::lava_pulse_system)
+                    .after(support::support_system),
                 enemy::update_active_tag_system,
                 enemy::enemy_ai_system,
                 enemy::enemy_attack_system,
                 enemy::enemy_visibility_system.after(recompute_fov_system),
                 enemy::enemy_physics_system,
                 enemy::animate_enemy_system,
+                lava::lava_damage_system,
+                lava::lava_pulse_system.before(redraw_changed_tiles_system),
+                support::support_system.before(redraw_changed_tiles_system),
+                support::falling_block_system,
+                enemy::enemy_health_bar_system,
+                enemy::spawn_timer_system,
+                crates::crate_damage_system,
+                crates::pickup_system,
+                enemy::burrower_system,
+                vegetation::grass_growth_system.before(redraw_changed_tiles_system),
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
                 /* HUD & misc --------------------------------------------- */
                 update_inventory_hud_system,
+                crafting_system,
+                crafting_panel_system.after(crafting_system),
+                overlay::diagnostics_overlay_system,
+                scanner::scanner_system,
+                scanner::scan_ping_update_system,
                 health_regen_system,
                 update_health_bar_system,
                 toggle_fullscreen,
                 detect_player_tile_change_system,
-            ),
+                signs::interaction_prompt_system,
+                compass::waypoint_input_system,
+                compass::compass_system.after(compass::waypoint_input_system),
+                free_cam_system,
+                backpack::backpack_panel_system,
+                undo_system,
+                weather::weather_system.after(weather::wind_system),
+                debug::debug_toggle_system,
+                merchant::animate_merchant_system,
+                merchant::trade_system.after(crafting_system),
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            merchant::trade_panel_system
+                .after(merchant::trade_system)
+                .run_if(in_state(AppState::InGame)),
         )
         /* post‑update (camera / FOV) -------------------------------------- */
         .add_systems(
@@ -243,8 +541,14 @@ fn main() {
                 camera_follow_system,
                 update_active_rect_system,
                 recompute_fov_system,
+                lights::glow_system.after(camera_follow_system),
+                vignette::depth_vignette_system.after(camera_follow_system),
+                debug::debug_grid_system
+                    .after(camera_follow_system)
+                    .after(update_active_rect_system),
                 sync_tile_sprite_entities_system.after(redraw_changed_tiles_system),
-            ),
+            )
+                .run_if(in_state(AppState::InGame)),
         )
         .run();
 }
\ No newline at end of file
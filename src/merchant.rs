@@ -0,0 +1,269 @@
+//! merchant – friendly trader standing near the surface spawn
+//!
+//! Walk up to the merchant and press E (`KeyBindings::interact`) to open
+//! the trade panel; ↑/↓ pick a deal, Enter makes it, E again or walking
+//! away closes it.  Mined materials sell for coins at fixed prices and
+//! coins buy tools back.  There are no potions in the game yet, so the
+//! healing draught is drunk on the spot instead of going into the
+//! backpack.
+//!
+//! The merchant has no `Enemy` or `Collider`, so bullets and orcs pass
+//! straight by.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::components::{
+    AnimationIndices, AnimationTimer, Health, Inventory, ItemKind, Merchant, PickaxeTier, Player,
+};
+use crate::constants::*;
+use crate::crafting::CraftingMenu;
+use crate::settings::Settings;
+use crate::world_gen::{tile_to_world_y, Terrain};
+
+/* ===========================================================
+   price list
+   =========================================================== */
+pub enum Goods {
+    Item(ItemKind, u32),
+    /// health restored on purchase
+    Heal(f32),
+}
+
+pub struct Trade {
+    pub name:  &'static str,
+    pub price: (ItemKind, u32),
+    pub goods: Goods,
+}
+
+pub const TRADES: &[Trade] = &[
+    /* selling – materials for coins */
+    Trade { name: "Sell dirt",     price: (ItemKind::Dirt, 20),     goods: Goods::Item(ItemKind::Coin, 1) },
+    Trade { name: "Sell stone",    price: (ItemKind::Stone, 10),    goods: Goods::Item(ItemKind::Coin, 1) },
+    Trade { name: "Sell obsidian", price: (ItemKind::Obsidian, 2),  goods: Goods::Item(ItemKind::Coin, 1) },
+    Trade { name: "Sell tusk",     price: (ItemKind::WarlordTusk, 1), goods: Goods::Item(ItemKind::Coin, 60) },
+    /* buying – coins for goods */
+    Trade { name: "Glass",           price: (ItemKind::Coin, 1),  goods: Goods::Item(ItemKind::Glass, 4) },
+    Trade { name: "Healing draught", price: (ItemKind::Coin, 3),  goods: Goods::Heal(50.0) },
+    Trade { name: "Stone pickaxe",   price: (ItemKind::Coin, 5),  goods: Goods::Item(ItemKind::StonePickaxe, 1) },
+    Trade { name: "Iron pickaxe",    price: (ItemKind::Coin, 25), goods: Goods::Item(ItemKind::IronPickaxe, 1) },
+];
+
+impl Trade {
+    #[inline]
+    pub fn affordable(&self, inv: &Inventory) -> bool {
+        inv.count(self.price.0) >= self.price.1
+    }
+
+    fn goods_label(&self) -> String {
+        match self.goods {
+            Goods::Item(item, n) => format!("{n} {item:?}"),
+            Goods::Heal(hp)      => format!("+{hp:.0} HP"),
+        }
+    }
+}
+
+/* ===========================================================
+   UI state & markers
+   =========================================================== */
+#[derive(Resource, Default)]
+pub struct TradeMenu {
+    pub open:     bool,
+    pub selected: usize,
+    /// player within `MERCHANT_REACH` this frame
+    pub near:     bool,
+}
+
+#[derive(Component)]
+pub struct TradePanel;
+
+#[derive(Component)]
+pub struct TradePanelText;
+
+/* ===========================================================
+   on enter: a few tiles right of the player start
+   =========================================================== */
+pub fn spawn_merchant(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    terrain: Res<Terrain>,
+) {
+    let sheet  = asset_server.load("textures/player_sheet.png");
+    let layout = atlas_layouts.add(TextureAtlasLayout::from_grid(UVec2::new(100, 100), 6, 1, None, None));
+
+    let x   = (terrain.width / 2 + MERCHANT_SPAWN_OFFSET).min(terrain.width - 1);
+    let pos = Vec2::new(
+        x as f32 * tile_size(),
+        tile_to_world_y(terrain.height, terrain.height_map[x]) + tile_size() * 0.5 + PLAYER_HEIGHT * 0.5,
+    );
+
+    let mut sprite = Sprite::from_atlas_image(sheet, TextureAtlas { layout, index: 0 });
+    sprite.color  = MERCHANT_TINT;
+    sprite.flip_x = true; // faces the spawn
+    commands.spawn((
+        sprite,
+        Transform { translation: pos.extend(9.0), scale: Vec3::splat(1.8), ..default() },
+        Merchant,
+        AnimationIndices { first: 0, last: 5 },
+        AnimationTimer(Timer::from_seconds(MERCHANT_IDLE_FRAME, TimerMode::Repeating)),
+    ));
+}
+
+/* ===========================================================
+   startup: hidden panel on the right edge
+   =========================================================== */
+pub fn setup_trade_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right:   Val::Px(10.0),
+                top:     Val::Px(44.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            Visibility::Hidden,
+            TradePanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::WHITE),
+                TradePanelText,
+            ));
+        });
+}
+
+/* ===========================================================
+   idle animation
+   =========================================================== */
+pub fn animate_merchant_system(
+    time: Res<Time>,
+    mut q: Query<(&AnimationIndices, &mut AnimationTimer, &mut Sprite), With<Merchant>>,
+) {
+    for (indices, mut timer, mut sprite) in &mut q {
+        if timer.tick(time.delta()).just_finished() {
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                atlas.index = if atlas.index == indices.last {
+                    indices.first
+                } else {
+                    atlas.index + 1
+                };
+            }
+        }
+    }
+}
+
+/* ===========================================================
+   input: proximity, open / close, select, trade
+   =========================================================== */
+pub fn trade_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut menu: ResMut<TradeMenu>,
+    mut crafting: ResMut<CraftingMenu>,
+    merchant_q: Query<&Transform, With<Merchant>>,
+    mut player_q: Query<(&Transform, &mut Inventory, &mut Health), With<Player>>,
+) {
+    let Ok((player_tf, mut inv, mut health)) = player_q.get_single_mut() else { return };
+    let player_pos = player_tf.translation.truncate();
+    menu.near = merchant_q
+        .iter()
+        .any(|tf| tf.translation.truncate().distance_squared(player_pos) <= tiles(MERCHANT_REACH).powi(2));
+
+    if !menu.near {
+        menu.open = false;
+        return;
+    }
+    if keys.just_pressed(settings.keys.interact) {
+        menu.open = !menu.open;
+        // both panels use ↑/↓/Enter
+        if menu.open {
+            crafting.open = false;
+        }
+    }
+    if !menu.open {
+        return;
+    }
+    if crafting.open {
+        menu.open = false;
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        menu.selected = (menu.selected + TRADES.len() - 1) % TRADES.len();
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        menu.selected = (menu.selected + 1) % TRADES.len();
+    }
+
+    if keys.just_pressed(KeyCode::Enter) {
+        let trade = &TRADES[menu.selected];
+        match trade.goods {
+            Goods::Heal(_) if health.current >= health.max => {
+                info!("{}: already at full health", trade.name);
+                return;
+            }
+            Goods::Item(item, _) if PickaxeTier::from_item(item).is_some_and(|t| t <= inv.pick_tier) => {
+                info!("{}: already own this pickaxe or better", trade.name);
+                return;
+            }
+            _ => {}
+        }
+        if !inv.take(trade.price.0, trade.price.1) {
+            info!("cannot afford {}", trade.name);
+            return;
+        }
+        match trade.goods {
+            // tools don't stack – equip the pickaxe straight away
+            Goods::Item(item, n) => match PickaxeTier::from_item(item) {
+                Some(tier) => inv.pick_tier = inv.pick_tier.max(tier),
+                None       => inv.add(item, n),
+            },
+            Goods::Heal(hp) => health.current = (health.current + hp).min(health.max),
+        }
+    }
+}
+
+/* ===========================================================
+   panel redraw – a one‑line hint while merely standing nearby
+   =========================================================== */
+pub fn trade_panel_system(
+    menu: Res<TradeMenu>,
+    settings: Res<Settings>,
+    inv_q: Query<&Inventory, With<Player>>,
+    mut panel_q: Query<&mut Visibility, With<TradePanel>>,
+    mut text_q: Query<&mut Text, With<TradePanelText>>,
+) {
+    let Ok(mut vis) = panel_q.get_single_mut() else { return };
+    *vis = if menu.near { Visibility::Visible } else { Visibility::Hidden };
+    if !menu.near {
+        return;
+    }
+
+    let (Ok(inv), Ok(mut text)) = (inv_q.get_single(), text_q.get_single_mut()) else {
+        return;
+    };
+
+    if !menu.open {
+        text.0 = format!("Merchant – press {:?} to trade", settings.keys.interact);
+        return;
+    }
+
+    let mut out = format!("Merchant  (Up/Down, Enter)   coins: {}\n", inv.count(ItemKind::Coin));
+    for (i, trade) in TRADES.iter().enumerate() {
+        let cursor = if i == menu.selected { ">" } else { " " };
+        let status = if trade.affordable(inv) { "ok" } else { "--" };
+        let (item, n) = trade.price;
+        out.push_str(&format!(
+            "{cursor} [{status}] {}: {}  <-  {n} {item:?} ({})\n",
+            trade.name,
+            trade.goods_label(),
+            inv.count(item),
+        ));
+    }
+    text.0 = out;
+}
@@ -0,0 +1,186 @@
+//! autominers – crafted machines that slowly dig a straight tunnel
+//!
+//! With the pickaxe held, RMB (pad: Start) on an empty tile within reach
+//! places one (from the inventory), facing away from the player along the
+//! dominant axis; the tile in front has to be diggable.  The same on a
+//! placed miner picks it back up.  Every MINER_TICK it takes MINER_BITE off the mine
+//! time of the tile in front (the cracks show it), and once that runs
+//! out the tile goes through the usual tile‑change path, its drop pops
+//! out as an `ItemPickup` and the miner moves into the hole.  It stalls
+//! on anything harder than MINER_MAX_HARDNESS, on open space and at the
+//! map edge.  Only miners inside the loaded window tick, at most
+//! MINER_MAX are placed, and one buried by a new solid tile pops back
+//! out as an item.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::{HeldItem, Inventory, ItemKind, ItemPickup, Miner, Player, Velocity};
+use crate::constants::*;
+use crate::cracks::MiningCracks;
+use crate::gamepad::{cursor_to_tile, pad_just_pressed, GamepadAim, PAD_ALT_USE};
+use crate::particles::ParticlePool;
+use crate::player::{spawn_debris, tile_drop};
+use crate::tile_stream::{solid_kind, LoadedWindow};
+use crate::world_gen::{tile_to_world_y, Terrain, TileKind};
+
+/// tile the miner at (x, y) digs next, if it is on the map
+#[inline]
+fn front(terrain: &Terrain, miner: &Miner) -> Option<(usize, usize)> {
+    let tx = miner.x as i32 + miner.dir.0;
+    let ty = miner.y as i32 + miner.dir.1;
+    (tx >= 0 && ty >= 0 && tx < terrain.width as i32 && ty < terrain.height as i32)
+        .then_some((tx as usize, ty as usize))
+}
+
+#[inline]
+fn diggable(kind: TileKind) -> bool {
+    solid_kind(kind) && kind.hardness() <= MINER_MAX_HARDNESS
+}
+
+#[inline]
+fn tile_centre(terrain: &Terrain, x: usize, y: usize) -> Vec2 {
+    Vec2::new(x as f32 * tile_size(), tile_to_world_y(terrain.height, y))
+}
+
+fn spawn_pickup(commands: &mut Commands, at: Vec2, item: ItemKind) {
+    let mut rng = rand::thread_rng();
+    commands.spawn((
+        Sprite {
+            color: Color::srgb(1.0, 0.9, 0.4),
+            custom_size: Some(Vec2::splat(PICKUP_SIZE)),
+            ..default()
+        },
+        Transform::from_translation(at.extend(6.0)),
+        Velocity(Vec2::new(rng.gen_range(-30.0..30.0), rng.gen_range(40.0..90.0))),
+        ItemPickup { item, n: 1 },
+    ));
+}
+
+/* ===========================================================
+   placement / pickup – pickaxe held, RMB / pad Start
+   =========================================================== */
+pub fn place_miner_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
+    terrain: Res<Terrain>,
+    mut commands: Commands,
+    mut q: Query<(&Transform, &mut Inventory), With<Player>>,
+    miners: Query<(Entity, &Miner)>,
+) {
+    let Ok((tf, mut inv)) = q.get_single_mut() else { return };
+    if inv.selected != HeldItem::Pickaxe
+        || !(mouse.just_pressed(MouseButton::Right) || pad_just_pressed(&pads, PAD_ALT_USE))
+    {
+        return;
+    }
+    let (Some(world), Some((ux, uy))) = (aim.point, cursor_to_tile(&aim, &terrain)) else { return };
+    let player_pos = tf.translation.truncate();
+    if (world - player_pos).length_squared() > tiles(DIG_RADIUS).powi(2) {
+        return;
+    }
+
+    /* a miner already there: take it back */
+    if let Some((e, _)) = miners.iter().find(|(_, m)| (m.x, m.y) == (ux, uy)) {
+        commands.entity(e).despawn_recursive();
+        inv.add(ItemKind::Miner, 1);
+        return;
+    }
+
+    if miners.iter().count() >= MINER_MAX
+        || inv.count(ItemKind::Miner) == 0
+        || !matches!(terrain.tiles[uy][ux].kind, TileKind::Air | TileKind::Sky)
+    {
+        return;
+    }
+    // face away from the player; grid y grows downward
+    let away = tile_centre(&terrain, ux, uy) - player_pos;
+    let dir = if away.x.abs() >= away.y.abs() {
+        (away.x.signum() as i32, 0)
+    } else {
+        (0, -away.y.signum() as i32)
+    };
+    let miner = Miner { x: ux, y: uy, dir, timer: MINER_TICK };
+    let Some((fx, fy)) = front(&terrain, &miner) else { return };
+    if !diggable(terrain.tiles[fy][fx].kind) {
+        return; // has to go against something it can dig
+    }
+    inv.take(ItemKind::Miner, 1);
+
+    let size  = tile_size() * 0.8;
+    let drill = Vec2::new(dir.0 as f32, -dir.1 as f32) * size * 0.45;
+    commands
+        .spawn((
+            Sprite { color: MINER_COLOR, custom_size: Some(Vec2::splat(size)), ..default() },
+            Transform::from_translation(tile_centre(&terrain, ux, uy).extend(7.0)),
+            miner,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Sprite {
+                    color: MINER_DRILL_COLOR,
+                    custom_size: Some(Vec2::splat(size * 0.35)),
+                    ..default()
+                },
+                Transform::from_translation(drill.extend(0.1)),
+            ));
+        });
+}
+
+/* ===========================================================
+   dig – one bite per tick, advance into the hole
+   =========================================================== */
+pub fn miner_system(
+    time: Res<Time>,
+    loaded: Res<LoadedWindow>,
+    mut terrain: ResMut<Terrain>,
+    mut cracks: ResMut<MiningCracks>,
+    mut pool: ResMut<ParticlePool>,
+    mut commands: Commands,
+    mut miners: Query<(Entity, &mut Miner, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+
+    for (e, mut miner, mut tf) in &mut miners {
+        if !loaded.contains_tile(miner.x, miner.y) {
+            continue;
+        }
+        if solid_kind(terrain.tiles[miner.y][miner.x].kind) {
+            // built over or caved in – pop out as an item
+            spawn_pickup(&mut commands, tf.translation.truncate(), ItemKind::Miner);
+            commands.entity(e).despawn_recursive();
+            continue;
+        }
+        miner.timer -= dt;
+        if miner.timer > 0.0 {
+            continue;
+        }
+        miner.timer += MINER_TICK;
+
+        let Some((fx, fy)) = front(&terrain, &miner) else { continue };
+        let tile = &mut terrain.tiles[fy][fx];
+        if !diggable(tile.kind) {
+            continue; // stalled
+        }
+        cracks.touch(fx, fy, tile.mine_time);
+        tile.mine_time -= MINER_BITE;
+        if tile.mine_time > 0.0 {
+            continue;
+        }
+
+        let drop = tile_drop(tile.kind);
+        spawn_debris(&mut commands, &mut pool, &terrain, fx, fy);
+        terrain.clear(fx, fy);
+
+        let at = tile_centre(&terrain, fx, fy);
+        if let Some(item) = drop {
+            spawn_pickup(&mut commands, at, item);
+        }
+        miner.x = fx;
+        miner.y = fy;
+        tf.translation.x = at.x;
+        tf.translation.y = at.y;
+    }
+}
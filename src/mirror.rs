@@ -0,0 +1,111 @@
+//! magic mirror – channel for a few seconds, then return to the surface
+//!
+//! Use (LMB / LT) with the mirror held starts the channel; drifting more than
+//! `MIRROR_CANCEL_DIST` or switching items cancels it.  The destination is the
+//! spawn column (`w / 2`), lifted above anything built on top of it.  The
+//! camera is moved with the player so the chunk window and active rect
+//! re‑centre on the same frame instead of easing across the map.
+//! RMB with the mirror held places portals instead (see portal.rs).
+
+use bevy::input::gamepad::Gamepad;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::camera::{CameraSnap, FreeCam};
+use crate::components::{Collider, HeldItem, Inventory, Player, Velocity};
+use crate::constants::*;
+use crate::gamepad::{pad_just_pressed, PAD_USE};
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::tile_stream::solid;
+use crate::world_gen::{tile_to_world_y, Terrain};
+
+/// channel in progress
+pub struct Channel {
+    left:  f32,
+    start: Vec2,
+}
+
+/// where `spawn_player` drops the player, raised past player‑built tiles
+fn surface_spawn(terrain: &Terrain, half_h: f32) -> Vec2 {
+    let x = terrain.width / 2;
+    let mut row = terrain.height_map[x] as i32;
+    while row > 0 && solid(terrain, x as i32, row - 1) {
+        row -= 1;
+    }
+    Vec2::new(
+        x as f32 * tile_size(),
+        tile_to_world_y(terrain.height, row as usize) + tile_size() * 0.5 + half_h + 4.0,
+    )
+}
+
+fn sparkle(commands: &mut Commands, pool: &mut ParticlePool, at: Vec2, count: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..count {
+        let offset = Vec2::new(rng.gen_range(-8.0..8.0), rng.gen_range(-8.0..8.0));
+        let vel    = Vec2::new(rng.gen_range(-15.0..15.0), rng.gen_range(20.0..60.0));
+        pool.spawn(
+            commands, ParticleKind::Exhaust, MIRROR_COLOR, 2.0,
+            (at + offset).extend(11.0), vel, 0.6,
+        );
+    }
+}
+
+/* ===========================================================
+   channel → teleport
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn mirror_system(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    pads: Query<&Gamepad>,
+    terrain: Res<Terrain>,
+    free: Res<FreeCam>,
+    mut channel: Local<Option<Channel>>,
+    mut pool: ResMut<ParticlePool>,
+    mut commands: Commands,
+    mut player_q: Query<(&mut Transform, &mut Velocity, &Inventory, &Collider), With<Player>>,
+    mut cam_q: Query<&mut Transform, (With<Camera>, Without<Player>)>,
+    mut snaps: EventWriter<CameraSnap>,
+) {
+    let Ok((mut tf, mut vel, inv, collider)) = player_q.get_single_mut() else { return };
+    if inv.selected != HeldItem::Mirror {
+        *channel = None;
+        return;
+    }
+    let pos = tf.translation.truncate();
+
+    if channel.is_none()
+        && (mouse.just_pressed(MouseButton::Left) || pad_just_pressed(&pads, PAD_USE))
+    {
+        *channel = Some(Channel { left: MIRROR_CHANNEL_TIME, start: pos });
+    }
+    let Some(ch) = channel.as_mut() else { return };
+
+    if (pos - ch.start).length() > MIRROR_CANCEL_DIST {
+        *channel = None; // moved – channel broken
+        return;
+    }
+    sparkle(&mut commands, &mut pool, pos, 1);
+
+    ch.left -= time.delta_secs();
+    if ch.left > 0.0 {
+        return;
+    }
+    *channel = None;
+
+    let dest = surface_spawn(&terrain, collider.half.y);
+    tf.translation.x = dest.x;
+    tf.translation.y = dest.y;
+    vel.0 = Vec2::ZERO;
+
+    // streaming + active rect follow the camera, so move it too
+    if !free.active {
+        if let Ok(mut cam_tf) = cam_q.get_single_mut() {
+            cam_tf.translation.x = dest.x;
+            cam_tf.translation.y = dest.y;
+        }
+    }
+    snaps.send(CameraSnap);
+    sparkle(&mut commands, &mut pool, dest, 24);
+}
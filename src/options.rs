@@ -0,0 +1,175 @@
+//! options screen – tune `GenParams` before the world is generated
+//!
+//! Shown at launch (`AppState::Options`).  ↑/↓ pick a knob, ←/→ move its
+//! slider, R resets to defaults, Enter starts generation.  The generator
+//! still seeds itself from `thread_rng`, so there is no seed field.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::constants::{
+    WORLD_CHUNKS_X_MAX, WORLD_CHUNKS_X_MIN, WORLD_CHUNKS_Y_MAX, WORLD_CHUNKS_Y_MIN,
+};
+use crate::loading::AppState;
+use crate::world_gen::GenParams;
+
+#[derive(Component)]
+pub struct OptionsScreen;
+
+#[derive(Component)]
+pub struct OptionsText;
+
+/* ===========================================================
+   slider table – (label, min, max, step) + field access
+   =========================================================== */
+struct Slider {
+    label: &'static str,
+    min:   f32,
+    max:   f32,
+    step:  f32,
+    get:   fn(&GenParams) -> f32,
+    set:   fn(&mut GenParams, f32),
+}
+
+const SLIDERS: &[Slider] = &[
+    Slider {
+        label: "Surface amplitude",
+        min: 0.0, max: 3.0, step: 0.1,
+        get: |p| p.surface_amp,
+        set: |p, v| p.surface_amp = v,
+    },
+    Slider {
+        label: "Cave density",
+        min: 0.0, max: 3.0, step: 0.1,
+        get: |p| p.cave_density,
+        set: |p, v| p.cave_density = v,
+    },
+    Slider {
+        label: "Ore rarity",
+        min: 0.25, max: 4.0, step: 0.25,
+        get: |p| p.ore_rarity,
+        set: |p, v| p.ore_rarity = v,
+    },
+    Slider {
+        label: "Grass ratio",
+        min: 0.0, max: 1.0, step: 0.05,
+        get: |p| p.grass_ratio,
+        set: |p, v| p.grass_ratio = v,
+    },
+    Slider {
+        label: "Rift threshold",
+        min: 0.5, max: 1.0, step: 0.025,
+        get: |p| p.rift_thresh as f32,
+        set: |p, v| p.rift_thresh = v as f64,
+    },
+    Slider {
+        label: "Mountains per side",
+        min: 0.0, max: 8.0, step: 1.0,
+        get: |p| p.mountains_per_side as f32,
+        set: |p, v| p.mountains_per_side = v.round() as usize,
+    },
+    Slider {
+        label: "World width (chunks)",
+        min: WORLD_CHUNKS_X_MIN as f32, max: WORLD_CHUNKS_X_MAX as f32, step: 8.0,
+        get: |p| p.chunks_x as f32,
+        set: |p, v| p.chunks_x = v.round() as usize,
+    },
+    Slider {
+        label: "World height (chunks)",
+        min: WORLD_CHUNKS_Y_MIN as f32, max: WORLD_CHUNKS_Y_MAX as f32, step: 4.0,
+        get: |p| p.chunks_y as f32,
+        set: |p, v| p.chunks_y = v.round() as usize,
+    },
+];
+
+/// width of the text slider bar, in characters
+const BAR_CELLS: usize = 20;
+
+/* ===========================================================
+   on enter: full‑screen panel
+   =========================================================== */
+pub fn setup_options_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                width:           Val::Percent(100.0),
+                height:          Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items:     AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.08)),
+            GlobalZIndex(100), // above the HUD spawned at startup
+            OptionsScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont { font_size: 20.0, ..default() },
+                TextColor(Color::WHITE),
+                OptionsText,
+            ));
+        });
+}
+
+/* ===========================================================
+   input + redraw
+   =========================================================== */
+pub fn options_menu_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut params: ResMut<GenParams>,
+    mut selected: Local<usize>,
+    mut text_q: Query<&mut Text, With<OptionsText>>,
+    mut next: ResMut<NextState<AppState>>,
+) {
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        *selected = (*selected + SLIDERS.len() - 1) % SLIDERS.len();
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        *selected = (*selected + 1) % SLIDERS.len();
+    }
+
+    let s = &SLIDERS[*selected];
+    let mut delta = 0.0;
+    if keys.just_pressed(KeyCode::ArrowLeft) {
+        delta -= s.step;
+    }
+    if keys.just_pressed(KeyCode::ArrowRight) {
+        delta += s.step;
+    }
+    if delta != 0.0 {
+        let v = ((s.get)(&params) + delta).clamp(s.min, s.max);
+        (s.set)(&mut params, v);
+    }
+    if keys.just_pressed(KeyCode::KeyR) {
+        *params = GenParams::default();
+    }
+    if keys.just_pressed(KeyCode::Enter) {
+        next.set(AppState::Loading);
+    }
+
+    let Ok(mut text) = text_q.get_single_mut() else { return };
+
+    let mut out = String::from("World options  (Up/Down, Left/Right, R = reset, Enter = start)\n\n");
+    for (i, s) in SLIDERS.iter().enumerate() {
+        let v      = (s.get)(&params);
+        let filled = (((v - s.min) / (s.max - s.min)) * BAR_CELLS as f32).round() as usize;
+        let cursor = if i == *selected { ">" } else { " " };
+        out.push_str(&format!(
+            "{cursor} {:<21} [{}{}] {v:.2}\n",
+            s.label,
+            "#".repeat(filled.min(BAR_CELLS)),
+            "-".repeat(BAR_CELLS - filled.min(BAR_CELLS)),
+        ));
+    }
+    text.0 = out;
+}
+
+/* ===========================================================
+   on exit: tear the panel down
+   =========================================================== */
+pub fn cleanup_options_screen(mut commands: Commands, q: Query<Entity, With<OptionsScreen>>) {
+    for e in &q {
+        commands.entity(e).despawn_recursive();
+    }
+}
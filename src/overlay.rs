@@ -0,0 +1,93 @@
+//! on‑screen diagnostics overlay (F3)
+//!
+//! FPS, frame time and entity count from the `DiagnosticsStore`, plus the
+//! streamed chunk window and active rectangle for chasing streaming hitches,
+//! the parked particle count (a blood burst should barely move the
+//! entity count once the pool is warm), the cost of the last tile redraw
+//! and the generation summary from `world_stats.rs`.
+
+use bevy::diagnostic::{
+    DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::tile_stream::{LoadedWindow, RedrawStats};
+use crate::world_gen::ActiveRect;
+use crate::world_stats::WorldStats;
+
+#[derive(Component)]
+pub struct DiagnosticsOverlay;
+
+/* ===========================================================
+   startup: hidden text in the bottom‑left corner
+   =========================================================== */
+pub fn setup_diagnostics_overlay(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(Color::srgb(1.0, 1.0, 0.6)),
+        Node {
+            position_type: PositionType::Absolute,
+            left:   Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        Visibility::Hidden,
+        DiagnosticsOverlay,
+    ));
+}
+
+/* ===========================================================
+   F3 toggle + per‑frame refresh
+   =========================================================== */
+pub fn diagnostics_overlay_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    diagnostics: Res<DiagnosticsStore>,
+    loaded: Option<Res<LoadedWindow>>,
+    rect: Option<Res<ActiveRect>>,
+    pool: Res<ParticlePool>,
+    redraw: Res<RedrawStats>,
+    world: Option<Res<WorldStats>>,
+    mut q: Query<(&mut Text, &mut Visibility), With<DiagnosticsOverlay>>,
+) {
+    let Ok((mut text, mut vis)) = q.get_single_mut() else { return };
+
+    if keys.just_pressed(KeyCode::F3) {
+        *vis = match *vis {
+            Visibility::Hidden => Visibility::Visible,
+            _                  => Visibility::Hidden,
+        };
+    }
+    if *vis == Visibility::Hidden {
+        return;
+    }
+
+    let smoothed = |path: &DiagnosticPath| diagnostics.get(path).and_then(|d| d.smoothed()).unwrap_or(0.0);
+    let fps      = smoothed(&FrameTimeDiagnosticsPlugin::FPS);
+    let frame_ms = smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME);
+    let entities = smoothed(&EntityCountDiagnosticsPlugin::ENTITY_COUNT);
+
+    let window = loaded
+        .map(|l| format!("({}, {})", l.origin_cx, l.origin_cy))
+        .unwrap_or_else(|| "-".into());
+    let active = rect
+        .map(|r| format!("x {}..{}  y {}..{}", r.min_x, r.max_x, r.min_y, r.max_y))
+        .unwrap_or_else(|| "-".into());
+
+    let pooled = pool.free_count(ParticleKind::Debris) + pool.free_count(ParticleKind::Exhaust);
+
+    let RedrawStats { micros, tiles, chunks } = *redraw;
+
+    text.0 = format!(
+        "FPS {fps:.0}  ({frame_ms:.2} ms)\n\
+         entities {entities:.0}  (particles pooled {pooled})\n\
+         chunk window origin {window}\n\
+         active rect {active}\n\
+         last redraw {tiles} tiles / {chunks} chunks in {micros} µs\n\
+         {}",
+        world.map_or_else(|| "world: -".into(), |w| w.summary()),
+    );
+}
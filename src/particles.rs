@@ -0,0 +1,157 @@
+//! particle entity pool – debris, blood, exhaust & dash puffs
+//!
+//! Short‑lived particles used to be spawned and despawned every frame,
+//! churning archetypes the way tile sprites did before `free_sprites`.
+//! Expired particles are now hidden and parked here; the next spawn of the
+//! same kind re‑inserts fresh components on a parked entity instead.
+//!
+//! A parked particle keeps its marker with `life <= 0`, so the decay
+//! systems simply skip it.
+//!
+//! Burst sizes go through `ParticlePool::scaled`: the `particle_quality`
+//! setting times an automatic factor that backs off while frames run
+//! long (and recovers once they don't), so a big fight on a slow machine
+//! emits fewer particles instead of stalling.
+
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::{Debris, Exhaust, Velocity};
+use crate::constants::*;
+use crate::settings::Settings;
+
+/// `particle_quality` in settings.ron
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ParticleQuality {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+impl ParticleQuality {
+    #[inline]
+    pub fn multiplier(self) -> f32 {
+        match self {
+            ParticleQuality::Low    => 0.35,
+            ParticleQuality::Medium => 0.65,
+            ParticleQuality::High   => 1.0,
+        }
+    }
+}
+
+/// which decay system owns the particle (the marker never changes)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParticleKind {
+    Debris,
+    Exhaust,
+}
+
+#[derive(Resource)]
+pub struct ParticlePool {
+    debris:  Vec<Entity>,
+    exhaust: Vec<Entity>,
+    /// quality × auto factor, refreshed by `particle_budget_system`
+    scale:   f32,
+    /// 1 = untouched; drops while frames run over PARTICLE_AUTO_FRAME_MS
+    auto:    f32,
+}
+
+impl Default for ParticlePool {
+    fn default() -> Self {
+        Self { debris: Vec::new(), exhaust: Vec::new(), scale: 1.0, auto: 1.0 }
+    }
+}
+
+impl ParticlePool {
+    /// how many of a `rate`‑particle burst to actually emit (at least one)
+    #[inline]
+    pub fn scaled(&self, rate: usize) -> usize {
+        ((rate as f32 * self.scale).round() as usize).clamp(rate.min(1), rate)
+    }
+
+    #[inline]
+    fn free_list(&mut self, kind: ParticleKind) -> &mut Vec<Entity> {
+        match kind {
+            ParticleKind::Debris  => &mut self.debris,
+            ParticleKind::Exhaust => &mut self.exhaust,
+        }
+    }
+
+    /// parked entities of `kind` (for diagnostics)
+    pub fn free_count(&self, kind: ParticleKind) -> usize {
+        match kind {
+            ParticleKind::Debris  => self.debris.len(),
+            ParticleKind::Exhaust => self.exhaust.len(),
+        }
+    }
+
+    /// reuse a parked particle or spawn a new one
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn(
+        &mut self,
+        commands: &mut Commands,
+        kind: ParticleKind,
+        color: Color,
+        size: f32,
+        pos: Vec3,
+        vel: Vec2,
+        life: f32,
+    ) {
+        let sprite = Sprite {
+            color,
+            custom_size: Some(Vec2::splat(size)),
+            ..default()
+        };
+        let bundle = (Visibility::Visible, sprite, Transform::from_translation(pos), Velocity(vel));
+
+        let mut ec = match self.free_list(kind).pop() {
+            Some(e) => {
+                let mut ec = commands.entity(e);
+                ec.insert(bundle);
+                ec
+            }
+            None => commands.spawn(bundle),
+        };
+        match kind {
+            ParticleKind::Debris  => ec.insert(Debris { life }),
+            ParticleKind::Exhaust => ec.insert(Exhaust { life }),
+        };
+    }
+
+    /// hide an expired particle and park it for reuse
+    #[inline]
+    pub fn release(&mut self, kind: ParticleKind, e: Entity, vis: &mut Visibility) {
+        *vis = Visibility::Hidden;
+        self.free_list(kind).push(e);
+    }
+}
+
+/* ===========================================================
+   burst budget – quality setting × frame‑time back‑off
+   =========================================================== */
+pub fn particle_budget_system(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut pool: ResMut<ParticlePool>,
+) {
+    let dt = time.delta_secs();
+    if settings.auto_particles {
+        let frame_ms = diagnostics
+            .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+            .and_then(|d| d.smoothed())
+            .unwrap_or(0.0) as f32;
+        pool.auto = if frame_ms > PARTICLE_AUTO_FRAME_MS {
+            (pool.auto - PARTICLE_AUTO_DROP * dt).max(PARTICLE_AUTO_MIN)
+        } else if frame_ms < PARTICLE_AUTO_FRAME_MS * PARTICLE_AUTO_RECOVER_FRAC {
+            (pool.auto + PARTICLE_AUTO_RECOVER * dt).min(1.0)
+        } else {
+            pool.auto
+        };
+    } else {
+        pool.auto = 1.0;
+    }
+    pool.scale = settings.particle_quality.multiplier() * pool.auto;
+}
@@ -4,21 +4,41 @@
 //! Works with **Bevy 0.15**, Rust 1.77.
 
 use bevy::color::Alpha;               // ← brings set_alpha / with_alpha into scope
+use bevy::input::gamepad::Gamepad;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::input::ButtonInput;
 use bevy::prelude::*;
 use rand::Rng;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::components::{
-    AnimationIndices, AnimationTimer, Bullet, Debris, Enemy, 
-    Exhaust, HeldItem, Inventory, Player, Velocity, Highlight,
-    Health, Dashing,
+    AnimationIndices, AnimationTimer, Bullet, Debris, Enemy, Gib,
+    Exhaust, HeldItem, Inventory, ItemKind, Player, Velocity, Highlight,
+    Health, Dashing, Collider, CollisionLayers, Corpse, Follower, ItemPickup,
+    WeaponStats,
 };
+use crate::booster::Boosters;
+use crate::camera::{FreeCam, ScreenShake};
+use crate::cracks::MiningCracks;
+use crate::debug::DebugFlags;
+use crate::env_zone::EnvZones;
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::settings::Settings;
+use crate::stats::Stats;
+use crate::weather::Wind;
+use crate::thrower::PlacedBlocks;
 use crate::constants::*;
-use crate::world_gen::{tile_to_world_y, world_to_tile_y, Terrain, TileKind};
-use crate::tile_stream::solid;
-
-/// seconds between bullets when the gun is held down (≈12.5 rps)
-const GUN_FIRE_INTERVAL: f32 = 0.12;
+use crate::world_gen::{
+    tile_to_world_y, world_to_cell, world_to_tile, world_to_tile_y, Decal, Terrain, Tile, TileKind,
+    BOOSTER_MINE_TIME, GLASS_MINE_TIME, LADDER_MINE_TIME, PLATFORM_MINE_TIME,
+};
+use crate::tile_stream::{liquid_at, minable_kind, solid, solid_for, solid_kind, Approach};
+use crate::gamepad::{
+    pad_just_pressed, pad_move_x, pad_move_y, pad_pressed, GamepadAim, PAD_DASH, PAD_JET,
+    PAD_JUMP, PAD_SLOT_1, PAD_SLOT_2, PAD_SLOT_3, PAD_SLOT_4, PAD_SLOT_5, PAD_SLOT_6, PAD_SLOT_7,
+    PAD_SLOT_8, PAD_SLOT_9, PAD_SLOT_10, PAD_TUNNEL,
+    PAD_USE,
+};
 
 /* -----------------------------------------------------------
    utility: approximate colour for debris particles
@@ -26,62 +46,397 @@ const GUN_FIRE_INTERVAL: f32 = 0.12;
 #[inline]
 fn tile_color(kind: TileKind) -> Color {
     match kind {
-        TileKind::Dirt  => Color::srgb(0.55, 0.27, 0.07),
-        TileKind::Stone => Color::srgb(0.50, 0.50, 0.50),
-        _               => Color::WHITE,
+        TileKind::Dirt     => Color::srgb(0.55, 0.27, 0.07),
+        TileKind::Grass    => Color::srgb(0.30, 0.60, 0.20),
+        TileKind::Stone    => Color::srgb(0.50, 0.50, 0.50),
+        TileKind::Obsidian => Color::srgb(0.20, 0.10, 0.30),
+        TileKind::Glass    => Color::srgb(0.70, 0.88, 0.95),
+        TileKind::Booster  => Color::srgb(0.85, 0.55, 0.15),
+        TileKind::Portal   => Color::srgb(0.55, 0.30, 0.95),
+        _                  => Color::WHITE,
+    }
+}
+
+/* -----------------------------------------------------------
+   utility: item collected when a tile is mined out
+   ----------------------------------------------------------- */
+#[inline]
+pub fn tile_drop(kind: TileKind) -> Option<ItemKind> {
+    match kind {
+        TileKind::Grass | TileKind::Dirt => Some(ItemKind::Dirt),
+        TileKind::Stone                  => Some(ItemKind::Stone),
+        TileKind::Obsidian               => Some(ItemKind::Obsidian),
+        TileKind::Glass                  => Some(ItemKind::Glass),
+        TileKind::Booster                => Some(ItemKind::Booster),
+        _                                => None,
     }
 }
 
 /* ===========================================================
-   inventory hot‑keys (1 = pickaxe, 2 = gun)
+   undo history – player edits only (mining, tunnelling, building);
+   generation, cave‑ins, lava and burrowers never record.
+   One entry per action: a drag, a stamp or one hold of the pickaxe
+   undoes in one go.
    =========================================================== */
+#[derive(Clone, Copy)]
+pub struct TileChange {
+    pub x:   usize,
+    pub y:   usize,
+    /// the tile as it was before the action
+    pub old: Tile,
+    /// what the edit left behind – undo is skipped if the tile moved on
+    pub new: TileKind,
+}
+
+/// ring buffer of the last CHANGE_HISTORY_LEN actions, newest at the back
+#[derive(Resource, Default)]
+pub struct ChangeHistory {
+    changes: VecDeque<Vec<TileChange>>,
+    /// further records join the newest entry until the next `begin`
+    open:    bool,
+}
+
+impl ChangeHistory {
+    /// start a new action; records until the next `begin` undo together
+    #[inline]
+    pub fn begin(&mut self) {
+        self.open = false;
+    }
+
+    pub fn record(&mut self, x: usize, y: usize, old: Tile, new: TileKind) {
+        if !self.open || self.changes.is_empty() {
+            if self.changes.len() == CHANGE_HISTORY_LEN {
+                self.changes.pop_front();
+            }
+            self.changes.push_back(Vec::new());
+            self.open = true;
+        }
+        if let Some(entry) = self.changes.back_mut() {
+            entry.push(TileChange { x, y, old, new });
+        }
+    }
+}
+
+/* -----------------------------------------------------------
+   utility: ladder lookup (out of bounds = no ladder)
+   ----------------------------------------------------------- */
+#[inline]
+fn is_ladder(terrain: &Terrain, tx: i32, ty: i32) -> bool {
+    tx >= 0 && ty >= 0
+        && tx < terrain.width as i32 && ty < terrain.height as i32
+        && terrain.tiles[ty as usize][tx as usize].kind == TileKind::Ladder
+}
+
+/// current pickaxe radius (world units), adjusted with +/-
+#[derive(Resource)]
+pub struct MiningRadius(pub f32);
+
+impl Default for MiningRadius {
+    fn default() -> Self {
+        Self(tiles(MINING_RADIUS))
+    }
+}
+
+/// speed factor for a tile `dist` from the cursor: 1 at the centre,
+/// falling linearly to MINING_FALLOFF_MIN at the rim
+#[inline]
+pub fn mining_falloff(dist: f32, radius: f32) -> f32 {
+    (1.0 - dist / radius).max(MINING_FALLOFF_MIN)
+}
+
+/// pickaxe footprint – the circle (default) or only the tile under the
+/// cursor, for careful tunnelling; toggled with `keys.precise_mine`
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MiningMode {
+    #[default]
+    Circle,
+    Single,
+}
+
+/// in‑bounds tiles the pickaxe works on around `world`, with each one's
+/// speed factor (`mining_falloff` in the circle, 1 for a single tile).
+/// The tile under the aim point is always part of the circle, so a small
+/// radius near a tile edge still mines something.
+fn mining_targets(terrain: &Terrain, world: Vec2, r: f32, mode: MiningMode) -> Vec<(usize, usize, f32)> {
+    let in_bounds = |tx: i32, ty: i32| {
+        tx >= 0 && ty >= 0 && tx < terrain.width as i32 && ty < terrain.height as i32
+    };
+    let aimed = world_to_tile(terrain.height, world);
+    if mode == MiningMode::Single {
+        let (tx, ty) = aimed;
+        return if in_bounds(tx, ty) { vec![(tx as usize, ty as usize, 1.0)] } else { Vec::new() };
+    }
+
+    let min_x = ((world.x - r) / tile_size()).floor() as i32;
+    let max_x = ((world.x + r) / tile_size()).ceil()  as i32;
+    let min_y = world_to_tile_y(terrain.height, world.y + r);
+    let max_y = world_to_tile_y(terrain.height, world.y - r);
+
+    let mut out = Vec::new();
+    for ty in min_y..=max_y {
+        for tx in min_x..=max_x {
+            if !in_bounds(tx, ty) {
+                continue;
+            }
+            let dx = tx as f32 * tile_size() - world.x;
+            let dy = tile_to_world_y(terrain.height, ty as usize) - world.y;
+            let dist2 = dx * dx + dy * dy;
+            if dist2 < r * r || (tx, ty) == aimed {
+                out.push((tx as usize, ty as usize, mining_falloff(dist2.sqrt(), r)));
+            }
+        }
+    }
+    out
+}
+
+/* ===========================================================
+   inventory hot‑keys (1 = pickaxe, 2 = gun, 3 = stone, 4 = ladder, 5 = sign,
+   6 = glass, 7 = mirror, 8 = bucket, 9 = booster, 0 = wall; F = back to the
+   previous one), the mining radius (+ / -)
+   and footprint (V: circle / single tile),
+   while holding a block the build stamp (wheel / R to rotate) and while
+   holding the gun the weapon (wheel)
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
 pub fn inventory_input_system(
     keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    pads: Query<&Gamepad>,
+    mut wheel: EventReader<MouseWheel>,
+    free: Res<FreeCam>,
+    mut radius: ResMut<MiningRadius>,
+    mut mode: ResMut<MiningMode>,
+    mut stamp: ResMut<BuildStamp>,
     mut q: Query<&mut Inventory, With<Player>>,
 ) {
+    if keys.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        radius.0 = (radius.0 + tiles(MINING_RADIUS_STEP)).min(tiles(MINING_RADIUS_MAX));
+    }
+    if keys.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        radius.0 = (radius.0 - tiles(MINING_RADIUS_STEP)).max(tiles(MINING_RADIUS_MIN));
+    }
+    if keys.just_pressed(settings.keys.precise_mine) {
+        *mode = match *mode {
+            MiningMode::Circle => MiningMode::Single,
+            MiningMode::Single => MiningMode::Circle,
+        };
+        info!("pickaxe: {:?}", *mode);
+    }
     if let Ok(mut inv) = q.get_single_mut() {
-        if keys.just_pressed(KeyCode::Digit1) {
+        let before = inv.selected;
+        if keys.just_pressed(KeyCode::Digit1) || pad_just_pressed(&pads, PAD_SLOT_1) {
             inv.selected = HeldItem::Pickaxe;
         }
-        if keys.just_pressed(KeyCode::Digit2) {
+        if keys.just_pressed(KeyCode::Digit2) || pad_just_pressed(&pads, PAD_SLOT_2) {
             inv.selected = HeldItem::Gun;
         }
-        if keys.just_pressed(KeyCode::Digit3) {
+        if keys.just_pressed(KeyCode::Digit3) || pad_just_pressed(&pads, PAD_SLOT_3) {
             inv.selected = HeldItem::StoneBlock;
         }
+        if keys.just_pressed(KeyCode::Digit4) || pad_just_pressed(&pads, PAD_SLOT_4) {
+            inv.selected = HeldItem::Ladder;
+        }
+        if keys.just_pressed(KeyCode::Digit5) || pad_just_pressed(&pads, PAD_SLOT_5) {
+            inv.selected = HeldItem::Sign;
+        }
+        if keys.just_pressed(KeyCode::Digit6) || pad_just_pressed(&pads, PAD_SLOT_6) {
+            inv.selected = HeldItem::GlassBlock;
+        }
+        if keys.just_pressed(KeyCode::Digit7) || pad_just_pressed(&pads, PAD_SLOT_7) {
+            inv.selected = HeldItem::Mirror;
+        }
+        if keys.just_pressed(KeyCode::Digit8) || pad_just_pressed(&pads, PAD_SLOT_8) {
+            inv.selected = HeldItem::Bucket;
+        }
+        if keys.just_pressed(KeyCode::Digit9) || pad_just_pressed(&pads, PAD_SLOT_9) {
+            inv.selected = HeldItem::BoosterBlock;
+        }
+        if keys.just_pressed(KeyCode::Digit0) || pad_just_pressed(&pads, PAD_SLOT_10) {
+            inv.selected = HeldItem::Wall;
+        }
+        if inv.selected != before {
+            inv.previous = before;
+        } else if keys.just_pressed(settings.keys.quick_swap) {
+            inv.selected = inv.previous;
+            inv.previous = before;
+        }
+
+        /* the wheel zooms the free camera instead */
+        if block_for(inv.selected).is_some() && !free.active {
+            let notches: f32 = wheel.read()
+                .map(|ev| match ev.unit {
+                    MouseScrollUnit::Line  => ev.y,
+                    MouseScrollUnit::Pixel => ev.y / 100.0,
+                })
+                .sum();
+            if notches.abs() >= 0.5 {
+                stamp.cycle(if notches > 0.0 { -1 } else { 1 });
+            }
+            if keys.just_pressed(KeyCode::KeyR) {
+                stamp.rotate();
+            }
+            return;
+        }
+
+        /* …and, with the gun out, cycles the weapon */
+        if inv.selected == HeldItem::Gun && !free.active {
+            let notches: f32 = wheel.read()
+                .map(|ev| match ev.unit {
+                    MouseScrollUnit::Line  => ev.y,
+                    MouseScrollUnit::Pixel => ev.y / 100.0,
+                })
+                .sum();
+            if notches.abs() >= 0.5 {
+                let all = WeaponStats::ALL;
+                let cur = all.iter().position(|w| *w == inv.weapon).unwrap_or(0);
+                let step = if notches > 0.0 { all.len() - 1 } else { 1 };
+                inv.weapon = all[(cur + step) % all.len()];
+                info!("weapon: {}", inv.weapon.name);
+            }
+            return;
+        }
     }
+    wheel.clear();
 }
 
 /* ===========================================================
    horizontal movement & jump
    =========================================================== */
+/// horizontal input from last frame, for last‑key‑wins SOCD
+#[derive(Default)]
+pub struct HorizontalHeld {
+    left:  bool,
+    right: bool,
+    /// direction of the most recently pressed side (-1 / +1)
+    last:  f32,
+}
+
+/// sprint carried between frames
+#[derive(Default)]
+pub struct Sprint {
+    /// auto‑run switched on (toggle mode only)
+    on:       bool,
+    /// extra x‑velocity on top of walking; eases in and out at
+    /// SPRINT_ACCEL, so a sprint slides to a stop and turns wide
+    momentum: f32,
+    /// the sprint key (a Ctrl) is part of a Ctrl+Z undo – ignored until
+    /// it is let go
+    chord:    bool,
+}
+
+#[allow(clippy::too_many_arguments)]
    pub fn player_input_system(
+    time: Res<Time>,
     keys: Res<ButtonInput<KeyCode>>,
-    mut q: Query<(&mut Velocity, &mut Transform, &Player, Option<&Dashing>)>,
+    settings: Res<Settings>,
+    pads: Query<&Gamepad>,
+    free: Res<FreeCam>,
+    mut held: Local<HorizontalHeld>,
+    mut sprint: Local<Sprint>,
+    mut q: Query<(&mut Velocity, &mut Transform, &mut Player, Option<&Dashing>)>,
 ) {
-    if let Ok((mut vel, mut tf, ply, dash)) = q.get_single_mut() {
+    /* WASD pans the free camera instead */
+    if free.active {
+        if let Ok((mut vel, ..)) = q.get_single_mut() {
+            vel.0.x = 0.0;
+        }
+        return;
+    }
+    if let Ok((mut vel, mut tf, mut ply, dash)) = q.get_single_mut() {
         /* ignore A/D while dashing */
         if dash.is_none() {
-            match (keys.pressed(KeyCode::KeyA), keys.pressed(KeyCode::KeyD)) {
-                (true,  false) => {
-                    vel.0.x = -WALK_SPEED;
-                    tf.scale.x = -tf.scale.x.abs();
-                }
-                (false, true) => {
-                    vel.0.x = WALK_SPEED;
-                    tf.scale.x =  tf.scale.x.abs();
-                }
-                _ => vel.0.x = 0.0,
+            let stick = pad_move_x(&pads);
+            let left  = keys.pressed(KeyCode::KeyA) || stick < 0.0;
+            let right = keys.pressed(KeyCode::KeyD) || stick > 0.0;
+            // both held: the side pressed last wins
+            if left && !held.left {
+                held.last = -1.0;
+            }
+            if right && !held.right {
+                held.last = 1.0;
+            }
+            held.left  = left;
+            held.right = right;
+
+            let dir = match (left, right) {
+                (true,  false) => -1.0,
+                (false, true)  =>  1.0,
+                (true,  true)  => held.last,
+                (false, false) =>  0.0,
+            };
+
+            // a Ctrl sprint key doubles as the undo modifier, so a
+            // press that ends up in Ctrl+Z neither runs nor toggles;
+            // toggle mode flips on release, once the chord is known
+            let sprint_key = settings.keys.sprint;
+            let is_ctrl    = matches!(sprint_key, KeyCode::ControlLeft | KeyCode::ControlRight);
+            if is_ctrl && keys.pressed(sprint_key) && keys.pressed(settings.keys.undo) {
+                sprint.chord = true;
+            }
+            if settings.sprint_toggle && keys.just_released(sprint_key) && !sprint.chord {
+                sprint.on = !sprint.on;
+            }
+            let sprinting = if settings.sprint_toggle {
+                sprint.on
+            } else {
+                keys.pressed(sprint_key) && !sprint.chord
+            };
+            if !keys.pressed(sprint_key) {
+                sprint.chord = false;
+            }
+            // a wall stopped us last frame – no sliding along it
+            if vel.0.x == 0.0 {
+                sprint.momentum = 0.0;
+                ply.knockback   = 0.0;
+            }
+            let target = if sprinting { dir * WALK_SPEED * (SPRINT_MULT - 1.0) } else { 0.0 };
+            let step   = SPRINT_ACCEL * time.delta_secs();
+            sprint.momentum += (target - sprint.momentum).clamp(-step, step);
+
+            let bleed = BLAST_KNOCKBACK_DECAY * time.delta_secs();
+            ply.knockback -= ply.knockback.clamp(-bleed, bleed);
+
+            vel.0.x = dir * WALK_SPEED + sprint.momentum + ply.knockback;
+            if dir != 0.0 {
+                tf.scale.x = tf.scale.x.abs() * dir;
             }
         }
 
-        /* jump still works while dashing */
-        if keys.just_pressed(KeyCode::Space) && ply.grounded {
-            vel.0.y = JUMP_SPEED;
+        /* jump still works while dashing – buffered, fired in physics */
+        if keys.just_pressed(settings.keys.jump) || pad_just_pressed(&pads, PAD_JUMP) {
+            ply.jump_buffer = JUMP_BUFFER_TIME;
         }
     }
 }
 
+/* ===========================================================
+   blast knock‑back – a push away from `centre`, fading to nothing at
+   `radius`.  Only velocity changes; the move itself goes through the
+   stepped collision in `physics_and_collision_system`, so a blast can't
+   carry the player through a wall.  The sideways part lives in
+   `Player::knockback` because input rewrites `vel.x` every frame.
+   =========================================================== */
+pub fn blast_knockback(
+    ply: &mut Player,
+    vel: &mut Velocity,
+    body: Vec2,
+    centre: Vec2,
+    radius: f32,
+    strength: f32,
+) {
+    let away = body - centre;
+    let dist = away.length();
+    if dist >= radius {
+        return;
+    }
+    let dir  = (away.normalize_or(Vec2::Y) + Vec2::Y * BLAST_LIFT).normalize();
+    let push = (dir * strength * (1.0 - dist / radius)).clamp_length_max(BLAST_KNOCKBACK_MAX);
+    ply.knockback = (ply.knockback + push.x).clamp(-BLAST_KNOCKBACK_MAX, BLAST_KNOCKBACK_MAX);
+    // this frame's move too – a standing player must not read as wall‑stopped
+    vel.0.x      += push.x;
+    vel.0.y       = (vel.0.y.max(0.0) + push.y).min(BLAST_KNOCKBACK_MAX);
+}
+
 /* ===========================================================
    physics, stepped collision & jet‑pack exhaust
    =========================================================== */
@@ -89,19 +444,72 @@ pub fn physics_and_collision_system(
     mut commands: Commands,
     time: Res<Time>,
     keys: Res<ButtonInput<KeyCode>>,
-    mut q: Query<(&mut Transform, &mut Velocity, &mut Player, &mut Health)>,
+    settings: Res<Settings>,
+    pads: Query<&Gamepad>,
+    mut q: Query<(&mut Transform, &mut Velocity, &mut Player, &mut Health, &Collider, &CollisionLayers)>,
     terrain: Res<Terrain>,
+    zones: Res<EnvZones>,
+    debug: Res<DebugFlags>,
+    mut shake: ResMut<ScreenShake>,
+    mut pool: ResMut<ParticlePool>,
+    mut step_timer: Local<f32>,
 ) {
     let dt = time.delta_secs();
-    let Ok((mut tf, mut vel, mut ply, mut health)) = q.get_single_mut() else { return };
+    let Ok((mut tf, mut vel, mut ply, mut health, collider, &layers)) = q.get_single_mut() else { return };
+
+    /* debug noclip: fly with WASD, no gravity, no tile checks */
+    if debug.noclip {
+        let mut dir = Vec2::new(pad_move_x(&pads), pad_move_y(&pads));
+        if keys.pressed(KeyCode::KeyA) { dir.x -= 1.0; }
+        if keys.pressed(KeyCode::KeyD) { dir.x += 1.0; }
+        if keys.pressed(KeyCode::KeyS) { dir.y -= 1.0; }
+        if keys.pressed(KeyCode::KeyW) { dir.y += 1.0; }
+        vel.0 = dir.normalize_or_zero() * NOCLIP_SPEED;
+        let max_x = (terrain.width - 1) as f32 * tile_size();
+        let min_y = tile_to_world_y(terrain.height, terrain.height - 1);
+        let max_y = tile_to_world_y(terrain.height, 0);
+        tf.translation.x = (tf.translation.x + vel.0.x * dt).clamp(0.0, max_x);
+        tf.translation.y = (tf.translation.y + vel.0.y * dt).clamp(min_y, max_y);
+        ply.grounded = false;
+        return;
+    }
 
-    vel.0.y += GRAVITY * dt;
-    if keys.pressed(KeyCode::Space) && !ply.grounded {
-        vel.0.y += JET_ACCEL * dt;
+    let jet_held = keys.pressed(settings.keys.jump) || pad_pressed(&pads, PAD_JET);
+    let half = collider.half;
+
+    /* ladder: climb while the centre or the feet are on a ladder tile */
+    let stick_y   = pad_move_y(&pads);
+    let up_held   = keys.pressed(KeyCode::KeyW) || stick_y > 0.0;
+    let down_held = keys.pressed(KeyCode::KeyS) || stick_y < 0.0;
+    let ptx       = (tf.translation.x / tile_size()).floor() as i32;
+    let on_ladder = is_ladder(&terrain, ptx, world_to_tile_y(terrain.height, tf.translation.y))
+        || is_ladder(&terrain, ptx, world_to_tile_y(terrain.height, tf.translation.y - half.y + 0.1));
+    let climbing  = on_ladder && up_held != down_held;
+    if on_ladder {
+        ply.coyote = COYOTE_TIME; // jumping off a ladder is always allowed
+    }
+
+    /* buffered jump fires while grounded or within coyote time */
+    if ply.jump_buffer > 0.0 && ply.coyote > 0.0 {
+        vel.0.y         = JUMP_SPEED;
+        ply.jump_buffer = 0.0;
+        ply.coyote      = 0.0;
+    }
+
+    if climbing {
+        // no gravity, no jump arc – straight up / down
+        vel.0.y = if up_held { CLIMB_SPEED } else { -CLIMB_SPEED };
+    } else {
+        // thinner air up high: less gravity, some drag (see env_zone.rs)
+        let (gravity, drag) = zones.at(terrain.height, tf.translation.y);
+        vel.0.y += GRAVITY * gravity * dt;
+        if jet_held && !ply.grounded {
+            vel.0.y += JET_ACCEL * dt;
+        }
+        vel.0.y -= vel.0.y * (drag * dt).min(1.0);
     }
 
     let step_dt = dt / COLLISION_STEPS as f32;
-    let half = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) / 2.0;
     ply.grounded = false;
     let mut landing_speed: Option<f32> = None;
 
@@ -111,20 +519,20 @@ pub fn physics_and_collision_system(
             let new_x = tf.translation.x + vel.0.x * step_dt;
             let dir = vel.0.x.signum();
             let probe_x = new_x + dir * half.x;
-            let tx = (probe_x / TILE_SIZE).floor() as i32;
+            let tx = (probe_x / tile_size()).floor() as i32;
 
             let y_top = world_to_tile_y(terrain.height, tf.translation.y + half.y - 0.1);
             let y_bot = world_to_tile_y(terrain.height, tf.translation.y - half.y + 0.1);
             let (y_min, y_max) = if y_top <= y_bot { (y_top, y_bot) } else { (y_bot, y_top) };
 
             // ─── try stepping up when the tile in front is solid ────────────────
-            if (y_min..=y_max).any(|ty| solid(&terrain, tx, ty)) {
+            if (y_min..=y_max).any(|ty| solid_for(&terrain, tx, ty, layers, Approach::Side)) {
                 // Progressive search: walk up slopes up to MAX_STEP_HEIGHT pixels high
                 let mut stepped = false;
 
                 // Don’t interfere while the player is moving upward (jumping)
                 if vel.0.y <= 0.0 {
-                    for h in 1..=MAX_STEP_HEIGHT as i32 {
+                    for h in 1..=tiles(MAX_STEP_HEIGHT) as i32 {
                         let lifted = tf.translation.y + h as f32;
 
                         let ty_top = world_to_tile_y(terrain.height, lifted + half.y - 0.1);
@@ -133,7 +541,7 @@ pub fn physics_and_collision_system(
                                         else                  { (ty_bot, ty_top) };
 
                         // Is there clear space at this height?
-                        if !(smin..=smax).any(|ty| solid(&terrain, tx, ty)) {
+                        if !(smin..=smax).any(|ty| solid_for(&terrain, tx, ty, layers, Approach::Side)) {
                             tf.translation.y += h as f32;   // climb
                             tf.translation.x  = new_x;      // move forward
                             ply.grounded      = true;
@@ -160,10 +568,26 @@ pub fn physics_and_collision_system(
             let probe_y = new_y + dir * half.y;
             let ty = world_to_tile_y(terrain.height, probe_y);
 
-            let x_left  = ((tf.translation.x - half.x + 0.1) / TILE_SIZE).floor() as i32;
-            let x_right = ((tf.translation.x + half.x - 0.1) / TILE_SIZE).floor() as i32;
+            let x_left  = ((tf.translation.x - half.x + 0.1) / tile_size()).floor() as i32;
+            let x_right = ((tf.translation.x + half.x - 0.1) / tile_size()).floor() as i32;
+
+            // the top rung of a ladder is a one‑way platform unless S is held,
+            // so climbing out of a shaft ends standing on it
+            let falling  = vel.0.y < 0.0;
+            let bottom   = tf.translation.y - half.y;
+            let rung_top = (terrain.height as i32 - ty) as f32 * tile_size();
+            let on_rung  = |tx: i32| {
+                falling && !down_held && bottom >= rung_top - 0.1
+                    && is_ladder(&terrain, tx, ty) && !is_ladder(&terrain, tx, ty - 1)
+            };
+            // platforms work the same way, through `solid_for`
+            let approach = if falling {
+                Approach::Falling { feet: bottom, drop_through: down_held }
+            } else {
+                Approach::Rising
+            };
 
-            if (x_left..=x_right).any(|tx| solid(&terrain, tx, ty)) {
+            if (x_left..=x_right).any(|tx| solid_for(&terrain, tx, ty, layers, approach) || on_rung(tx)) {
                 if vel.0.y < 0.0 {
                     ply.grounded = true;
                     landing_speed = Some(-vel.0.y);
@@ -175,12 +599,33 @@ pub fn physics_and_collision_system(
         }
     }
 
+    /* jump‑feel timers */
+    ply.coyote = if ply.grounded { COYOTE_TIME } else { (ply.coyote - dt).max(0.0) };
+    ply.jump_buffer = (ply.jump_buffer - dt).max(0.0);
+
+    /* dust: a puff on landing (bigger the harder), steps while running */
+    let feet = Vec2::new(tf.translation.x, tf.translation.y - half.y);
+    if let Some(v) = landing_speed.filter(|&v| v > DUST_MIN_LAND_SPEED) {
+        let n = ((v * DUST_PER_LAND_SPEED) as usize).clamp(1, DUST_LAND_MAX);
+        spawn_dust(&mut commands, &mut pool, &terrain, feet, n);
+    }
+    if ply.grounded && vel.0.x != 0.0 {
+        *step_timer -= dt;
+        if *step_timer <= 0.0 {
+            *step_timer = DUST_STEP_INTERVAL;
+            spawn_dust(&mut commands, &mut pool, &terrain, feet, DUST_STEP_COUNT);
+        }
+    } else {
+        *step_timer = 0.0; // first step after stopping puffs at once
+    }
+
     /* after the collision loop, before the jet‑pack code */
     if let Some(v) = landing_speed {
-        if v > SAFE_FALL_SPEED {
+        if v > SAFE_FALL_SPEED && !debug.invincible {
             let dmg = (v - SAFE_FALL_SPEED) * FALL_DMG_FACTOR;
             health.current = (health.current - dmg).max(0.0);
             health.last_damage = 0.0;
+            shake.add(dmg * SHAKE_PER_FALL_DAMAGE);
 
             // optional VFX / death check:
             // if health.current == 0.0 { commands.entity(entity).despawn(); }
@@ -188,29 +633,22 @@ pub fn physics_and_collision_system(
     }
 
     /* jet‑pack exhaust */
-    if keys.pressed(KeyCode::Space) && !ply.grounded {
+    if jet_held && !ply.grounded {
         let mut rng = rand::thread_rng();
-        for _ in 0..EXHAUST_RATE {
-            commands.spawn((
-                SpriteBundle {
-                    sprite: Sprite {
-                        color: EXHAUST_COLOR,
-                        custom_size: Some(Vec2::splat(EXHAUST_SIZE)),
-                        ..default()
-                    },
-                    transform: Transform::from_xyz(
-                        tf.translation.x + rng.gen_range(-2.0..2.0),
-                        tf.translation.y - half.y,
-                        5.0,
-                    ),
-                    ..default()
-                },
-                Velocity(Vec2::new(
-                    rng.gen_range(EXHAUST_SPEED_X.clone()),
-                    rng.gen_range(EXHAUST_SPEED_Y.clone()),
-                )),
-                Exhaust { life: EXHAUST_LIFETIME },
-            ));
+        for _ in 0..pool.scaled(EXHAUST_RATE) {
+            let pos = Vec3::new(
+                tf.translation.x + rng.gen_range(-2.0..2.0),
+                tf.translation.y - half.y,
+                5.0,
+            );
+            let vel = Vec2::new(
+                rng.gen_range(EXHAUST_SPEED_X.clone()),
+                rng.gen_range(EXHAUST_SPEED_Y.clone()),
+            );
+            pool.spawn(
+                &mut commands, ParticleKind::Exhaust, EXHAUST_COLOR, EXHAUST_SIZE,
+                pos, vel, EXHAUST_LIFETIME,
+            );
         }
     }
 }
@@ -219,10 +657,15 @@ pub fn physics_and_collision_system(
    dash start (Shift)                                          */
    pub fn dash_start_system(
     mut commands: Commands,
+    mut pool: ResMut<ParticlePool>,
     keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    pads: Query<&Gamepad>,
     mut q: Query<(Entity, &mut Velocity, &Transform), (With<Player>, Without<Dashing>)>,
 ) {
-    if !(keys.just_pressed(KeyCode::ShiftLeft) || keys.just_pressed(KeyCode::ShiftRight)) {
+    let dash_key = keys.just_pressed(settings.keys.dash)
+        || settings.keys.dash_alt.is_some_and(|k| keys.just_pressed(k));
+    if !(dash_key || pad_just_pressed(&pads, PAD_DASH)) {
         return;
     }
 
@@ -234,29 +677,22 @@ pub fn physics_and_collision_system(
         {
             use rand::Rng;
             let mut rng = rand::thread_rng();
-            for _ in 0..DASH_PUFF_RATE {
-                commands.spawn((
-                    SpriteBundle {
-                        sprite: Sprite {
-                            color: Color::rgba(0.9, 0.9, 0.9, 1.0),
-                            custom_size: Some(Vec2::splat(DASH_PUFF_SIZE)),
-                            ..default()
-                        },
-                        transform: Transform::from_xyz(
-                            tf.translation.x - dir * PLAYER_WIDTH * 0.6
-                                + rng.gen_range(-2.0..2.0),
-                            tf.translation.y - PLAYER_HEIGHT * 0.2
-                                + rng.gen_range(-2.0..2.0),
-                            5.0,
-                        ),
-                        ..default()
-                    },
-                    Velocity(Vec2::new(
-                        -dir * rng.gen_range(80.0..140.0),
-                        rng.gen_range(-20.0..40.0),
-                    )),
-                    Exhaust { life: DASH_PUFF_LIFETIME },
-                ));
+            for _ in 0..pool.scaled(DASH_PUFF_RATE) {
+                let pos = Vec3::new(
+                    tf.translation.x - dir * PLAYER_WIDTH * 0.6
+                        + rng.gen_range(-2.0..2.0),
+                    tf.translation.y - PLAYER_HEIGHT * 0.2
+                        + rng.gen_range(-2.0..2.0),
+                    5.0,
+                );
+                let vel = Vec2::new(
+                    -dir * rng.gen_range(80.0..140.0),
+                    rng.gen_range(-20.0..40.0),
+                );
+                pool.spawn(
+                    &mut commands, ParticleKind::Exhaust, Color::rgba(0.9, 0.9, 0.9, 1.0),
+                    DASH_PUFF_SIZE, pos, vel, DASH_PUFF_LIFETIME,
+                );
             }
         }
         commands.entity(entity).insert(Dashing {
@@ -296,62 +732,135 @@ pub fn dash_update_system(
    =========================================================== */
 pub fn pickaxe_mining_system(
     mouse: Res<ButtonInput<MouseButton>>,
-    windows: Query<&Window>,
-    cam_q: Query<(&Camera, &GlobalTransform)>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
     mut terrain: ResMut<Terrain>,
     mut commands: Commands,
-    inv_q: Query<&Inventory, With<Player>>,
+    mut inv_q: Query<&mut Inventory, With<Player>>,
     player_q: Query<&Transform, With<Player>>,
+    mut pool: ResMut<ParticlePool>,
+    radius: Res<MiningRadius>,
+    mut history: ResMut<ChangeHistory>,
+    mode: Res<MiningMode>,
+    mut cracks: ResMut<MiningCracks>,
+    mut stats: ResMut<Stats>,
 ) {
-    let Ok(inv) = inv_q.get_single() else { return };
+    let Ok(mut inv) = inv_q.get_single_mut() else { return };
     let Ok(player_tf) = player_q.get_single() else { return };
     let player_pos = player_tf.translation.truncate();
-    if inv.selected != HeldItem::Pickaxe || !mouse.pressed(MouseButton::Left) {
+    let using = mouse.pressed(MouseButton::Left) || pad_pressed(&pads, PAD_USE);
+    if inv.selected != HeldItem::Pickaxe || !using {
         return;
     }
+    if mouse.just_pressed(MouseButton::Left) || pad_just_pressed(&pads, PAD_USE) {
+        history.begin(); // one hold of the pickaxe = one undo
+    }
 
-    let window = windows.single();
-    let Some(cursor) = window.cursor_position() else { return };
-    let (cam, cam_tf) = cam_q.single();
-    let Ok(world) = cam.viewport_to_world_2d(cam_tf, cursor) else { return };
-    if (world - player_pos).length_squared() > DIG_RADIUS * DIG_RADIUS {
+    let Some(world) = aim.point else { return };
+    if (world - player_pos).length_squared() > tiles(DIG_RADIUS).powi(2) {
         return; // cursor out of reach
     }
 
-    let min_x = ((world.x - MINING_RADIUS) / TILE_SIZE).floor() as i32;
-    let max_x = ((world.x + MINING_RADIUS) / TILE_SIZE).ceil()  as i32;
-
-    let min_y_world = world.y - MINING_RADIUS;
-    let max_y_world = world.y + MINING_RADIUS;
-    let min_y = world_to_tile_y(terrain.height, max_y_world);
-    let max_y = world_to_tile_y(terrain.height, min_y_world);
-
     let dt = 1.0 / 60.0;
 
-    for ty in min_y..=max_y {
-        for tx in min_x..=max_x {
-            if tx < 0 || ty < 0 ||
-               tx >= terrain.width as i32 || ty >= terrain.height as i32 {
-                continue;
-            }
-            let dx = tx as f32 * TILE_SIZE - world.x;
-            let dy = tile_to_world_y(terrain.height, ty as usize) - world.y;
-            if dx * dx + dy * dy >= MINING_RADIUS * MINING_RADIUS {
-                continue;
-            }
+    for (ux, uy, falloff) in mining_targets(&terrain, world, radius.0, *mode) {
+        let tile = &mut terrain.tiles[uy][ux];
+        if !minable_kind(tile.kind) {
+            continue;
+        }
+        if !inv.can_mine(tile.kind) {
+            continue; // too hard for this pickaxe (highlighted grey)
+        }
 
-            let (ux, uy) = (tx as usize, ty as usize);
-            let tile = &mut terrain.tiles[uy][ux];
-            if !matches!(tile.kind, TileKind::Dirt | TileKind::Stone | TileKind::Obsidian | TileKind::Grass | TileKind::Snow) {
-                continue;
+        let full = cracks.touch(ux, uy, tile.mine_time);
+        tile.mine_time -= dt * PICKAXE_SPEED * inv.pick_tier.speed() * falloff;
+        if tile.mine_time <= 0.0 {
+            if let Some(item) = tile_drop(tile.kind) {
+                inv.add(item, 1);
             }
+            history.record(ux, uy, Tile { mine_time: full, ..*tile }, TileKind::Air);
+            terrain.clear(ux, uy);
+            stats.tiles_mined += 1;
+            spawn_debris(&mut commands, &mut pool, &terrain, ux, uy);
+        }
+    }
+}
+
+/* ===========================================================
+   tunnel (hold X with the pickaxe) – dig straight down
 
-            tile.mine_time -= dt * PICKAXE_SPEED;
-            if tile.mine_time <= 0.0 {
-                tile.kind = TileKind::Air;
-                terrain.changed_tiles.push_back((ux, uy));
-                spawn_debris(&mut commands, &terrain, ux, uy);
+   While the player stands still, the row directly under the feet
+   is mined over time.  The body is wider than a tile, so the shaft
+   spans every column the body covers.  Each time the player drops
+   into the shaft, the row they left gets a ladder in the centre
+   column so the way back up is always climbable.
+   =========================================================== */
+pub fn tunnel_system(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    pads: Query<&Gamepad>,
+    mut terrain: ResMut<Terrain>,
+    mut commands: Commands,
+    mut q: Query<(&Transform, &Velocity, &Player, &mut Inventory)>,
+    mut pool: ResMut<ParticlePool>,
+    mut history: ResMut<ChangeHistory>,
+    mut cracks: ResMut<MiningCracks>,
+) {
+    let Ok((tf, vel, ply, mut inv)) = q.get_single_mut() else { return };
+    let held = keys.pressed(KeyCode::KeyX) || pad_pressed(&pads, PAD_TUNNEL);
+    if inv.selected != HeldItem::Pickaxe || !held {
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyX) || pad_just_pressed(&pads, PAD_TUNNEL) {
+        history.begin(); // the whole shaft dug in one hold undoes together
+    }
+    // only while standing still on solid ground
+    if !ply.grounded || vel.0.x.abs() > 1.0 {
+        return;
+    }
+
+    let half    = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) / 2.0;
+    let x_left  = ((tf.translation.x - half.x + 0.1) / tile_size()).floor() as i32;
+    let x_right = ((tf.translation.x + half.x - 0.1) / tile_size()).floor() as i32;
+    let x_mid   = (tf.translation.x / tile_size()).floor() as i32;
+    let feet    = world_to_tile_y(terrain.height, tf.translation.y - half.y + 0.1);
+    let below   = feet + 1;
+    if below >= terrain.height as i32 {
+        return;
+    }
+
+    /* ladder in the row the feet occupy (left behind once we drop) */
+    if x_mid >= 0 && x_mid < terrain.width as i32 && feet >= 0 {
+        let (ux, uy) = (x_mid as usize, feet as usize);
+        let old = terrain.tiles[uy][ux];
+        if matches!(old.kind, TileKind::Air | TileKind::Sky) {
+            history.record(ux, uy, old, TileKind::Ladder);
+            terrain.tiles[uy][ux].kind = TileKind::Ladder;
+            terrain.tiles[uy][ux].mine_time = LADDER_MINE_TIME;
+            terrain.touch(ux, uy);
+        }
+    }
+
+    /* mine the row under the feet */
+    let dt = time.delta_secs();
+    for tx in x_left..=x_right {
+        if tx < 0 || tx >= terrain.width as i32 {
+            continue;
+        }
+        let (ux, uy) = (tx as usize, below as usize);
+        let tile = &mut terrain.tiles[uy][ux];
+        if !solid_kind(tile.kind) || !inv.can_mine(tile.kind) {
+            continue;
+        }
+        let full = cracks.touch(ux, uy, tile.mine_time);
+        tile.mine_time -= dt * PICKAXE_SPEED * inv.pick_tier.speed();
+        if tile.mine_time <= 0.0 {
+            if let Some(item) = tile_drop(tile.kind) {
+                inv.add(item, 1);
             }
+            history.record(ux, uy, Tile { mine_time: full, ..*tile }, TileKind::Air);
+            terrain.clear(ux, uy);
+            spawn_debris(&mut commands, &mut pool, &terrain, ux, uy);
         }
     }
 }
@@ -361,11 +870,15 @@ pub fn pickaxe_mining_system(
    =========================================================== */
    pub fn cursor_highlight_system(
     mut commands: Commands,
-    windows: Query<&Window>,
-    cam_q: Query<(&Camera, &GlobalTransform)>,
+    aim: Res<GamepadAim>,
+    drag: Res<BuildDrag>,
+    stamp: Res<BuildStamp>,
     inv_q: Query<&Inventory, With<Player>>,
     player_q: Query<&Transform, With<Player>>,
+    enemy_q: Query<&Transform, With<Enemy>>,
     terrain: Res<Terrain>,
+    radius: Res<MiningRadius>,
+    mode: Res<MiningMode>,
     old: Query<Entity, With<Highlight>>,   // clear previous frame
 ) {
     // despawn previous highlights
@@ -374,84 +887,131 @@ pub fn pickaxe_mining_system(
     }
 
     let Ok(inv) = inv_q.get_single()            else { return };
-    let window  =        windows.single();
-    let Some(cursor) = window.cursor_position() else { return };
-    let (cam, cam_tf)    = cam_q.single();
-    let Ok(world) = cam.viewport_to_world_2d(cam_tf, cursor) else { return };
+    let Some(world) = aim.point                 else { return };
     let Ok(player_tf) = player_q.get_single() else { return };
     let player_pos = player_tf.translation.truncate();
-    let too_far = (world - player_pos).length_squared() > DIG_RADIUS * DIG_RADIUS;
+    let too_far = (world - player_pos).length_squared() > tiles(DIG_RADIUS).powi(2);
 
     match inv.selected {
-        /* ---------- pickaxe: opaque‑red squares in mining radius ---------- */
+        /* ---------- pickaxe: opaque‑red squares in mining radius
+                      (just the one under the cursor in single mode) ---------- */
         HeldItem::Pickaxe => {
             if too_far {
                 return; // skip red highlight when the cursor is beyond dig range
             }
-            let min_x = ((world.x - MINING_RADIUS) / TILE_SIZE).floor() as i32;
-            let max_x = ((world.x + MINING_RADIUS) / TILE_SIZE).ceil()  as i32;
-            let min_y_world = world.y - MINING_RADIUS;
-            let max_y_world = world.y + MINING_RADIUS;
-            let min_y = world_to_tile_y(terrain.height, max_y_world);
-            let max_y = world_to_tile_y(terrain.height, min_y_world);
-
-            for ty in min_y..=max_y {
-                for tx in min_x..=max_x {
-                    if tx < 0 || ty < 0 ||
-                       tx >= terrain.width as i32 || ty >= terrain.height as i32 {
-                        continue;
-                    }
-                    let dx = tx as f32 * TILE_SIZE - world.x;
-                    let dy = tile_to_world_y(terrain.height, ty as usize) - world.y;
-                    if dx*dx + dy*dy >= MINING_RADIUS*MINING_RADIUS { continue; }
-
-                    let (ux, uy) = (tx as usize, ty as usize);
-                    if matches!(terrain.tiles[uy][ux].kind,
-                        TileKind::Grass | TileKind::Dirt | TileKind::Stone | TileKind::Obsidian | TileKind::Snow)
-                    {
-                        commands.spawn((
-                            Sprite {
-                                color: Color::rgba(1.0, 0.0, 0.0, 0.4),
-                                custom_size: Some(Vec2::splat(TILE_SIZE)),
-                                ..default()
-                            },
-                            Transform::from_xyz(
-                                ux as f32 * TILE_SIZE,
-                                tile_to_world_y(terrain.height, uy),
-                                20.0,
-                            ),
-                            Highlight,
-                        ));
-                    }
+            for (ux, uy, falloff) in mining_targets(&terrain, world, radius.0, *mode) {
+                let kind = terrain.tiles[uy][ux].kind;
+                if minable_kind(kind) {
+                    // red fades with the mining falloff;
+                    // grey = too hard for the current pickaxe tier
+                    let color = if inv.can_mine(kind) {
+                        Color::rgba(1.0, 0.0, 0.0, 0.15 + 0.45 * falloff)
+                    } else {
+                        Color::rgba(0.5, 0.5, 0.5, 0.5)
+                    };
+                    commands.spawn((
+                        Sprite {
+                            color,
+                            custom_size: Some(Vec2::splat(tile_size())),
+                            ..default()
+                        },
+                        Transform::from_xyz(
+                            ux as f32 * tile_size(),
+                            tile_to_world_y(terrain.height, uy),
+                            20.0,
+                        ),
+                        Highlight,
+                    ));
                 }
             }
         }
 
-        /* ---------- building: single green square if placeable ----------- */
-        HeldItem::StoneBlock => {
-            let tx = (world.x / TILE_SIZE).floor() as i32;
-            let ty = world_to_tile_y(terrain.height, world.y);
-            if tx < 0 || ty < 0 ||
-               tx >= terrain.width as i32 || ty >= terrain.height as i32 {
-                return;
+        /* ---------- gun: reticle + predicted arc up to the first wall ------ */
+        HeldItem::Gun => {
+            let dir = (world - player_pos).normalize_or_zero();
+            if dir != Vec2::ZERO {
+                for (i, p) in bullet_arc(&terrain, player_pos, dir, inv.weapon).enumerate() {
+                    let fade = 1.0 - i as f32 / AIM_DOT_COUNT as f32;
+                    commands.spawn((
+                        Sprite {
+                            color: Color::srgba(1.0, 0.85, 0.3, 0.6 * fade),
+                            custom_size: Some(Vec2::splat(AIM_DOT_SIZE)),
+                            ..default()
+                        },
+                        Transform::from_translation(p.extend(20.0)),
+                        Highlight,
+                    ));
+                }
             }
-            let (ux, uy) = (tx as usize, ty as usize);
-            if !matches!(terrain.tiles[uy][ux].kind, TileKind::Air | TileKind::Sky) {
-                return; // occupied
+            // cross‑hair: one horizontal + one vertical bar
+            for size in [Vec2::new(RETICLE_SIZE, 1.5), Vec2::new(1.5, RETICLE_SIZE)] {
+                commands.spawn((
+                    Sprite {
+                        color: Color::srgba(1.0, 1.0, 1.0, 0.8),
+                        custom_size: Some(size),
+                        ..default()
+                    },
+                    Transform::from_translation(world.extend(21.0)),
+                    Highlight,
+                ));
             }
-            if ![(-1,0),(1,0),(0,-1),(0,1)].iter()
-                .any(|(dx,dy)| solid(&terrain, tx+dx, ty+dy))
-            {
-                return; // no solid neighbour
+        }
+
+        /* ---------- building: green squares for the whole drag region ---- */
+        /* ---------- (or the stamp ghost, red where a tile won't fit) ----- */
+        HeldItem::StoneBlock | HeldItem::GlassBlock | HeldItem::BoosterBlock => {
+            let Some((item, _)) = block_for(inv.selected) else { return };
+            let cur    = world_to_tile(terrain.height, world);
+            let bodies: Vec<Vec2> = player_q.iter().chain(enemy_q.iter())
+                .map(|tf| tf.translation.truncate())
+                .collect();
+            let (plan, blocked) = if stamp.is_drag() {
+                let start = drag.start.unwrap_or(cur);
+                (plan_placement(&terrain, start, cur, &bodies, inv.count(item)), Vec::new())
+            } else {
+                let cells = stamp.cells(cur);
+                let plan  = plan_cells(&terrain, &cells, &bodies, inv.count(item));
+                let blocked: Vec<(usize, usize)> = cells.iter()
+                    .filter(|&&(tx, ty)| {
+                        tx >= 0 && ty >= 0
+                            && tx < terrain.width as i32 && ty < terrain.height as i32
+                    })
+                    .map(|&(tx, ty)| (tx as usize, ty as usize))
+                    .filter(|t| !plan.contains(t))
+                    .collect();
+                (plan, blocked)
+            };
+
+            let ghost = plan.into_iter().map(|t| (t, Color::rgba(0.0, 1.0, 0.0, 0.4)))
+                .chain(blocked.into_iter().map(|t| (t, Color::rgba(1.0, 0.0, 0.0, 0.3))));
+            for ((ux, uy), color) in ghost {
+                commands.spawn((
+                    Sprite {
+                        color,
+                        custom_size: Some(Vec2::splat(tile_size())),
+                        ..default()
+                    },
+                    Transform::from_xyz(
+                        ux as f32 * tile_size(),
+                        tile_to_world_y(terrain.height, uy),
+                        20.0,
+                    ),
+                    Highlight,
+                ));
             }
+        }
+
+        /* ---------- ladder: single green square on an empty tile --------- */
+        HeldItem::Ladder => {
+            let Some((ux, uy)) = ladder_target(&terrain, world, player_pos) else { return };
             commands.spawn((
                 Sprite {
                     color: Color::rgba(0.0, 1.0, 0.0, 0.4),
-                    custom_size: Some(Vec2::splat(TILE_SIZE)),
+                    custom_size: Some(Vec2::splat(tile_size())),
                     ..default()
                 },
                 Transform::from_xyz(
-                    ux as f32 * TILE_SIZE,
+                    ux as f32 * tile_size(),
                     tile_to_world_y(terrain.height, uy),
                     20.0,
                 ),
@@ -462,67 +1022,438 @@ pub fn pickaxe_mining_system(
     }
 }
 
+/// dot positions along a bullet's flight, integrated the same way as
+/// `bullet_update_system` (the weapon's gravity, no spread or liquid
+/// drag); stops at the first solid tile
+fn bullet_arc(
+    terrain: &Terrain,
+    origin: Vec2,
+    dir: Vec2,
+    weapon: WeaponStats,
+) -> impl Iterator<Item = Vec2> + '_ {
+    const SUBSTEPS: usize = 4;
+    let dt = AIM_DOT_INTERVAL / SUBSTEPS as f32;
+    let (mut pos, mut vel) = (origin, dir * weapon.muzzle_velocity);
+    let mut blocked = false;
+
+    (0..AIM_DOT_COUNT).map_while(move |_| {
+        for _ in 0..SUBSTEPS {
+            vel.y += GRAVITY * dt * weapon.gravity_factor;
+            pos += vel * dt;
+            let tx = (pos.x / tile_size()).round() as i32;
+            blocked |= solid(terrain, tx, world_to_tile_y(terrain.height, pos.y));
+        }
+        (!blocked).then_some(pos)
+    })
+}
+
 /* ===========================================================
-   place Stone block (HeldItem::StoneBlock)
+   place blocks (stone / glass) – click for one, drag for a rectangle
    =========================================================== */
+/// largest drag span per axis (tiles)
+const BUILD_MAX_SPAN: i32 = 16;
+
+/// tile where the current build drag started (`None` = not dragging)
+#[derive(Resource, Default)]
+pub struct BuildDrag {
+    pub start: Option<(i32, i32)>,
+}
+
+/// prefab shapes cycled with the scroll wheel while holding a block.
+/// Offsets are (dx, dy) tiles from the cursor, +dy = one row down;
+/// entry 0 is the plain click / drag‑rectangle mode.
+const STAMPS: &[(&str, &[(i32, i32)])] = &[
+    ("single / drag", &[(0, 0)]),
+    ("platform 3×3",  &[(-1,-1),(0,-1),(1,-1),(-1,0),(0,0),(1,0),(-1,1),(0,1),(1,1)]),
+    ("floor 5×1",     &[(-2,0),(-1,0),(0,0),(1,0),(2,0)]),
+    ("wall 1×4",      &[(0,0),(0,-1),(0,-2),(0,-3)]),
+    ("stairs",        &[(-1,0),(0,0),(1,0),(0,-1),(1,-1),(1,-2)]),
+    ("frame 3×3",     &[(-1,-1),(0,-1),(1,-1),(-1,0),(1,0),(-1,1),(0,1),(1,1)]),
+];
+
+/// selected build stamp (wheel) and its rotation (R)
+#[derive(Resource)]
+pub struct BuildStamp {
+    pub index:    usize,
+    /// quarter turns clockwise
+    pub rotation: u8,
+    /// tile offsets of the stamp after rotation
+    pub offsets:  Vec<(i32, i32)>,
+}
+
+impl Default for BuildStamp {
+    fn default() -> Self {
+        Self { index: 0, rotation: 0, offsets: vec![(0, 0)] }
+    }
+}
+
+impl BuildStamp {
+    /// plain click / drag placement instead of a prefab
+    #[inline]
+    pub fn is_drag(&self) -> bool {
+        self.index == 0
+    }
+
+    pub fn cycle(&mut self, step: i32) {
+        self.index = (self.index as i32 + step).rem_euclid(STAMPS.len() as i32) as usize;
+        self.rebuild();
+    }
+
+    pub fn rotate(&mut self) {
+        self.rotation = (self.rotation + 1) % 4;
+        self.rebuild();
+    }
+
+    fn rebuild(&mut self) {
+        let (name, cells) = STAMPS[self.index];
+        self.offsets = cells.iter()
+            .map(|&(x, y)| (0..self.rotation).fold((x, y), |(x, y), _| (-y, x)))
+            .collect();
+        info!("build stamp: {name} ({}°)", self.rotation as u32 * 90);
+    }
+
+    /// stamp tiles with its origin on tile `at`
+    pub fn cells(&self, at: (i32, i32)) -> Vec<(i32, i32)> {
+        self.offsets.iter().map(|&(dx, dy)| (at.0 + dx, at.1 + dy)).collect()
+    }
+}
+
+/// item consumed and tile placed for a held building block
+#[inline]
+fn block_for(held: HeldItem) -> Option<(ItemKind, TileKind)> {
+    match held {
+        HeldItem::StoneBlock => Some((ItemKind::Stone, TileKind::Stone)),
+        HeldItem::GlassBlock => Some((ItemKind::Glass, TileKind::Glass)),
+        HeldItem::BoosterBlock => Some((ItemKind::Booster, TileKind::Booster)),
+        _                    => None,
+    }
+}
+
+/// does a player‑sized body centred at `pos` overlap tile (tx, ty)?
+#[inline]
+fn body_covers(terrain: &Terrain, pos: Vec2, tx: i32, ty: i32) -> bool {
+    let half = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) / 2.0;
+    let x_min = ((pos.x - half.x) / tile_size()).floor() as i32;
+    let x_max = ((pos.x + half.x) / tile_size()).floor() as i32;
+    let y_min = world_to_tile_y(terrain.height, pos.y + half.y);
+    let y_max = world_to_tile_y(terrain.height, pos.y - half.y);
+    (x_min..=x_max).contains(&tx) && (y_min..=y_max).contains(&ty)
+}
+
+/// every tile in the `a`–`b` rectangle that can take a block, at most
+/// `budget` of them (see `plan_cells`)
+fn plan_placement(
+    terrain: &Terrain,
+    a: (i32, i32),
+    b: (i32, i32),
+    bodies: &[Vec2],
+    budget: u32,
+) -> Vec<(usize, usize)> {
+    let bx = b.0.clamp(a.0 - BUILD_MAX_SPAN + 1, a.0 + BUILD_MAX_SPAN - 1);
+    let by = b.1.clamp(a.1 - BUILD_MAX_SPAN + 1, a.1 + BUILD_MAX_SPAN - 1);
+    let (x0, x1) = (a.0.min(bx), a.0.max(bx));
+    let (y0, y1) = (a.1.min(by), a.1.max(by));
+
+    let cells: Vec<(i32, i32)> = (y0..=y1)
+        .flat_map(|ty| (x0..=x1).map(move |tx| (tx, ty)))
+        .collect();
+    plan_cells(terrain, &cells, bodies, budget)
+}
+
+/// the `cells` that can take a block, at most `budget` of them.  Each
+/// needs a solid neighbour, where blocks planned earlier in the same
+/// placement count as solid, so rows grow off the ground.
+fn plan_cells(
+    terrain: &Terrain,
+    cells: &[(i32, i32)],
+    bodies: &[Vec2],
+    budget: u32,
+) -> Vec<(usize, usize)> {
+    let mut planned: HashSet<(i32, i32)> = HashSet::new();
+    let mut order:   Vec<(usize, usize)> = Vec::new();
+
+    'grow: loop {
+        let mut grew = false;
+        for &(tx, ty) in cells {
+            if order.len() as u32 >= budget {
+                break 'grow;
+            }
+            if tx < 0 || ty < 0
+                || tx >= terrain.width as i32 || ty >= terrain.height as i32
+                || planned.contains(&(tx, ty))
+            {
+                continue;
+            }
+            let (ux, uy) = (tx as usize, ty as usize);
+            if !matches!(terrain.tiles[uy][ux].kind, TileKind::Air | TileKind::Sky) {
+                continue; // occupied
+            }
+            if bodies.iter().any(|&p| body_covers(terrain, p, tx, ty)) {
+                continue; // someone is standing here
+            }
+            if ![(-1,0),(1,0),(0,-1),(0,1)].iter().any(|(dx,dy)| {
+                solid(terrain, tx+dx, ty+dy) || planned.contains(&(tx+dx, ty+dy))
+            }) {
+                continue; // no solid neighbour (yet)
+            }
+            planned.insert((tx, ty));
+            order.push((ux, uy));
+            grew = true;
+        }
+        if !grew {
+            break;
+        }
+    }
+    order
+}
+
+#[allow(clippy::too_many_arguments)]
    pub fn place_stone_system(
     mouse: Res<ButtonInput<MouseButton>>,
-    windows: Query<&Window>,
-    cam_q: Query<(&Camera, &GlobalTransform)>,
-    inv_q: Query<&Inventory, With<Player>>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
+    mut drag: ResMut<BuildDrag>,
+    stamp: Res<BuildStamp>,
+    mut inv_q: Query<&mut Inventory, With<Player>>,
+    player_q: Query<&Transform, With<Player>>,
+    enemy_q: Query<&Transform, With<Enemy>>,
     mut terrain: ResMut<Terrain>,
+    mut history: ResMut<ChangeHistory>,
+    mut boosters: ResMut<Boosters>,
+    mut placed: ResMut<PlacedBlocks>,
 ) {
-    let Ok(inv) = inv_q.get_single()                         else { return };
-    if inv.selected != HeldItem::StoneBlock
-        || !mouse.just_pressed(MouseButton::Left) { return; }
+    let Ok(mut inv) = inv_q.get_single_mut()                 else { return };
+    let Some((item, kind)) = block_for(inv.selected) else {
+        drag.start = None;
+        return;
+    };
+    let Some(world) = aim.point                              else { return };
+    let cur = world_to_tile(terrain.height, world);
+
+    let bodies = || -> Vec<Vec2> {
+        player_q.iter().chain(enemy_q.iter())
+            .map(|tf| tf.translation.truncate())
+            .collect()
+    };
+
+    let plan = if stamp.is_drag() {
+        /* press / hold: remember where the drag began */
+        if mouse.pressed(MouseButton::Left) || pad_pressed(&pads, PAD_USE) {
+            drag.start.get_or_insert(cur);
+            return;
+        }
+        /* release: place every valid tile of the region */
+        let Some(start) = drag.start.take()                  else { return };
+        plan_placement(&terrain, start, cur, &bodies(), inv.count(item))
+    } else {
+        /* prefab stamp: one click places every valid tile of it */
+        drag.start = None;
+        if !(mouse.just_pressed(MouseButton::Left) || pad_just_pressed(&pads, PAD_USE)) {
+            return;
+        }
+        plan_cells(&terrain, &stamp.cells(cur), &bodies(), inv.count(item))
+    };
+    if plan.is_empty() { return; }
+
+    inv.take(item, plan.len() as u32);
+    history.begin();
+    for (ux, uy) in plan {
+        let old = terrain.tiles[uy][ux];
+        history.record(ux, uy, old, kind);
+        terrain.tiles[uy][ux].kind = kind;
+        terrain.tiles[uy][ux].mine_time = match kind {
+            TileKind::Glass   => GLASS_MINE_TIME,
+            TileKind::Booster => BOOSTER_MINE_TIME,
+            _                 => 0.50,
+        };
+        match kind {
+            TileKind::Booster => boosters.place(ux, uy, stamp.rotation),
+            TileKind::Stone   => { placed.0.insert((ux, uy)); }
+            _ => {}
+        }
+        terrain.touch(ux, uy);
+    }
+}
 
-    let window  =        windows.single();
-    let Some(cursor) = window.cursor_position()              else { return };
-    let (cam, cam_tf)    = cam_q.single();
-    let Ok(world) = cam.viewport_to_world_2d(cam_tf, cursor)  else { return };
+/* ===========================================================
+   ladder placement – one click, one ladder tile on air;
+   with `keys.platform` (B) held a one‑way platform instead
+   =========================================================== */
+/// empty (air / sky) tile under the aim point, if within reach
+#[inline]
+fn ladder_target(terrain: &Terrain, world: Vec2, player_pos: Vec2) -> Option<(usize, usize)> {
+    if (world - player_pos).length_squared() > tiles(DIG_RADIUS).powi(2) {
+        return None;
+    }
+    let (tx, ty) = world_to_tile(terrain.height, world);
+    if tx < 0 || ty < 0 || tx >= terrain.width as i32 || ty >= terrain.height as i32 {
+        return None;
+    }
+    let (ux, uy) = (tx as usize, ty as usize);
+    matches!(terrain.tiles[uy][ux].kind, TileKind::Air | TileKind::Sky).then_some((ux, uy))
+}
 
-    let tx = (world.x / TILE_SIZE).floor() as i32;
-    let ty = world_to_tile_y(terrain.height, world.y);
-    if tx < 0 || ty < 0 ||
-       tx >= terrain.width as i32 || ty >= terrain.height as i32 { return; }
+pub fn place_ladder_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
+    q: Query<(&Transform, &Inventory), With<Player>>,
+    mut terrain: ResMut<Terrain>,
+    mut history: ResMut<ChangeHistory>,
+) {
+    let Ok((tf, inv)) = q.get_single() else { return };
+    if inv.selected != HeldItem::Ladder {
+        return;
+    }
+    if !(mouse.just_pressed(MouseButton::Left) || pad_just_pressed(&pads, PAD_USE)) {
+        return;
+    }
+    let Some(world) = aim.point else { return };
+    let Some((ux, uy)) = ladder_target(&terrain, world, tf.translation.truncate()) else { return };
+
+    let (kind, mine_time) = if keys.pressed(settings.keys.platform) {
+        (TileKind::Platform, PLATFORM_MINE_TIME)
+    } else {
+        (TileKind::Ladder, LADDER_MINE_TIME)
+    };
+    let old = terrain.tiles[uy][ux];
+    history.begin();
+    history.record(ux, uy, old, kind);
+    terrain.tiles[uy][ux].kind = kind;
+    terrain.tiles[uy][ux].mine_time = mine_time;
+    terrain.touch(ux, uy);
+}
 
-    let (ux, uy) = (tx as usize, ty as usize);
-    if !matches!(terrain.tiles[uy][ux].kind, TileKind::Air | TileKind::Sky) { return; }
-    if ![(-1,0),(1,0),(0,-1),(0,1)].iter()
-        .any(|(dx,dy)| solid(&terrain, tx+dx, ty+dy)) { return; }
+/* ===========================================================
+   undo (Ctrl+Z) – revert the newest action that still applies,
+   all of its tiles at once.  Placed blocks are refunded; mined
+   tiles need their drops back in the inventory and must not
+   close over the player.
+   =========================================================== */
+pub fn undo_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut history: ResMut<ChangeHistory>,
+    mut terrain: ResMut<Terrain>,
+    mut q: Query<(&Transform, &mut Inventory), With<Player>>,
+) {
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if !(ctrl && keys.just_pressed(settings.keys.undo)) {
+        return;
+    }
+    let Ok((tf, mut inv)) = q.get_single_mut() else { return };
+
+    // tiles overwritten since (cave‑ins, re‑mining …) are dropped,
+    // and so are actions with nothing left to revert
+    history.begin();
+    while let Some(entry) = history.changes.pop_back() {
+        // newest first, following what each tile will be once the later
+        // changes are reverted (a tunnel ladder sits on a mined tile)
+        let mut kinds: HashMap<(usize, usize), TileKind> = HashMap::new();
+        let mut live:  Vec<TileChange> = Vec::new();
+        for c in entry.iter().rev() {
+            let now = kinds.get(&(c.x, c.y)).copied().unwrap_or(terrain.tiles[c.y][c.x].kind);
+            if now == c.new {
+                kinds.insert((c.x, c.y), c.old.kind);
+                live.push(*c);
+            }
+        }
+        if live.is_empty() {
+            continue;
+        }
+        let pos = tf.translation.truncate();
+        let buries = |c: &TileChange| {
+            solid_kind(c.old.kind) && body_covers(&terrain, pos, c.x as i32, c.y as i32)
+        };
+        if live.iter().any(buries) {
+            history.changes.push_back(entry); // step out first
+            return;
+        }
+        let mut owed: HashMap<ItemKind, u32> = HashMap::new();
+        for item in live.iter().filter_map(|c| tile_drop(c.old.kind)) {
+            *owed.entry(item).or_default() += 1;
+        }
+        if owed.iter().any(|(&item, &n)| inv.count(item) < n) {
+            history.changes.push_back(entry); // drops already spent
+            return;
+        }
+        for (item, n) in owed {
+            inv.take(item, n);
+        }
+
+        for c in &live {
+            if let Some(item) = tile_drop(c.new) {
+                inv.add(item, 1);
+            }
+            let now = terrain.tiles[c.y][c.x];
+            terrain.tiles[c.y][c.x] = Tile {
+                wall:               now.wall,
+                visible:            now.visible,
+                explored:           now.explored,
+                display_brightness: now.display_brightness,
+                ..c.old
+            };
+            terrain.touch(c.x, c.y);
+            terrain.tiles[c.y][c.x].decal = c.old.decal; // `touch` wipes it
+        }
+        return;
+    }
+}
+
+/* helper: dust puffs at the feet, coloured like the tile below;
+   reuses the exhaust pool / fade (no gravity, just drifts) */
+fn spawn_dust(
+    commands: &mut Commands,
+    pool: &mut ParticlePool,
+    terrain: &Terrain,
+    feet: Vec2,
+    count: usize,
+) {
+    let (tx, ty) = world_to_cell(terrain.height, feet - Vec2::Y * 0.5);
+    // out of bounds counts as solid, so rule that out first
+    if tx < 0 || ty < 0 || tx >= terrain.width as i32 || ty >= terrain.height as i32
+        || !solid(terrain, tx, ty)
+    {
+        return;
+    }
+    let kind = terrain.tiles[ty as usize][tx as usize].kind;
+    let color = tile_color(kind);
 
-    terrain.tiles[uy][ux].kind = TileKind::Stone;
-    terrain.tiles[uy][ux].mine_time = 0.50;
-    terrain.changed_tiles.push_back((ux, uy));
+    let mut rng = rand::thread_rng();
+    for _ in 0..count {
+        let pos = Vec3::new(feet.x + rng.gen_range(-4.0..4.0), feet.y, 5.0);
+        let vel = Vec2::new(
+            rng.gen_range(DUST_SPEED_X.clone()),
+            rng.gen_range(DUST_SPEED_Y.clone()),
+        );
+        pool.spawn(commands, ParticleKind::Exhaust, color, DUST_SIZE, pos, vel, DUST_LIFETIME);
+    }
 }
 
 /* helper: debris particles */
-fn spawn_debris(commands: &mut Commands, terrain: &Terrain, x: usize, y: usize) {
+pub fn spawn_debris(
+    commands: &mut Commands,
+    pool: &mut ParticlePool,
+    terrain: &Terrain,
+    x: usize,
+    y: usize,
+) {
     let mut rng = rand::thread_rng();
     let color = tile_color(terrain.tiles[y][x].kind);
     let origin = Vec3::new(
-        x as f32 * TILE_SIZE,
+        x as f32 * tile_size(),
         tile_to_world_y(terrain.height, y),
         6.0,
     );
 
-    for _ in 0..DEBRIS_RATE {
-        commands.spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    color,
-                    custom_size: Some(Vec2::splat(2.5)),
-                    ..default()
-                },
-                transform: Transform::from_translation(origin),
-                ..default()
-            },
-            Velocity(Vec2::new(
-                rng.gen_range(DEBRIS_SPEED_X.clone()),
-                rng.gen_range(DEBRIS_SPEED_Y.clone()),
-            )),
-            Debris { life: DEBRIS_LIFETIME },
-        ));
+    for _ in 0..pool.scaled(DEBRIS_RATE) {
+        let vel = Vec2::new(
+            rng.gen_range(DEBRIS_SPEED_X.clone()),
+            rng.gen_range(DEBRIS_SPEED_Y.clone()),
+        );
+        pool.spawn(commands, ParticleKind::Debris, color, 2.5, origin, vel, DEBRIS_LIFETIME);
     }
 }
 
@@ -533,29 +1464,31 @@ pub fn gun_shoot_system(
     mouse: Res<ButtonInput<MouseButton>>,      // read LMB state
     time:  Res<Time>,                          // delta‑time
     mut cooldown: Local<f32>,                  // time until next shot
-    windows: Query<&Window>,
-    cam_q:  Query<(&Camera, &GlobalTransform)>,
+    mut bloom: Local<f32>,                     // 0‥1 recoil bloom
+    pads:   Query<&Gamepad>,
+    aim:    Res<GamepadAim>,
     inv_q:  Query<&Inventory, With<Player>>,
     player_q: Query<&Transform, With<Player>>,
     mut commands: Commands,
+    mut stats: ResMut<Stats>,
 ) {
     let dt = time.delta_secs();
     *cooldown -= dt;
 
     let Ok(inv) = inv_q.get_single() else { return };
-    if inv.selected != HeldItem::Gun || !mouse.pressed(MouseButton::Left) {
+    let firing = mouse.pressed(MouseButton::Left) || pad_pressed(&pads, PAD_USE);
+    if inv.selected != HeldItem::Gun || !firing {
+        *bloom = (*bloom - GUN_BLOOM_RECOVERY * dt).max(0.0); // settle between bursts
         return; // not in gun mode or button not held
     }
     if *cooldown > 0.0 {
         return; // still cooling down
     }
-    *cooldown = GUN_FIRE_INTERVAL; // reset timer
+    let weapon = inv.weapon;
+    *cooldown = weapon.fire_interval; // reset timer
 
     /* ---------- spawn a bullet ---------- */
-    let window  =        windows.single();
-    let Some(cursor) = window.cursor_position()              else { return };
-    let (cam, cam_tf)    = cam_q.single();
-    let Ok(target) = cam.viewport_to_world_2d(cam_tf, cursor) else { return };
+    let Some(target) = aim.point else { return };
 
     let origin = player_q.single().translation.truncate();
     let dir = (target - origin).normalize_or_zero();
@@ -563,6 +1496,12 @@ pub fn gun_shoot_system(
         return;
     }
 
+    /* spread: random deviation that widens the longer the trigger is held */
+    let spread = GUN_SPREAD_RADIANS + *bloom * GUN_BLOOM_RADIANS;
+    let angle  = rand::thread_rng().gen_range(-1.0f32..=1.0) * spread;
+    let dir    = Vec2::from_angle(angle).rotate(dir);
+    *bloom = (*bloom + GUN_BLOOM_PER_SHOT).min(1.0);
+
     commands.spawn((
         SpriteBundle {
             sprite: Sprite {
@@ -573,14 +1512,115 @@ pub fn gun_shoot_system(
             transform: Transform::from_translation(origin.extend(8.0)),
             ..default()
         },
-        Velocity(dir * BULLET_SPEED),
-        Bullet { damage: BULLET_DAMAGE, life: BULLET_LIFETIME },
+        Velocity(dir * weapon.muzzle_velocity),
+        Bullet {
+            damage:  weapon.damage,
+            gravity: weapon.gravity_factor,
+            life:    BULLET_LIFETIME,
+            bounces: 0,
+            pierce:  inv.gun_pierce,
+            hit:     Vec::new(),
+            knockback: 1.0,
+        },
+    ));
+    stats.bullets_fired += 1;
+}
+
+/* ===========================================================
+   charged shot – hold RMB with the gun, release to fire one big
+   piercing round; putting the gun away drops the charge
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn gun_charge_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    time:  Res<Time>,
+    mut charge: Local<f32>,                    // seconds held so far
+    aim:    Res<GamepadAim>,
+    inv_q:  Query<&Inventory, With<Player>>,
+    player_q: Query<&Transform, With<Player>>,
+    mut commands: Commands,
+    mut stats: ResMut<Stats>,
+) {
+    let Ok(inv) = inv_q.get_single() else { return };
+    if inv.selected != HeldItem::Gun {
+        *charge = 0.0;
+        return;
+    }
+    if mouse.pressed(MouseButton::Right) {
+        *charge = (*charge + time.delta_secs()).min(GUN_CHARGE_MAX);
+    }
+    let Some(target) = aim.point else { return };
+
+    /* indicator under the reticle; a Highlight, so it's cleared next frame */
+    if *charge > 0.0 {
+        let frac  = *charge / GUN_CHARGE_MAX;
+        let full  = *charge >= GUN_CHARGE_MAX;
+        let below = target - Vec2::Y * (RETICLE_SIZE * 0.5 + 4.0);
+        let fill  = Vec2::new(GUN_CHARGE_BAR.x * frac, GUN_CHARGE_BAR.y);
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(0.1, 0.1, 0.1, 0.6),
+                custom_size: Some(GUN_CHARGE_BAR),
+                ..default()
+            },
+            Transform::from_translation(below.extend(21.0)),
+            Highlight,
+        ));
+        commands.spawn((
+            Sprite {
+                color: if full { Color::WHITE } else { Color::srgb(1.0, 0.75, 0.0) },
+                custom_size: Some(fill),
+                ..default()
+            },
+            // grows from the bar's left end
+            Transform::from_translation(
+                (below - Vec2::X * (GUN_CHARGE_BAR.x - fill.x) * 0.5).extend(21.1),
+            ),
+            Highlight,
+        ));
+    }
+
+    if !mouse.just_released(MouseButton::Right) {
+        return;
+    }
+    let held = std::mem::take(&mut *charge);
+    if held < GUN_CHARGE_MIN {
+        return;
+    }
+    let origin = player_q.single().translation.truncate();
+    let dir = (target - origin).normalize_or_zero();
+    if dir == Vec2::ZERO {
+        return;
+    }
+
+    let t = (held - GUN_CHARGE_MIN) / (GUN_CHARGE_MAX - GUN_CHARGE_MIN);
+    let lerp = |r: std::ops::Range<f32>| r.start + (r.end - r.start) * t;
+    let weapon = inv.weapon;
+    commands.spawn((
+        Sprite {
+            color: Color::srgb(1.0, 0.95, 0.6),
+            custom_size: Some(Vec2::splat(lerp(GUN_CHARGE_SIZE))),
+            ..default()
+        },
+        Transform::from_translation(origin.extend(8.0)),
+        Velocity(dir * weapon.muzzle_velocity * lerp(GUN_CHARGE_SPEED)),
+        Bullet {
+            damage:  weapon.damage * lerp(GUN_CHARGE_DAMAGE),
+            gravity: weapon.gravity_factor,
+            life:    BULLET_LIFETIME,
+            bounces: 0,
+            pierce:  inv.gun_pierce.saturating_add(GUN_CHARGE_PIERCE),
+            hit:     Vec::new(),
+            knockback: lerp(GUN_CHARGE_KNOCKBACK),
+        },
     ));
+    stats.bullets_fired += 1;
 }
 
 /* ===========================================================
 bullet flight, damage, knock‑back & blood FX
 =========================================================== */
+#[allow(clippy::too_many_arguments)]
 pub fn bullet_update_system(
     time: Res<Time>,
     mut commands: Commands,
@@ -595,64 +1635,109 @@ pub fn bullet_update_system(
        also proves it never touches the bullet set */
     mut orcs: ParamSet<(
         /* read HP + position, despawn on death */
-        Query<(Entity, &GlobalTransform, &mut Enemy), Without<Bullet>>,
+        Query<(Entity, &GlobalTransform, &mut Enemy, &Collider, &Sprite), (Without<Bullet>, Without<Follower>)>,
         /* apply knock‑back impulse */
         Query<&mut Velocity, (With<Enemy>, Without<Bullet>)>,
     )>,
 
     terrain: Res<Terrain>,
+    mut shake: ResMut<ScreenShake>,
+    mut pool: ResMut<ParticlePool>,
+    mut stats: ResMut<Stats>,
 ) {
     let dt       = time.delta_secs();
-    let half_orc = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT);
-    let mut knocks: Vec<(Entity, f32)> = Vec::new(); // (orc‑ID, ±1)
+    let mut knocks: Vec<(Entity, f32)> = Vec::new(); // (orc‑ID, ±1 × knock‑back scale)
 
     /* ───────── 1. move bullets & process hits ───────── */
     for (b_ent, mut b_tf, mut b_vel, mut bullet) in &mut bullets {
         /* movement */
-        b_vel.0.y += GRAVITY * dt * 0.5;
-        b_tf.translation += (b_vel.0 * dt).extend(0.0);
+        b_vel.0.y += GRAVITY * dt * bullet.gravity;
+        let old = b_tf.translation.truncate();
+
+        /* liquids don't stop bullets – they drag and sink them */
+        let (lx, ly) = ((old.x / tile_size()).round() as i32, world_to_tile_y(terrain.height, old.y));
+        if liquid_at(&terrain, lx, ly) {
+            b_vel.0 *= (1.0 - LIQUID_BULLET_DRAG * dt).max(0.0);
+            b_vel.0.y += GRAVITY * dt * LIQUID_BULLET_SINK;
+        }
+        let new = old + b_vel.0 * dt;
         bullet.life -= dt;
 
-        /* tile or timeout */
-        if bullet.life <= 0.0
-            || solid(
-                &terrain,
-                (b_tf.translation.x / TILE_SIZE).round() as i32,
-                world_to_tile_y(terrain.height, b_tf.translation.y),
-            )
-        {
+        if bullet.life <= 0.0 {
             commands.entity(b_ent).despawn();
             continue;
         }
 
+        /* tile hit: obsidian ricochets, everything else absorbs */
+        let tile_of = |p: Vec2| {
+            ((p.x / tile_size()).round() as i32, world_to_tile_y(terrain.height, p.y))
+        };
+        let (tx, ty) = tile_of(new);
+        if solid(&terrain, tx, ty) {
+            let in_bounds = tx >= 0 && ty >= 0
+                && tx < terrain.width as i32 && ty < terrain.height as i32;
+            let obsidian = in_bounds
+                && terrain.tiles[ty as usize][tx as usize].kind == TileKind::Obsidian;
+
+            if !obsidian || bullet.bounces >= BULLET_MAX_BOUNCES {
+                commands.entity(b_ent).despawn();
+                continue;
+            }
+
+            /* reflect along the axis that actually crossed into the tile */
+            let (hx, hy) = tile_of(Vec2::new(new.x, old.y));
+            let (vx, vy) = tile_of(Vec2::new(old.x, new.y));
+            let hit_x = solid(&terrain, hx, hy);
+            let hit_y = solid(&terrain, vx, vy);
+            if hit_x || !hit_y { b_vel.0.x = -b_vel.0.x; }
+            if hit_y || !hit_x { b_vel.0.y = -b_vel.0.y; }
+            bullet.bounces += 1;
+            // stay at the pre‑impact position so we don't re‑collide next frame
+            continue;
+        }
+        b_tf.translation = new.extend(b_tf.translation.z);
+
         /* test vs. every orc */
         let b_pos = b_tf.translation.truncate();
-        for (e_ent, e_gxf, mut enemy) in &mut orcs.p0() {
+        for (e_ent, e_gxf, mut enemy, collider, sprite) in &mut orcs.p0() {
+            if bullet.hit.contains(&e_ent) {
+                continue; // still passing through this one
+            }
             let delta = (e_gxf.translation().truncate() - b_pos).abs();
 
-            if delta.x <= half_orc.x && delta.y <= half_orc.y {
+            // generous: a full body width either side of the centre
+            if delta.x <= collider.half.x * 2.0 && delta.y <= collider.half.y * 2.0 {
                 /* hit */
                 enemy.hp -= bullet.damage as i32;
                 enemy.recoil = RECOIL_TIME;          // start the stun timer
-                spawn_hit_blood(&mut commands, e_gxf.translation());
-                knocks.push((e_ent, b_vel.0.x.signum()));
-                commands.entity(b_ent).despawn();
+                spawn_hit_blood(&mut commands, &mut pool, e_gxf.translation());
+                knocks.push((e_ent, b_vel.0.x.signum() * bullet.knockback));
+                bullet.hit.push(e_ent);
 
                 if enemy.hp <= 0 {
-                    spawn_blood(&mut commands, e_gxf.translation() + Vec3::Z * 2.0);
-                    commands.entity(e_ent).despawn();
+                    spawn_blood(&mut commands, &mut pool, e_gxf.translation() + Vec3::Z * 2.0);
+                    spawn_gibs(&mut commands, e_gxf.translation() + Vec3::Z * 3.0);
+                    spawn_corpse(&mut commands, sprite, &e_gxf.compute_transform(), collider.half);
+                    commands.entity(e_ent).despawn_recursive(); // + health bar
+                    shake.add(SHAKE_ORC_DEATH * enemy.size_scale); // big orcs land harder
+                    stats.orcs_killed += 1;
+                }
+                if bullet.pierce == 0 {
+                    commands.entity(b_ent).despawn();
+                    break; // bullet gone
                 }
-                break; // bullet gone
+                bullet.pierce -= 1;
             }
         }
     }
 
     /* ───────── 2. knock‑back (separate Velocity borrow) ───────── */
-    for (e_ent, dir_sign) in knocks {
+    for (e_ent, push) in knocks {
         if let Ok(mut vel) = orcs.p1().get_mut(e_ent) {
-            vel.0.x = dir_sign * HIT_KNOCKBACK;        // horizontal shove
-            if vel.0.y < HIT_KNOCKBACK_UP {            // only boost upward, never drag down
-                vel.0.y = HIT_KNOCKBACK_UP;            // vertical pop
+            let pop = HIT_KNOCKBACK_UP * push.abs();
+            vel.0.x = push * HIT_KNOCKBACK;            // horizontal shove
+            if vel.0.y < pop {                         // only boost upward, never drag down
+                vel.0.y = pop;                         // vertical pop
             }
         }
     }
@@ -664,16 +1749,159 @@ pub fn bullet_update_system(
 pub fn debris_update_system(
     time: Res<Time>,
     mut commands: Commands,
-    mut q: Query<(Entity, &mut Transform, &mut Sprite, &Velocity, &mut Debris)>,
+    mut pool: ResMut<ParticlePool>,
+    terrain: Res<Terrain>,
+    wind: Res<Wind>,
+    mut q: Query<(Entity, &mut Transform, &mut Sprite, &mut Visibility, &mut Velocity, &mut Debris)>,
 ) {
     let dt = time.delta_secs();
-    for (e, mut tf, mut spr, vel, mut db) in &mut q {
+    let tile_of = |p: Vec3| {
+        ((p.x / tile_size()).round() as i32, world_to_tile_y(terrain.height, p.y))
+    };
+    let mut splashes: Vec<(Vec3, Vec3)> = Vec::new(); // (position, liquid rgb)
+
+    for (e, mut tf, mut spr, mut vis, mut vel, mut db) in &mut q {
+        if db.life <= 0.0 {
+            continue; // parked in the pool
+        }
+        wind.push(&mut vel.0, WIND_PARTICLE_COUPLING, dt);
+        let (ox, oy) = tile_of(tf.translation);
         tf.translation += (vel.0 * dt).extend(0.0);
         db.life -= dt;
 
+        /* entering a liquid: splash and stop */
+        let (nx, ny) = tile_of(tf.translation);
+        if liquid_at(&terrain, nx, ny) && !liquid_at(&terrain, ox, oy) {
+            splashes.push((tf.translation, terrain.tiles[ny as usize][nx as usize].base_rgb));
+            db.life = 0.0;
+        }
+
         spr.color.set_alpha(db.life / DEBRIS_LIFETIME);
 
         if db.life <= 0.0 {
+            pool.release(ParticleKind::Debris, e, &mut vis);
+        }
+    }
+
+    /* droplets leap back out of the surface, so they never re‑splash */
+    let mut rng = rand::thread_rng();
+    for (pos, rgb) in splashes {
+        for _ in 0..SPLASH_DROPLETS {
+            let vel = Vec2::new(rng.gen_range(-40.0..40.0), rng.gen_range(60.0..120.0));
+            pool.spawn(
+                &mut commands, ParticleKind::Debris, Color::srgb(rgb.x, rgb.y, rgb.z),
+                2.0, pos, vel, DEBRIS_LIFETIME * 0.5,
+            );
+        }
+    }
+}
+
+/* ===========================================================
+   gibs: gravity + tile collision, then fade
+   =========================================================== */
+pub fn gib_update_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    terrain: Res<Terrain>,
+    mut q: Query<(Entity, &mut Transform, &mut Sprite, &mut Velocity, &mut Gib)>,
+) {
+    let dt = time.delta_secs();
+
+    /* cap: if too many are alive, cut the oldest short */
+    let alive = q.iter().count();
+    if alive > GIB_MAX_ALIVE {
+        let mut lives: Vec<f32> = q.iter().map(|(.., g)| g.life).collect();
+        lives.sort_unstable_by(|a, b| a.total_cmp(b));
+        let cutoff = lives[alive - GIB_MAX_ALIVE - 1];
+        for (.., mut g) in &mut q {
+            if g.life <= cutoff {
+                g.life = 0.0;
+            }
+        }
+    }
+
+    for (e, mut tf, mut spr, mut vel, mut gib) in &mut q {
+        vel.0.y += GRAVITY * dt;
+        let next = tf.translation.truncate() + vel.0 * dt;
+        let tx   = (next.x / tile_size()).round() as i32;
+        let ty   = world_to_tile_y(terrain.height, next.y);
+
+        if solid(&terrain, tx, ty) {
+            vel.0 = Vec2::ZERO; // landed – lie still and fade
+        } else {
+            tf.translation = next.extend(tf.translation.z);
+        }
+
+        gib.life -= dt;
+        spr.color.set_alpha((gib.life / GIB_LIFETIME).max(0.0));
+
+        if gib.life <= 0.0 {
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+/// the dead orc's last frame, tipped over where it fell; `tf` is the
+/// orc's world transform and `half` its upright half‑extents.  Any coin
+/// drop pops out right away, so it can be grabbed while the body lies there.
+pub fn spawn_corpse(commands: &mut Commands, sprite: &Sprite, tf: &Transform, half: Vec2) {
+    let mut body = sprite.clone();
+    body.color = CORPSE_TINT;
+    // fall over backwards, away from where it was facing
+    let tip = -tf.scale.x.signum() * std::f32::consts::FRAC_PI_2;
+    commands.spawn((
+        body,
+        Transform {
+            translation: tf.translation.truncate().extend(CORPSE_Z),
+            rotation:    Quat::from_rotation_z(tip),
+            scale:       tf.scale,
+        },
+        Velocity(Vec2::ZERO),
+        Corpse { life: CORPSE_LIFETIME, half_h: half.x },
+    ));
+
+    let mut rng = rand::thread_rng();
+    if rng.gen_bool(CORPSE_LOOT_CHANCE) {
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(1.0, 0.9, 0.4),
+                custom_size: Some(Vec2::splat(PICKUP_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(tf.translation.truncate().extend(6.0)),
+            Velocity(Vec2::new(0.0, 80.0)),
+            ItemPickup { item: ItemKind::Coin, n: rng.gen_range(CORPSE_LOOT_COINS) },
+        ));
+    }
+}
+
+/* ===========================================================
+   corpses: fall, rest on the ground, fade
+   =========================================================== */
+pub fn corpse_update_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut terrain: ResMut<Terrain>,
+    mut q: Query<(Entity, &mut Transform, &mut Sprite, &mut Velocity, &mut Corpse)>,
+) {
+    let dt = time.delta_secs();
+
+    for (e, mut tf, mut spr, mut vel, mut corpse) in &mut q {
+        // keep testing the tile below, so mining it out drops the body
+        vel.0.y += GRAVITY * dt;
+        let next = tf.translation.truncate() + vel.0 * dt;
+        let (tx, ty) = world_to_cell(terrain.height, next - Vec2::Y * corpse.half_h);
+        if solid(&terrain, tx, ty) {
+            vel.0 = Vec2::ZERO;
+            terrain.paint_decal(tx, ty, Decal::Blood);
+        } else {
+            tf.translation = next.extend(tf.translation.z);
+        }
+
+        corpse.life -= dt;
+        spr.color.set_alpha((corpse.life / CORPSE_FADE_TIME).clamp(0.0, 1.0));
+
+        if corpse.life <= 0.0 {
             commands.entity(e).despawn();
         }
     }
@@ -684,77 +1912,129 @@ pub fn debris_update_system(
    =========================================================== */
 pub fn exhaust_update_system(
     time: Res<Time>,
-    mut commands: Commands,
-    mut q: Query<(Entity, &mut Transform, &mut Sprite, &Velocity, &mut Exhaust)>,
+    mut pool: ResMut<ParticlePool>,
+    wind: Res<Wind>,
+    mut q: Query<(Entity, &mut Transform, &mut Sprite, &mut Visibility, &mut Velocity, &mut Exhaust)>,
 ) {
     let dt = time.delta_secs();
-    for (e, mut tf, mut spr, vel, mut ex) in &mut q {
+    for (e, mut tf, mut spr, mut vis, mut vel, mut ex) in &mut q {
+        if ex.life <= 0.0 {
+            continue; // parked in the pool
+        }
+        wind.push(&mut vel.0, WIND_PARTICLE_COUPLING, dt);
         tf.translation += (vel.0 * dt).extend(0.0);
         ex.life -= dt;
 
         spr.color.set_alpha(ex.life / EXHAUST_LIFETIME);
 
         if ex.life <= 0.0 {
-            commands.entity(e).despawn();
+            pool.release(ParticleKind::Exhaust, e, &mut vis);
         }
     }
 }
 
-fn spawn_blood(commands: &mut Commands, pos: Vec3) {
-    use rand::Rng;
+pub fn spawn_blood(commands: &mut Commands, pool: &mut ParticlePool, pos: Vec3) {
     let mut rng = rand::thread_rng();
 
-    for _ in 0..BLOOD_RATE {
-        commands.spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    color: BLOOD_COLOR,
-                    custom_size: Some(Vec2::splat(4.0)),
-                    ..default()
-                },
-                transform: Transform::from_translation(pos),
-                ..default()
-            },
-            Velocity(Vec2::new(
-                rng.gen_range(BLOOD_SPEED_X.clone()),
-                rng.gen_range(BLOOD_SPEED_Y.clone()),
-            )),
-            Debris { life: BLOOD_LIFETIME },        // we can reuse Debris
-        ));
+    for _ in 0..pool.scaled(BLOOD_RATE) {
+        let vel = Vec2::new(
+            rng.gen_range(BLOOD_SPEED_X.clone()),
+            rng.gen_range(BLOOD_SPEED_Y.clone()),
+        );
+        // we can reuse Debris
+        pool.spawn(commands, ParticleKind::Debris, BLOOD_COLOR, 4.0, pos, vel, BLOOD_LIFETIME);
     }
 }
 
-fn spawn_hit_blood(commands: &mut Commands, pos: Vec3) {
+/// a handful of body parts per death (never more than GIBS_PER_DEATH)
+fn spawn_gibs(commands: &mut Commands, pos: Vec3) {
+    const PARTS: [Vec2; GIBS_PER_DEATH] = [
+        Vec2::new(5.0, 5.0), // head
+        Vec2::new(2.0, 5.0), // arms
+        Vec2::new(2.0, 5.0),
+        Vec2::new(2.5, 6.0), // legs
+        Vec2::new(2.5, 6.0),
+    ];
     let mut rng = rand::thread_rng();
-    for _ in 0..HIT_BLOOD_RATE {
+
+    for size in PARTS {
         commands.spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    color: BLOOD_COLOR,
-                    custom_size: Some(Vec2::splat(3.0)),
-                    ..default()
-                },
-                transform: Transform::from_translation(pos),
+            Sprite {
+                color: GIB_COLOR,
+                custom_size: Some(size),
                 ..default()
             },
+            Transform::from_translation(pos),
             Velocity(Vec2::new(
-                rng.gen_range(-70.0..70.0),
-                rng.gen_range(20.0..120.0),
+                rng.gen_range(GIB_SPEED_X.clone()),
+                rng.gen_range(GIB_SPEED_Y.clone()),
             )),
-            Debris { life: HIT_BLOOD_LIFE },
+            Gib { life: GIB_LIFETIME },
         ));
     }
 }
 
+pub fn spawn_hit_blood(commands: &mut Commands, pool: &mut ParticlePool, pos: Vec3) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..pool.scaled(HIT_BLOOD_RATE) {
+        let vel = Vec2::new(rng.gen_range(-70.0..70.0), rng.gen_range(20.0..120.0));
+        pool.spawn(commands, ParticleKind::Debris, BLOOD_COLOR, 3.0, pos, vel, HIT_BLOOD_LIFE);
+    }
+}
+
 /* ===========================================================
    simple walk‑cycle animation
    =========================================================== */
+/// frame ranges per state – the sheet is a single 6‑frame run strip,
+/// so jump / fall / dash borrow the poses that read best from it
+const ANIM_IDLE: (usize, usize) = (0, 0);
+const ANIM_RUN:  (usize, usize) = (0, 5);
+const ANIM_JUMP: (usize, usize) = (1, 1);
+const ANIM_FALL: (usize, usize) = (4, 4);
+const ANIM_DASH: (usize, usize) = (3, 3);
+
+/// picks the frame range from velocity / grounded / dashing and jumps
+/// straight to its first frame whenever the state changes
+pub fn player_anim_state_system(
+    mut q: Query<
+        (&Velocity, &Player, Option<&Dashing>, &mut AnimationIndices, &mut Sprite),
+        With<Player>,
+    >,
+) {
+    let Ok((vel, ply, dashing, mut indices, mut sprite)) = q.get_single_mut() else { return };
+
+    let (first, last) = if dashing.is_some() {
+        ANIM_DASH
+    } else if !ply.grounded {
+        if vel.0.y > 0.0 { ANIM_JUMP } else { ANIM_FALL }
+    } else if vel.0.x.abs() < 1.0 {
+        ANIM_IDLE
+    } else {
+        ANIM_RUN
+    };
+
+    if (indices.first, indices.last) != (first, last) {
+        indices.first = first;
+        indices.last  = last;
+        if let Some(atlas) = sprite.texture_atlas.as_mut() {
+            atlas.index = first;
+        }
+    }
+}
+
+/// steps the frame range; the run cycle plays faster or slower with the
+/// horizontal speed (`anim_rate`), poses and idle tick at the base rate
 pub fn animate_player_system(
     time: Res<Time>,
-    mut q: Query<(&AnimationIndices, &mut AnimationTimer, &mut Sprite), With<Player>>,
+    mut q: Query<(&AnimationIndices, &mut AnimationTimer, &mut Sprite, &Velocity), With<Player>>,
 ) {
-    for (indices, mut timer, mut sprite) in &mut q {
-        if timer.tick(time.delta()).just_finished() {
+    for (indices, mut timer, mut sprite, vel) in &mut q {
+        let rate = if (indices.first, indices.last) == ANIM_RUN {
+            anim_rate(vel.0.x, WALK_SPEED)
+        } else {
+            1.0
+        };
+        if timer.tick(time.delta().mul_f32(rate)).just_finished() {
             if let Some(atlas) = sprite.texture_atlas.as_mut() {
                 atlas.index = if atlas.index == indices.last {
                     indices.first
@@ -766,6 +2046,13 @@ pub fn animate_player_system(
     }
 }
 
+/// animation speed multiplier for a body moving at `vx` whose normal
+/// walk speed is `walk` – 1.0 at walk speed, clamped to ANIM_RATE_RANGE
+#[inline]
+pub fn anim_rate(vx: f32, walk: f32) -> f32 {
+    (vx.abs() / walk.max(1.0)).clamp(*ANIM_RATE_RANGE.start(), *ANIM_RATE_RANGE.end())
+}
+
 /* ===========================================================
    passive health regeneration
    =========================================================== */
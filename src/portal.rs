@@ -0,0 +1,189 @@
+//! portals – placed tiles that come in linked pairs
+//!
+//! With the mirror held, RMB puts a portal tile on an empty tile within
+//! reach.  Portals link in placement order: the first of a pair waits in
+//! `Portals::pending` until the next one is placed.  Walking
+//! into a linked portal drops the player on its partner; the player has
+//! to step off the arrival portal (and PORTAL_COOLDOWN must run out)
+//! before it fires again.  Like the mirror, the camera jumps along so
+//! the chunk window and active rect re‑centre on the same frame.
+
+use std::collections::HashMap;
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::camera::{CameraSnap, FreeCam};
+use crate::components::{Collider, HeldItem, Inventory, Player, Velocity};
+use crate::constants::*;
+use crate::gamepad::{cursor_to_tile, GamepadAim};
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::player::ChangeHistory;
+use crate::tile_stream::solid;
+use crate::world_gen::{
+    tile_to_world_y, world_to_cell, world_to_tile_y, Terrain, TileKind, PORTAL_MINE_TIME,
+};
+
+/// every linked portal (stored both ways round) plus the one still
+/// waiting for a partner; mined‑out portals are dropped by `portal_system`
+#[derive(Resource, Default)]
+pub struct Portals {
+    pub links:   HashMap<(usize, usize), (usize, usize)>,
+    pub pending: Option<(usize, usize)>,
+}
+
+impl Portals {
+    /// link `at` to the waiting portal, or make it the waiting one
+    pub fn place(&mut self, at: (usize, usize)) {
+        match self.pending.take() {
+            Some(other) if other != at => {
+                self.links.insert(at, other);
+                self.links.insert(other, at);
+            }
+            _ => self.pending = Some(at),
+        }
+    }
+
+    /// forget portals whose tile is gone; the other half of a broken
+    /// pair goes back to waiting for a partner
+    fn prune(&mut self, terrain: &Terrain) {
+        let is_portal = |&(x, y): &(usize, usize)| terrain.tiles[y][x].kind == TileKind::Portal;
+        if !self.pending.as_ref().is_some_and(is_portal) {
+            self.pending = None;
+        }
+        let dead: Vec<(usize, usize)> =
+            self.links.keys().copied().filter(|p| !is_portal(p)).collect();
+        for p in dead {
+            let Some(other) = self.links.remove(&p) else { continue };
+            self.links.remove(&other);
+            if is_portal(&other) {
+                self.place(other);
+            }
+        }
+    }
+}
+
+/// what stops a portal firing twice in a row
+#[derive(Default)]
+pub struct PortalState {
+    cooldown: f32,
+    /// portal just arrived on – ignored until the player steps off it
+    arrived:  Option<(usize, usize)>,
+}
+
+fn sparkle(commands: &mut Commands, pool: &mut ParticlePool, at: Vec2, count: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..count {
+        let offset = Vec2::new(rng.gen_range(-8.0..8.0), rng.gen_range(-8.0..8.0));
+        let vel    = Vec2::new(rng.gen_range(-30.0..30.0), rng.gen_range(-30.0..30.0));
+        pool.spawn(
+            commands, ParticleKind::Exhaust, PORTAL_COLOR, 2.0,
+            (at + offset).extend(11.0), vel, 0.5,
+        );
+    }
+}
+
+/* ===========================================================
+   placement – mirror held, RMB on an empty tile within reach
+   =========================================================== */
+pub fn place_portal_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    aim: Res<GamepadAim>,
+    q: Query<(&Transform, &Inventory), With<Player>>,
+    mut terrain: ResMut<Terrain>,
+    mut history: ResMut<ChangeHistory>,
+    mut portals: ResMut<Portals>,
+) {
+    let Ok((tf, inv)) = q.get_single() else { return };
+    if inv.selected != HeldItem::Mirror || !mouse.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let (Some(world), Some((ux, uy))) = (aim.point, cursor_to_tile(&aim, &terrain)) else { return };
+    if (world - tf.translation.truncate()).length_squared() > tiles(DIG_RADIUS).powi(2) {
+        return;
+    }
+    let old = terrain.tiles[uy][ux];
+    if !matches!(old.kind, TileKind::Air | TileKind::Sky) {
+        return;
+    }
+    history.begin();
+    history.record(ux, uy, old, TileKind::Portal);
+    terrain.tiles[uy][ux].kind      = TileKind::Portal;
+    terrain.tiles[uy][ux].mine_time = PORTAL_MINE_TIME;
+    terrain.touch(ux, uy);
+    portals.place((ux, uy));
+    info!(
+        "portal placed at ({ux}, {uy}){}",
+        if portals.pending.is_some() { " – waiting for its partner" } else { " – linked" },
+    );
+}
+
+/// where a body of half‑extents `half` stands in portal (x, y), if there
+/// is room for it
+fn arrival(terrain: &Terrain, (x, y): (usize, usize), half: Vec2) -> Option<Vec2> {
+    let ts  = tile_size();
+    let pos = Vec2::new((x as f32 + 0.5) * ts, tile_to_world_y(terrain.height, y) + half.y + 0.1);
+    let x0  = ((pos.x - half.x) / ts).floor() as i32;
+    let x1  = ((pos.x + half.x - 0.01) / ts).floor() as i32;
+    let y0  = world_to_tile_y(terrain.height, pos.y + half.y - 0.01);
+    let y1  = world_to_tile_y(terrain.height, pos.y - half.y);
+    let clear = (y0..=y1).all(|ty| (x0..=x1).all(|tx| !solid(terrain, tx, ty)));
+    clear.then_some(pos)
+}
+
+/* ===========================================================
+   stepping into a portal → its partner
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn portal_system(
+    time: Res<Time>,
+    terrain: Res<Terrain>,
+    free: Res<FreeCam>,
+    mut portals: ResMut<Portals>,
+    mut state: Local<PortalState>,
+    mut pool: ResMut<ParticlePool>,
+    mut commands: Commands,
+    mut player_q: Query<(&mut Transform, &mut Velocity, &Collider), With<Player>>,
+    mut cam_q: Query<&mut Transform, (With<Camera>, Without<Player>)>,
+    mut snaps: EventWriter<CameraSnap>,
+) {
+    if terrain.is_changed() {
+        portals.prune(&terrain);
+    }
+    state.cooldown = (state.cooldown - time.delta_secs()).max(0.0);
+
+    let Ok((mut tf, mut vel, collider)) = player_q.get_single_mut() else { return };
+    let pos = tf.translation.truncate();
+    let (tx, ty) = world_to_cell(terrain.height, pos);
+    let here = (tx >= 0 && ty >= 0 && tx < terrain.width as i32 && ty < terrain.height as i32)
+        .then_some((tx as usize, ty as usize))
+        .filter(|&(x, y)| terrain.tiles[y][x].kind == TileKind::Portal);
+
+    if here != state.arrived {
+        state.arrived = None; // stepped off the arrival portal
+    }
+    let Some(from) = here else { return };
+    if state.cooldown > 0.0 || state.arrived.is_some() {
+        return;
+    }
+    let Some(&to) = portals.links.get(&from) else { return };
+    let Some(dest) = arrival(&terrain, to, collider.half) else { return }; // partner is walled in
+
+    sparkle(&mut commands, &mut pool, pos, 16);
+    tf.translation.x = dest.x;
+    tf.translation.y = dest.y;
+    vel.0 = Vec2::ZERO;
+    state.cooldown = PORTAL_COOLDOWN;
+    state.arrived  = Some(to);
+
+    // streaming + active rect follow the camera, so move it too
+    if !free.active {
+        if let Ok(mut cam_tf) = cam_q.get_single_mut() {
+            cam_tf.translation.x = dest.x;
+            cam_tf.translation.y = dest.y;
+        }
+    }
+    snaps.send(CameraSnap);
+    sparkle(&mut commands, &mut pool, dest, 16);
+}
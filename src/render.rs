@@ -0,0 +1,109 @@
+//! render settings – vsync, frame‑rate cap and tile size
+//!
+//! F4 toggles vsync, F5 cycles the frame cap (off → 30 → 60 → 120 → 144).
+//! `--tile-size <px>` changes how big a tile is in world units; it is
+//! fixed for the run and published through `tile_size()` before the app
+//! starts, so every conversion and sprite size agrees on it.
+//! The cap is a simple sleep at the end of the frame, so an idle game no
+//! longer spins a core at several hundred FPS.  Physics still steps with the
+//! frame delta, so capped and uncapped runs behave the same – only smoother
+//! or coarser.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use bevy::window::{PresentMode, PrimaryWindow};
+use std::time::{Duration, Instant};
+
+use crate::constants::*;
+
+/// cap presets cycled by F5 (`None` = uncapped)
+const FPS_CAPS: [Option<u32>; 5] = [None, Some(30), Some(60), Some(120), Some(144)];
+
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct RenderConfig {
+    pub vsync:     bool,
+    pub fps_cap:   Option<u32>,
+    /// world units per tile (read‑only after start‑up)
+    pub tile_size: f32,
+}
+
+impl Default for RenderConfig {
+    fn default() -> Self {
+        Self { vsync: DEFAULT_VSYNC, fps_cap: DEFAULT_FPS_CAP, tile_size: DEFAULT_TILE_SIZE }
+    }
+}
+
+impl RenderConfig {
+    /// reads `--tile-size <px>` (clamped to TILE_SIZE_RANGE) and publishes
+    /// the result for `tile_size()`; missing or invalid → DEFAULT_TILE_SIZE
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let tile_size = args
+            .iter()
+            .position(|a| a == "--tile-size")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|v| v.parse::<f32>().ok())
+            .filter(|v| v.is_finite())
+            .map_or(DEFAULT_TILE_SIZE, |v| {
+                v.clamp(*TILE_SIZE_RANGE.start(), *TILE_SIZE_RANGE.end())
+            });
+        set_tile_size(tile_size);
+        if tile_size != DEFAULT_TILE_SIZE {
+            info!("tile size {tile_size}");
+        }
+        Self { tile_size, ..default() }
+    }
+}
+
+#[inline]
+fn present_mode(vsync: bool) -> PresentMode {
+    if vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync }
+}
+
+/* ===========================================================
+   startup: apply the defaults to the primary window
+   =========================================================== */
+pub fn apply_render_config(
+    cfg: Res<RenderConfig>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if let Ok(mut window) = window_q.get_single_mut() {
+        window.present_mode = present_mode(cfg.vsync);
+    }
+}
+
+/* ===========================================================
+   F4 / F5 – change at runtime
+   =========================================================== */
+pub fn render_config_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut cfg: ResMut<RenderConfig>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if keys.just_pressed(KeyCode::F4) {
+        cfg.vsync = !cfg.vsync;
+        if let Ok(mut window) = window_q.get_single_mut() {
+            window.present_mode = present_mode(cfg.vsync);
+        }
+        info!("vsync {}", if cfg.vsync { "on" } else { "off" });
+    }
+    if keys.just_pressed(KeyCode::F5) {
+        let i = FPS_CAPS.iter().position(|&c| c == cfg.fps_cap).unwrap_or(0);
+        cfg.fps_cap = FPS_CAPS[(i + 1) % FPS_CAPS.len()];
+        info!("frame cap {:?}", cfg.fps_cap);
+    }
+}
+
+/* ===========================================================
+   limiter – runs in `Last`, sleeps off what is left of the frame
+   =========================================================== */
+pub fn frame_limiter_system(cfg: Res<RenderConfig>, mut last: Local<Option<Instant>>) {
+    if let (Some(cap), Some(prev)) = (cfg.fps_cap, *last) {
+        let budget = Duration::from_secs_f64(1.0 / cap.max(1) as f64);
+        let spent  = prev.elapsed();
+        if spent < budget {
+            std::thread::sleep(budget - spent);
+        }
+    }
+    *last = Some(Instant::now());
+}
@@ -0,0 +1,99 @@
+//! ore detector – Q sends a pulse that reveals valuable tiles through walls
+//!
+//! Matches are marked with fading sprites for `SCAN_PING_LIFETIME` seconds.
+//! Only tiles inside the streamed chunk window are scanned.  There are no
+//! ore tiles yet, so the rarest materials (obsidian, lava) are reported;
+//! extend `scan_target` when ores are added.
+
+use bevy::color::Alpha;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::components::{Player, ScanPing};
+use crate::constants::*;
+use crate::settings::Settings;
+use crate::tile_stream::LoadedWindow;
+use crate::world_gen::{tile_to_world_y, world_to_cell, Terrain, TileKind};
+
+/// ping colour for tile kinds the scanner reports
+#[inline]
+fn scan_target(kind: TileKind) -> Option<Color> {
+    match kind {
+        TileKind::Obsidian => Some(Color::srgb(0.75, 0.35, 1.0)),
+        TileKind::Lava     => Some(Color::srgb(1.0, 0.55, 0.1)),
+        _                  => None,
+    }
+}
+
+/* ===========================================================
+   pulse (Q) – one‑shot scan with a cooldown
+   =========================================================== */
+pub fn scanner_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+    mut cooldown: Local<f32>,
+    mut commands: Commands,
+    terrain: Res<Terrain>,
+    loaded: Option<Res<LoadedWindow>>,
+    player_q: Query<&Transform, With<Player>>,
+) {
+    *cooldown -= time.delta_secs();
+    if !keys.just_pressed(settings.keys.scanner) || *cooldown > 0.0 {
+        return;
+    }
+    let (Some(loaded), Ok(tf)) = (loaded, player_q.get_single()) else { return };
+    *cooldown = SCAN_COOLDOWN;
+
+    let (px, py) = world_to_cell(terrain.height, tf.translation.truncate());
+
+    let mut hits: Vec<(i32, usize, usize, Color)> = Vec::new();
+    for dy in -SCAN_RADIUS..=SCAN_RADIUS {
+        for dx in -SCAN_RADIUS..=SCAN_RADIUS {
+            let d2 = dx * dx + dy * dy;
+            let (x, y) = (px + dx, py + dy);
+            if d2 > SCAN_RADIUS * SCAN_RADIUS || x < 0 || y < 0 {
+                continue;
+            }
+            let (ux, uy) = (x as usize, y as usize);
+            if ux >= terrain.width || uy >= terrain.height || !loaded.contains_tile(ux, uy) {
+                continue;
+            }
+            if let Some(color) = scan_target(terrain.tiles[uy][ux].kind) {
+                hits.push((d2, ux, uy, color));
+            }
+        }
+    }
+
+    // nearest first, so a solid obsidian floor can't swamp the pulse
+    hits.sort_unstable_by_key(|h| h.0);
+    for &(_, ux, uy, color) in hits.iter().take(SCAN_MAX_PINGS) {
+        commands.spawn((
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(tile_size() * 0.6)),
+                ..default()
+            },
+            Transform::from_xyz(ux as f32 * tile_size(), tile_to_world_y(terrain.height, uy), 21.0),
+            ScanPing { life: SCAN_PING_LIFETIME },
+        ));
+    }
+}
+
+/* ===========================================================
+   ping fade‑out
+   =========================================================== */
+pub fn scan_ping_update_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut Sprite, &mut ScanPing)>,
+) {
+    let dt = time.delta_secs();
+    for (e, mut spr, mut ping) in &mut q {
+        ping.life -= dt;
+        spr.color.set_alpha((ping.life / SCAN_PING_LIFETIME).max(0.0));
+        if ping.life <= 0.0 {
+            commands.entity(e).despawn();
+        }
+    }
+}
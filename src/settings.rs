@@ -0,0 +1,165 @@
+//! settings – `settings.ron` next to the executable's working directory
+//!
+//! Loaded once in `main` before the window is built; a missing file is
+//! written out with the defaults, a broken one is left alone (defaults are
+//! used and a warning printed).  Window size and fullscreen are saved back
+//! so they persist between sessions.  `master_volume` is stored for when
+//! audio lands – nothing plays sound yet.
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode, WindowResized};
+use serde::{Deserialize, Serialize};
+
+use crate::difficulty::DifficultyLevel;
+use crate::particles::ParticleQuality;
+
+const SETTINGS_PATH: &str = "settings.ron";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub jump:       KeyCode,
+    pub dash:       KeyCode,
+    /// second dash key; `None` frees it for something else
+    pub dash_alt:   Option<KeyCode>,
+    pub fullscreen: KeyCode,
+    pub backpack:   KeyCode,
+    pub crafting:   KeyCode,
+    pub scanner:    KeyCode,
+    pub free_cam:   KeyCode,
+    /// talk to the merchant
+    pub interact:   KeyCode,
+    /// with Ctrl held
+    pub undo:       KeyCode,
+    pub stats:      KeyCode,
+    /// pickaxe: circle ↔ single tile
+    pub precise_mine: KeyCode,
+    /// back to the previously held item
+    pub quick_swap: KeyCode,
+    /// enemies on / off
+    pub peaceful:   KeyCode,
+    /// held (or toggled, see `Settings::sprint_toggle`); a Ctrl used
+    /// for Ctrl+Z doesn't count as a sprint press
+    pub sprint:     KeyCode,
+    /// held while placing a ladder: a one‑way platform instead
+    pub platform:   KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            jump:       KeyCode::Space,
+            dash:       KeyCode::ShiftLeft,
+            dash_alt:   Some(KeyCode::ShiftRight),
+            fullscreen: KeyCode::Escape,
+            backpack:   KeyCode::Tab,
+            crafting:   KeyCode::KeyC,
+            scanner:    KeyCode::KeyQ,
+            free_cam:   KeyCode::F6,
+            interact:   KeyCode::KeyE,
+            undo:       KeyCode::KeyZ,
+            stats:      KeyCode::KeyI,
+            precise_mine: KeyCode::KeyV,
+            quick_swap: KeyCode::KeyF,
+            peaceful:   KeyCode::KeyO,
+            sprint:     KeyCode::ControlLeft,
+            platform:   KeyCode::KeyB,
+        }
+    }
+}
+
+/// missing fields fall back to their defaults, so old files keep loading
+#[derive(Resource, Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct Settings {
+    /// windowed size (logical px)
+    pub resolution:    (f32, f32),
+    pub fullscreen:    bool,
+    pub master_volume: f32,
+    /// `--difficulty` on the command line still wins
+    pub difficulty:    DifficultyLevel,
+    /// start without enemies; `--peaceful` also turns it on
+    pub peaceful:      bool,
+    /// scales every particle burst (see particles.rs)
+    pub particle_quality: ParticleQuality,
+    /// thin bursts further while frames run long
+    pub auto_particles:   bool,
+    /// the sprint key switches auto‑run on / off instead of being held
+    pub sprint_toggle:    bool,
+    pub keys:          KeyBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            resolution:    (1920.0, 1080.0),
+            fullscreen:    false,
+            master_volume: 1.0,
+            difficulty:    DifficultyLevel::Normal,
+            peaceful:      false,
+            particle_quality: ParticleQuality::High,
+            auto_particles:   true,
+            sprint_toggle:    false,
+            keys:          KeyBindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// runs before logging is set up, hence `eprintln!`
+    pub fn load() -> Self {
+        match std::fs::read_to_string(SETTINGS_PATH) {
+            Ok(text) => ron::from_str(&text).unwrap_or_else(|e| {
+                eprintln!("⚠️  {SETTINGS_PATH}: {e} – using defaults");
+                Self::default()
+            }),
+            Err(_) => {
+                let settings = Self::default();
+                settings.save();
+                settings
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let text = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(t) => t,
+            Err(e) => return eprintln!("⚠️  could not serialise settings: {e}"),
+        };
+        if let Err(e) = std::fs::write(SETTINGS_PATH, text) {
+            eprintln!("⚠️  could not write {SETTINGS_PATH}: {e}");
+        }
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        if self.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Primary)
+        } else {
+            WindowMode::Windowed
+        }
+    }
+}
+
+/* ===========================================================
+   remember the windowed size (fullscreen resizes are ignored)
+   =========================================================== */
+pub fn track_window_size_system(
+    mut resized: EventReader<WindowResized>,
+    mut settings: ResMut<Settings>,
+    window_q: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Some(ev) = resized.read().last() else { return };
+    let Ok(window) = window_q.get_single() else { return };
+    if window.mode == WindowMode::Windowed {
+        settings.resolution = (ev.width, ev.height);
+    }
+}
+
+/* ===========================================================
+   write the file back on exit
+   =========================================================== */
+pub fn save_settings_on_exit(mut exit: EventReader<AppExit>, settings: Res<Settings>) {
+    if exit.read().next().is_some() {
+        settings.save();
+    }
+}
@@ -0,0 +1,189 @@
+//! signs – placeable text markers & the interaction prompt
+//!
+//! With the sign selected (5), clicking an empty tile next to solid ground
+//! plants a sign and opens a small text‑entry mode: type, Backspace to
+//! erase, Enter to confirm, Escape to cancel.  While typing, keyboard
+//! state is swallowed so WASD / hot‑keys don't fire.  Standing near a sign
+//! shows its text in the prompt label at the bottom of the screen.
+
+use bevy::input::gamepad::Gamepad;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::components::{HeldItem, Inventory, Player, Sign};
+use crate::constants::*;
+use crate::gamepad::{pad_just_pressed, GamepadAim, PAD_USE};
+use crate::world_gen::{world_to_cell, Terrain, TerrainQuery, TileKind};
+
+/// sign currently being written (if any) and its text so far
+#[derive(Resource, Default)]
+pub struct SignEditor {
+    pub editing: Option<Entity>,
+    pub buffer:  String,
+}
+
+#[derive(Component)]
+pub struct InteractionPrompt;
+
+/* ===========================================================
+   startup: hidden label, bottom centre
+   =========================================================== */
+pub fn setup_interaction_prompt(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont { font_size: 18.0, ..default() },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom:  Val::Px(40.0),
+            left:    Val::Percent(40.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        Visibility::Hidden,
+        InteractionPrompt,
+    ));
+}
+
+/* ===========================================================
+   placement – empty tile with at least one solid neighbour
+   =========================================================== */
+pub fn place_sign_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
+    mut editor: ResMut<SignEditor>,
+    terrain: TerrainQuery,
+    player_q: Query<(&Transform, &Inventory), With<Player>>,
+    signs: Query<&Sign>,
+    mut commands: Commands,
+) {
+    if editor.editing.is_some() {
+        return;
+    }
+    let Ok((tf, inv)) = player_q.get_single() else { return };
+    if inv.selected != HeldItem::Sign {
+        return;
+    }
+    if !(mouse.just_pressed(MouseButton::Left) || pad_just_pressed(&pads, PAD_USE)) {
+        return;
+    }
+    let Some(world) = aim.point else { return };
+    if (world - tf.translation.truncate()).length_squared() > tiles(DIG_RADIUS).powi(2) {
+        return;
+    }
+
+    let Some((ux, uy)) = terrain.world_to_tile(world) else { return };
+    let (tx, ty) = (ux as i32, uy as i32);
+    if !matches!(terrain.kind_at((tx, ty)), Some(TileKind::Air | TileKind::Sky)) {
+        return;
+    }
+    let anchored = [(0, 1), (0, -1), (1, 0), (-1, 0)]
+        .iter()
+        .any(|&(dx, dy)| terrain.is_solid((tx + dx, ty + dy)));
+    if !anchored || signs.iter().any(|s| (s.x, s.y) == (ux, uy)) {
+        return;
+    }
+
+    let e = commands
+        .spawn((
+            Sprite {
+                color: Color::srgb(0.60, 0.42, 0.20),
+                custom_size: Some(Vec2::new(tile_size() * 0.8, tile_size() * 0.6)),
+                ..default()
+            },
+            Transform::from_translation(terrain.tile_to_world((ux, uy)).extend(7.0)),
+            Sign { text: String::new(), x: ux, y: uy },
+        ))
+        .id();
+    editor.editing = Some(e);
+    editor.buffer.clear();
+}
+
+/* ===========================================================
+   text entry – runs in PreUpdate, right after input is read,
+   and swallows the keyboard so gameplay never sees the keys
+   =========================================================== */
+pub fn sign_text_entry_system(
+    mut events: EventReader<KeyboardInput>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut editor: ResMut<SignEditor>,
+    mut signs: Query<&mut Sign>,
+    mut commands: Commands,
+) {
+    let Some(e) = editor.editing else {
+        events.clear();
+        return;
+    };
+
+    for ev in events.read() {
+        if !ev.state.is_pressed() {
+            continue;
+        }
+        match &ev.logical_key {
+            Key::Enter => {
+                if let Ok(mut sign) = signs.get_mut(e) {
+                    sign.text = std::mem::take(&mut editor.buffer);
+                }
+                editor.editing = None;
+                break;
+            }
+            Key::Escape => {
+                commands.entity(e).despawn();
+                editor.editing = None;
+                editor.buffer.clear();
+                break;
+            }
+            Key::Backspace => {
+                editor.buffer.pop();
+            }
+            Key::Space if editor.buffer.len() < SIGN_MAX_LEN => editor.buffer.push(' '),
+            Key::Character(s) if editor.buffer.len() + s.len() <= SIGN_MAX_LEN => {
+                editor.buffer.push_str(s);
+            }
+            _ => {}
+        }
+    }
+    keys.reset_all();
+}
+
+/* ===========================================================
+   prompt: editor buffer while typing, else the nearest sign
+   =========================================================== */
+pub fn interaction_prompt_system(
+    editor: Res<SignEditor>,
+    terrain: Res<Terrain>,
+    player_q: Query<&Transform, With<Player>>,
+    signs: Query<&Sign>,
+    mut q: Query<(&mut Text, &mut Visibility), With<InteractionPrompt>>,
+) {
+    let Ok((mut text, mut vis)) = q.get_single_mut() else { return };
+
+    if editor.editing.is_some() {
+        text.0 = format!("Sign: {}_   (Enter = done, Esc = cancel)", editor.buffer);
+        *vis = Visibility::Visible;
+        return;
+    }
+
+    let Ok(tf) = player_q.get_single() else { return };
+    let (px, py) = world_to_cell(terrain.height, tf.translation.truncate());
+
+    let near = signs
+        .iter()
+        .filter(|s| !s.text.is_empty())
+        .map(|s| ((s.x as i32 - px).abs().max((s.y as i32 - py).abs()), s))
+        .filter(|&(d, _)| d <= SIGN_READ_RADIUS)
+        .min_by_key(|&(d, _)| d);
+
+    match near {
+        Some((_, sign)) => {
+            if text.0 != sign.text {
+                text.0 = sign.text.clone();
+            }
+            *vis = Visibility::Visible;
+        }
+        None => *vis = Visibility::Hidden,
+    }
+}
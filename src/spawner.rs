@@ -0,0 +1,119 @@
+//! orc portals – deep cavern rooms that keep letting orcs through
+//!
+//! Spots are picked during generation (`Terrain::spawner_spots`) from the
+//! same settled room floors crates use.  A portal only works while the
+//! player is within SPAWNER_RANGE and it sits inside the ActiveRect, and
+//! never has more than SPAWNER_MAX_ALIVE of its own orcs about.  Bullets
+//! chip at its hp (same box test as crates); at zero it closes in a
+//! burst of particles.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::{Bullet, Enemy, Player, Spawner};
+use crate::constants::*;
+use crate::difficulty::Difficulty;
+use crate::enemy::{spawn_orc_body, OrcAssets};
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::world_gen::{tile_to_world_y, world_to_tile_y, ActiveRect, Decal, Terrain};
+
+/* ===========================================================
+   on enter: one portal per generated spot, standing on the floor
+   =========================================================== */
+pub fn spawn_spawners(mut commands: Commands, terrain: Res<Terrain>) {
+    let size = SPAWNER_SIZE * tile_size();
+    for &(x, y) in &terrain.spawner_spots {
+        let floor = tile_to_world_y(terrain.height, y) - tile_size() * 0.5;
+        commands.spawn((
+            Sprite {
+                color: SPAWNER_COLOR,
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_xyz(x as f32 * tile_size(), floor + size.y * 0.5, 7.0),
+            Spawner { hp: SPAWNER_HP, timer: SPAWNER_INTERVAL, spawned: Vec::new() },
+        ));
+    }
+    info!("spawned {} orc portals", terrain.spawner_spots.len());
+}
+
+/* ===========================================================
+   throb, take bullets, let orcs through while the player is near
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn spawner_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    assets: Option<Res<OrcAssets>>,
+    rect: Res<ActiveRect>,
+    mut terrain: ResMut<Terrain>,
+    difficulty: Res<Difficulty>,
+    mut pool: ResMut<ParticlePool>,
+    player_q: Query<&Transform, With<Player>>,
+    bullets: Query<(Entity, &Transform, &Bullet)>,
+    enemies: Query<(), With<Enemy>>,
+    mut q: Query<(Entity, &Transform, &mut Spawner, &mut Sprite)>,
+) {
+    let dt    = time.delta_secs();
+    let pulse = 0.75 + 0.25 * (time.elapsed_secs() * SPAWNER_PULSE_SPEED).sin();
+    let half  = SPAWNER_SIZE * tile_size() * 0.5;
+    let player_pos = player_q.get_single().ok().map(|tf| tf.translation.truncate());
+    let mut rng = rand::thread_rng();
+
+    for (e, tf, mut spawner, mut sprite) in &mut q {
+        let pos = tf.translation.truncate();
+        sprite.color = SPAWNER_COLOR.with_alpha(pulse);
+
+        for (b_ent, b_tf, bullet) in &bullets {
+            let delta = (b_tf.translation.truncate() - pos).abs();
+            if delta.x <= half.x && delta.y <= half.y {
+                spawner.hp -= bullet.damage;
+                commands.entity(b_ent).despawn();
+            }
+        }
+        if spawner.hp <= 0.0 {
+            close_portal(&mut commands, &mut pool, pos.extend(8.0));
+            let floor = pos - Vec2::Y * (half.y + tile_size() * 0.5);
+            let (fx, fy) = ((floor.x / tile_size()).round() as i32, world_to_tile_y(terrain.height, floor.y));
+            terrain.splat_decal(fx, fy, DECAL_PORTAL_RADIUS, Decal::Scorch);
+            commands.entity(e).despawn();
+            continue;
+        }
+
+        /* only near the player, and only on the simulated part of the map */
+        let tx = (pos.x / tile_size()).round() as i32;
+        let ty = terrain.height as i32 - 1 - (pos.y / tile_size()).round() as i32;
+        let in_rect = (rect.min_x..=rect.max_x).contains(&tx) && (rect.min_y..=rect.max_y).contains(&ty);
+        let near = player_pos.is_some_and(|p| (p - pos).length() < tiles(SPAWNER_RANGE));
+        if !(in_rect && near) {
+            continue;
+        }
+
+        spawner.spawned.retain(|&orc| enemies.contains(orc));
+        if difficulty.peaceful {
+            continue;
+        }
+        spawner.timer -= dt;
+        if spawner.timer > 0.0 || spawner.spawned.len() >= SPAWNER_MAX_ALIVE {
+            continue;
+        }
+        spawner.timer = SPAWNER_INTERVAL;
+
+        let Some(assets) = assets.as_deref() else { continue };
+        let body   = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) * 0.5;
+        let offset = rng.gen_range(-half.x..=half.x);
+        let at     = Vec2::new(pos.x + offset, pos.y - half.y + body.y);
+        let hp     = (ENEMY_MAX_HP as f32 * difficulty.hp).round() as i32;
+        let orc    = spawn_orc_body(&mut commands, assets, at, 1.0, hp, ENEMY_SPEED, Color::WHITE);
+        spawner.spawned.push(orc);
+    }
+}
+
+/// purple burst where a portal closed
+fn close_portal(commands: &mut Commands, pool: &mut ParticlePool, pos: Vec3) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..pool.scaled(SPAWNER_BURST) {
+        let vel = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(40.0..220.0);
+        pool.spawn(commands, ParticleKind::Debris, SPAWNER_COLOR, 3.0, pos, vel, SPAWNER_BURST_LIFE);
+    }
+}
@@ -0,0 +1,229 @@
+//! lifetime stats & achievements – `stats.ron` next to `settings.ron`
+//!
+//! Counters are bumped by the systems that cause them (mining, shooting,
+//! orc deaths); distance, depth and deaths are watched here.  The stats
+//! screen is toggled with the rebindable stats key (I).  Crossing an
+//! `ACHIEVEMENTS` threshold pops a short banner – ones already reached when
+//! the file was loaded stay quiet.  Written back on every new achievement
+//! and on exit.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::{Health, Player};
+use crate::constants::*;
+use crate::settings::Settings;
+use crate::world_gen::{world_to_cell, Terrain};
+
+const STATS_PATH: &str = "stats.ron";
+
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct Stats {
+    pub tiles_mined:   u64,
+    pub orcs_killed:   u64,
+    pub bullets_fired: u64,
+    /// tiles walked / flown
+    pub distance:      f32,
+    pub deaths:        u32,
+    /// tiles below the surface
+    pub deepest:       u32,
+}
+
+/// (banner text, reached?)
+const ACHIEVEMENTS: &[(&str, fn(&Stats) -> bool)] = &[
+    ("First blood",       |s| s.orcs_killed >= 1),
+    ("100 orcs slain",    |s| s.orcs_killed >= 100),
+    ("1 000 tiles mined", |s| s.tiles_mined >= 1_000),
+    ("Trigger happy",     |s| s.bullets_fired >= 1_000),
+    ("Wanderer",          |s| s.distance >= 10_000.0),
+    ("Into the deep",     |s| s.deepest >= 500),
+];
+
+impl Stats {
+    pub fn load() -> Self {
+        match std::fs::read_to_string(STATS_PATH) {
+            Ok(text) => ron::from_str(&text).unwrap_or_else(|e| {
+                warn!("{STATS_PATH}: {e} – starting fresh");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(text) => {
+                if let Err(e) = std::fs::write(STATS_PATH, text) {
+                    warn!("could not write {STATS_PATH}: {e}");
+                }
+            }
+            Err(e) => warn!("could not serialise stats: {e}"),
+        }
+    }
+}
+
+/// which achievements have been announced, and the banner on screen
+#[derive(Resource)]
+pub struct Achievements {
+    reached: Vec<bool>,
+    banner:  f32,
+}
+
+impl Achievements {
+    /// everything already reached in `stats` counts as announced
+    pub fn from_stats(stats: &Stats) -> Self {
+        Self {
+            reached: ACHIEVEMENTS.iter().map(|(_, done)| done(stats)).collect(),
+            banner:  0.0,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct StatsScreen;
+
+#[derive(Component)]
+pub struct AchievementBanner;
+
+/* ===========================================================
+   startup: read the file once logging is up
+   =========================================================== */
+pub fn load_stats(mut commands: Commands) {
+    let stats = Stats::load();
+    commands.insert_resource(Achievements::from_stats(&stats));
+    commands.insert_resource(stats);
+}
+
+/* ===========================================================
+   startup: hidden screen (centre) + hidden banner (top)
+   =========================================================== */
+pub fn setup_stats_screen(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont { font_size: 18.0, ..default() },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(40.0),
+            top:  Val::Percent(30.0),
+            padding: UiRect::all(Val::Px(12.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+        Visibility::Hidden,
+        StatsScreen,
+    ));
+    commands.spawn((
+        Text::new(""),
+        TextFont { font_size: 20.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.85, 0.2)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(42.0),
+            top:  Val::Px(56.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        Visibility::Hidden,
+        AchievementBanner,
+    ));
+}
+
+/* ===========================================================
+   distance, depth & deaths – watched on the player
+   =========================================================== */
+pub fn stats_tracking_system(
+    mut stats: ResMut<Stats>,
+    terrain: Res<Terrain>,
+    mut last: Local<Option<(Vec2, bool)>>,
+    player_q: Query<(&Transform, &Health), With<Player>>,
+) {
+    let Ok((tf, health)) = player_q.get_single() else { return };
+    let pos  = tf.translation.truncate();
+    let dead = health.current <= 0.0;
+
+    if let Some((prev, was_dead)) = *last {
+        // the mirror jumps across the map – that isn't walking
+        let moved = (pos - prev).length() / tile_size();
+        if moved > 0.0 && moved < STATS_MAX_STEP {
+            stats.distance += moved;
+        }
+        if dead && !was_dead {
+            stats.deaths += 1;
+        }
+    }
+    *last = Some((pos, dead));
+
+    let (tx, ty) = world_to_cell(terrain.height, pos);
+    if tx >= 0 && (tx as usize) < terrain.width {
+        let depth = (ty - terrain.height_map[tx as usize] as i32).max(0) as u32;
+        if depth > stats.deepest {
+            stats.deepest = depth;
+        }
+    }
+}
+
+/* ===========================================================
+   stats screen toggle + achievement banners
+   =========================================================== */
+pub fn stats_screen_system(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    stats: Res<Stats>,
+    mut achievements: ResMut<Achievements>,
+    mut screen_q: Query<(&mut Text, &mut Visibility), (With<StatsScreen>, Without<AchievementBanner>)>,
+    mut banner_q: Query<(&mut Text, &mut Visibility), With<AchievementBanner>>,
+) {
+    if let Ok((mut text, mut vis)) = screen_q.get_single_mut() {
+        if keys.just_pressed(settings.keys.stats) {
+            *vis = match *vis {
+                Visibility::Hidden => Visibility::Visible,
+                _                  => Visibility::Hidden,
+            };
+        }
+        if *vis != Visibility::Hidden {
+            text.0 = format!(
+                "Stats  ({:?} to close)\n\n\
+                 tiles mined    {}\n\
+                 orcs killed    {}\n\
+                 bullets fired  {}\n\
+                 distance       {:.0} tiles\n\
+                 deaths         {}\n\
+                 deepest        {} tiles down",
+                settings.keys.stats, stats.tiles_mined, stats.orcs_killed,
+                stats.bullets_fired, stats.distance, stats.deaths, stats.deepest,
+            );
+        }
+    }
+
+    let Ok((mut text, mut vis)) = banner_q.get_single_mut() else { return };
+    let mut unlocked = false;
+    for (i, (name, done)) in ACHIEVEMENTS.iter().enumerate() {
+        if !achievements.reached[i] && done(&stats) {
+            achievements.reached[i] = true;
+            achievements.banner     = ACHIEVEMENT_BANNER_TIME;
+            text.0 = format!("Achievement: {name}");
+            info!("achievement: {name}");
+            unlocked = true;
+        }
+    }
+    // a crash later on must not lose the unlock
+    if unlocked {
+        stats.save();
+    }
+    achievements.banner = (achievements.banner - time.delta_secs()).max(0.0);
+    *vis = if achievements.banner > 0.0 { Visibility::Visible } else { Visibility::Hidden };
+}
+
+/* ===========================================================
+   write the file back on exit
+   =========================================================== */
+pub fn save_stats_on_exit(mut exit: EventReader<AppExit>, stats: Res<Stats>) {
+    if exit.read().next().is_some() {
+        stats.save();
+    }
+}
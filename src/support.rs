@@ -0,0 +1,165 @@
+//! structural support – small floating clusters collapse
+//!
+//! Whenever a tile whose kind changed (`Terrain::kind_changed`, filled by
+//! `Terrain::touch`) is empty, each solid neighbour is
+//! flood‑filled.  A cluster counts as supported as soon as the fill grows
+//! past `SUPPORT_MAX_TILES`, leaves `SUPPORT_RADIUS` or touches the map
+//! edge – so big terrain masses bail out early and the check stays local.
+//! Anything smaller is turned into falling blocks that re‑settle on landing.
+
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+use crate::components::{FallingBlock, Velocity};
+use crate::constants::*;
+use crate::tile_stream::{solid, solid_kind};
+use crate::world_gen::{tile_to_world_y, Terrain, TileKind};
+
+/// flood‑fill budget before a cluster is assumed anchored
+const SUPPORT_MAX_TILES: usize = 256;
+/// max distance (tiles, per axis) the fill may stray from its seed
+const SUPPORT_RADIUS: i32 = 12;
+/// terminal velocity of a falling block (px / s)
+const FALL_MAX_SPEED: f32 = 900.0;
+
+/* ===========================================================
+   bounded flood fill
+   =========================================================== */
+/// `Some(tiles)` if the cluster containing `seed` is floating, `None` if
+/// supported.  Every tile of a supported fill goes into `anchored` so later
+/// seeds in the same region bail out immediately.
+fn floating_cluster(
+    terrain: &Terrain,
+    seed: (i32, i32),
+    anchored: &mut HashSet<(i32, i32)>,
+) -> Option<Vec<(i32, i32)>> {
+    let (w, h) = (terrain.width as i32, terrain.height as i32);
+    let mut seen:  HashSet<(i32, i32)>   = HashSet::from([seed]);
+    let mut queue: VecDeque<(i32, i32)>  = VecDeque::from([seed]);
+    let mut supported = false;
+
+    while let Some((x, y)) = queue.pop_front() {
+        if anchored.contains(&(x, y))
+            || seen.len() > SUPPORT_MAX_TILES
+            || (x - seed.0).abs() > SUPPORT_RADIUS
+            || (y - seed.1).abs() > SUPPORT_RADIUS
+            || x == 0 || x == w - 1 || y == h - 1
+        {
+            supported = true;
+            break;
+        }
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let n = (x + dx, y + dy);
+            if n.0 < 0 || n.1 < 0 || n.0 >= w || n.1 >= h || seen.contains(&n) {
+                continue;
+            }
+            if solid_kind(terrain.tiles[n.1 as usize][n.0 as usize].kind) {
+                seen.insert(n);
+                queue.push_back(n);
+            }
+        }
+    }
+
+    if supported {
+        anchored.extend(seen);
+        None
+    } else {
+        Some(seen.into_iter().collect())
+    }
+}
+
+/* ===========================================================
+   support check – seeded only by tiles whose kind changed, so FOV,
+   fades and decals never start a search
+   =========================================================== */
+pub fn support_system(mut commands: Commands, mut terrain: ResMut<Terrain>) {
+    if terrain.kind_changed.is_empty() {
+        return;
+    }
+    let mut anchored: HashSet<(i32, i32)> = HashSet::new();
+    let mut checked:  HashSet<(i32, i32)> = HashSet::new();
+    let mut falling:  Vec<(i32, i32)>     = Vec::new();
+
+    let seeds = std::mem::take(&mut terrain.kind_changed);
+    for &(x, y) in &seeds {
+        if solid_kind(terrain.tiles[y][x].kind) {
+            continue; // only emptied tiles can remove support
+        }
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let n = (x as i32 + dx, y as i32 + dy);
+            if n.0 < 0 || n.1 < 0 || n.0 >= terrain.width as i32 || n.1 >= terrain.height as i32 {
+                continue;
+            }
+            if !checked.insert(n) || !solid(&terrain, n.0, n.1) {
+                continue;
+            }
+            if let Some(cluster) = floating_cluster(&terrain, n, &mut anchored) {
+                checked.extend(cluster.iter().copied());
+                falling.extend(cluster);
+            }
+        }
+    }
+
+    /* detach: tile → falling sprite ---------------------------------- */
+    for (x, y) in falling {
+        let (ux, uy) = (x as usize, y as usize);
+        let tile = terrain.tiles[uy][ux];
+        terrain.clear(ux, uy);
+
+        let c = tile.base_rgb;
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(c.x.clamp(0.0, 1.0), c.y.clamp(0.0, 1.0), c.z.clamp(0.0, 1.0)),
+                custom_size: Some(Vec2::splat(tile_size())),
+                ..default()
+            },
+            Transform::from_xyz(ux as f32 * tile_size(), tile_to_world_y(terrain.height, uy), 1.0),
+            Velocity(Vec2::ZERO),
+            FallingBlock { x: ux, kind: tile.kind, mine_time: tile.mine_time },
+        ));
+    }
+}
+
+/* ===========================================================
+   falling blocks – gravity, then re‑settle on the first solid tile
+   =========================================================== */
+pub fn falling_block_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut terrain: ResMut<Terrain>,
+    mut q: Query<(Entity, &mut Transform, &mut Velocity, &FallingBlock)>,
+) {
+    let dt = time.delta_secs();
+    let h  = terrain.height as i32;
+    // row whose centre is at or above world‑y `wy`
+    let row_above = |wy: f32| ((h - 1) as f32 - wy / tile_size()).floor() as i32;
+
+    for (e, mut tf, mut vel, block) in &mut q {
+        vel.0.y = (vel.0.y + GRAVITY * dt).max(-FALL_MAX_SPEED);
+        let new_y = tf.translation.y + vel.0.y * dt;
+
+        /* walk every row passed this frame so fast blocks can't tunnel */
+        let mut landed = None;
+        for r in row_above(tf.translation.y)..=row_above(new_y) {
+            if r >= h - 1 || solid(&terrain, block.x as i32, r + 1) {
+                landed = Some(r.clamp(0, h - 1));
+                break;
+            }
+        }
+
+        let Some(mut r) = landed else {
+            tf.translation.y = new_y;
+            continue;
+        };
+
+        /* find the nearest free cell at or above the landing row */
+        while r > 0 && !matches!(terrain.tiles[r as usize][block.x].kind, TileKind::Air | TileKind::Sky) {
+            r -= 1;
+        }
+        let tile = &mut terrain.tiles[r as usize][block.x];
+        tile.kind      = block.kind;
+        tile.mine_time = block.mine_time;
+        terrain.touch(block.x, r as usize);
+        commands.entity(e).despawn();
+    }
+}
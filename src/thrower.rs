@@ -0,0 +1,168 @@
+//! block throwers – orcs that turn the player's own walls into ammo
+//!
+//! Every stone block the player places is remembered in `PlacedBlocks`.
+//! An aggroed `Thrower` within THROWER_REACH of one tears it out (it goes
+//! through the usual tile‑change path, so debris, AO and support all see
+//! it) and lobs it at the player.  One block per THROWER_INTERVAL, so a
+//! single orc chips at a base rather than flattening it.  The block
+//! shatters on the first solid tile or on the player, knocking the player
+//! back if they are close.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::debug::DebugFlags;
+use crate::difficulty::Difficulty;
+use crate::particles::{ParticleKind, ParticlePool};
+use crate::player::{blast_knockback, spawn_debris};
+use crate::tile_stream::solid;
+use crate::world_gen::{tile_to_world_y, Terrain, TerrainQuery, TileKind};
+
+/// stone blocks placed by the player; entries whose tile has since
+/// changed are dropped when a thrower looks at them
+#[derive(Resource, Default)]
+pub struct PlacedBlocks(pub HashSet<(usize, usize)>);
+
+/* ===========================================================
+   pick up the nearest exposed placed block and throw it
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn thrower_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut terrain: ResMut<Terrain>,
+    mut placed: ResMut<PlacedBlocks>,
+    mut pool: ResMut<ParticlePool>,
+    difficulty: Res<Difficulty>,
+    player_q: Query<&Transform, With<Player>>,
+    mut q: Query<(&Transform, &Enemy, &mut Thrower), With<Active>>,
+) {
+    let Ok(player_tf) = player_q.get_single() else { return };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+
+    for (tf, enemy, mut thrower) in &mut q {
+        thrower.cooldown = (thrower.cooldown - dt).max(0.0);
+        if difficulty.peaceful || !enemy.aggro || thrower.cooldown > 0.0 {
+            continue;
+        }
+        let pos = tf.translation.truncate();
+        placed.0.retain(|&(x, y)| terrain.tiles[y][x].kind == TileKind::Stone);
+
+        let Some((x, y)) = nearest_block(&terrain, &placed.0, pos) else { continue };
+        placed.0.remove(&(x, y));
+        thrower.cooldown = THROWER_INTERVAL;
+
+        let rgb = terrain.tiles[y][x].base_rgb;
+        spawn_debris(&mut commands, &mut pool, &terrain, x, y);
+        terrain.clear(x, y);
+
+        let from = Vec2::new(x as f32 * tile_size(), tile_to_world_y(terrain.height, y));
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(rgb.x, rgb.y, rgb.z),
+                custom_size: Some(Vec2::splat(tile_size())),
+                ..default()
+            },
+            Transform::from_translation(from.extend(12.0)),
+            Velocity(lob(from, player_pos)),
+            ThrownBlock { life: THROWN_LIFETIME },
+        ));
+    }
+}
+
+/// closest placed block within reach that has open air on some side
+/// (a block buried in the middle of a wall can't be pried loose)
+fn nearest_block(
+    terrain: &Terrain,
+    placed: &HashSet<(usize, usize)>,
+    pos: Vec2,
+) -> Option<(usize, usize)> {
+    let reach = tiles(THROWER_REACH);
+    placed
+        .iter()
+        .copied()
+        .filter(|&(x, y)| {
+            let (x, y) = (x as i32, y as i32);
+            [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .iter()
+                .any(|&(dx, dy)| !solid(terrain, x + dx, y + dy))
+        })
+        .map(|(x, y)| {
+            let at = Vec2::new(x as f32 * tile_size(), tile_to_world_y(terrain.height, y));
+            ((x, y), (at - pos).length())
+        })
+        .filter(|&(_, d)| d <= reach)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(tile, _)| tile)
+}
+
+/// launch velocity that lands on `to` after a flight of
+/// distance / THROWER_SPEED seconds under THROWER_GRAVITY
+fn lob(from: Vec2, to: Vec2) -> Vec2 {
+    let delta = to - from;
+    let t = (delta.length() / THROWER_SPEED).max(0.2);
+    let g = GRAVITY * THROWER_GRAVITY;
+    Vec2::new(delta.x / t, delta.y / t - 0.5 * g * t)
+}
+
+/* ===========================================================
+   blocks in flight – fall, hit, shatter
+   =========================================================== */
+pub fn thrown_block_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut pool: ResMut<ParticlePool>,
+    terrain: TerrainQuery,
+    difficulty: Res<Difficulty>,
+    debug: Res<DebugFlags>,
+    mut blocks: Query<(Entity, &mut Transform, &mut Velocity, &mut ThrownBlock, &Sprite), Without<Player>>,
+    mut player_q: Query<(&Transform, &mut Health, &Collider, &mut Player, &mut Velocity)>,
+) {
+    let dt = time.delta_secs();
+    let mut player = player_q.get_single_mut().ok();
+    let dmg_scale  = if debug.invincible { 0.0 } else { difficulty.damage };
+
+    for (e, mut tf, mut vel, mut block, sprite) in &mut blocks {
+        block.life -= dt;
+        vel.0.y    += GRAVITY * THROWER_GRAVITY * dt;
+        tf.translation += (vel.0 * dt).extend(0.0);
+        let p = tf.translation.truncate();
+
+        // floor cell, as the player's collision sweeps; off the map is a hit
+        let (tx, ty) = terrain.world_to_cell(p);
+        let mut hit  = block.life <= 0.0 || solid(&terrain, tx, ty);
+        if let Some((player_tf, health, player_box, ..)) = player.as_mut() {
+            let delta = (player_tf.translation.truncate() - p).abs();
+            let reach = player_box.half + Vec2::splat(tile_size() * 0.5);
+            if !hit && delta.x < reach.x && delta.y < reach.y {
+                health.current = (health.current - THROWN_DAMAGE * dmg_scale).max(0.0);
+                health.last_damage = 0.0;
+                hit = true;
+            }
+        }
+        if hit {
+            if let Some((player_tf, _, _, ply, player_vel)) = player.as_mut() {
+                blast_knockback(
+                    ply, player_vel, player_tf.translation.truncate(), p,
+                    tiles(THROWN_BLAST_RADIUS), THROWN_KNOCKBACK,
+                );
+            }
+            shatter(&mut commands, &mut pool, p, sprite.color);
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+/// stone shards flying out of a broken block
+fn shatter(commands: &mut Commands, pool: &mut ParticlePool, pos: Vec2, color: Color) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..pool.scaled(THROWN_SHARDS) {
+        let vel = Vec2::new(rng.gen_range(-140.0..140.0), rng.gen_range(20.0..180.0));
+        pool.spawn(commands, ParticleKind::Debris, color, 2.5, pos.extend(12.0), vel, DEBRIS_LIFETIME);
+    }
+}
@@ -0,0 +1,181 @@
+//! tile textures – one baked greyscale atlas for every tile kind
+//!
+//! Each cell is TILE_ATLAS_CELL × TILE_ATLAS_CELL pixels of near‑white
+//! pattern (speckled dirt, cobbled stone, grass blades, …), sampled
+//! nearest so the pixels stay crisp.  The sprite colour still carries
+//! `base_rgb` × brightness × AO (see `tile_stream::color_and_z`), so the
+//! texture only adds detail on top of the existing tint and lighting.
+
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use rand::Rng;
+
+use crate::constants::*;
+use crate::world_gen::{Tile, TileKind};
+
+/// atlas cells, in order
+#[derive(Clone, Copy)]
+enum Cell {
+    Plain,
+    Dirt,
+    Grass,
+    Stone,
+    Obsidian,
+    Snow,
+    Glass,
+    Booster,
+    Ladder,
+    Platform,
+    Wall,
+    Portal,
+}
+
+const CELLS: [Cell; 12] = [
+    Cell::Plain, Cell::Dirt, Cell::Grass, Cell::Stone, Cell::Obsidian, Cell::Snow,
+    Cell::Glass, Cell::Booster, Cell::Ladder, Cell::Platform, Cell::Wall,
+    Cell::Portal,
+];
+
+#[derive(Resource)]
+pub struct TileAtlas {
+    pub image:  Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+}
+
+impl TileAtlas {
+    /// sprite for `tile`, tinted `color` and stretched to one tile
+    pub fn sprite(&self, tile: &Tile, color: Color) -> Sprite {
+        let mut sprite = Sprite::from_atlas_image(
+            self.image.clone(),
+            TextureAtlas { layout: self.layout.clone(), index: cell_of(tile) as usize },
+        );
+        sprite.color       = color;
+        sprite.custom_size = Some(Vec2::splat(tile_size()));
+        sprite
+    }
+}
+
+/// which cell draws `tile` – a wall showing through open space wins
+fn cell_of(tile: &Tile) -> Cell {
+    if tile.wall.is_some() && matches!(tile.kind, TileKind::Air | TileKind::Sky) {
+        return Cell::Wall;
+    }
+    match tile.kind {
+        TileKind::Dirt     => Cell::Dirt,
+        TileKind::Grass    => Cell::Grass,
+        TileKind::Stone    => Cell::Stone,
+        TileKind::Obsidian => Cell::Obsidian,
+        TileKind::Snow     => Cell::Snow,
+        TileKind::Glass    => Cell::Glass,
+        TileKind::Booster  => Cell::Booster,
+        TileKind::Ladder   => Cell::Ladder,
+        TileKind::Platform => Cell::Platform,
+        TileKind::Portal   => Cell::Portal,
+        TileKind::Air | TileKind::Sky | TileKind::Lava => Cell::Plain,
+    }
+}
+
+/* ===========================================================
+   startup: bake every cell side by side into one strip
+   =========================================================== */
+pub fn setup_tile_atlas(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let n     = TILE_ATLAS_CELL;
+    let width = n * CELLS.len();
+    let mut rng    = rand::thread_rng();
+    let mut pixels = vec![255u8; width * n];
+
+    for (i, &cell) in CELLS.iter().enumerate() {
+        for y in 0..n {
+            for x in 0..n {
+                pixels[y * width + i * n + x] = shade(cell, x, y, n, &mut rng);
+            }
+        }
+    }
+
+    let data = pixels.iter().flat_map(|&v| [v, v, v, 255]).collect();
+    let mut image = Image::new(
+        Extent3d { width: width as u32, height: n as u32, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    image.sampler = ImageSampler::nearest();
+
+    let layout = TextureAtlasLayout::from_grid(UVec2::splat(n as u32), CELLS.len() as u32, 1, None, None);
+    commands.insert_resource(TileAtlas {
+        image:  images.add(image),
+        layout: layouts.add(layout),
+    });
+}
+
+/// grey level of one pixel (y = 0 is the top row); everything stays
+/// fairly bright so the tint keeps its colour
+fn shade(cell: Cell, x: usize, y: usize, n: usize, rng: &mut impl Rng) -> u8 {
+    let last = n - 1;
+    match cell {
+        Cell::Plain => 255,
+        Cell::Dirt => {
+            if rng.gen_bool(0.2) { rng.gen_range(190..220) } else { rng.gen_range(235..=255) }
+        }
+        Cell::Grass => {
+            // blades along the top, dirt speckle below
+            if y < 2 && (x + y) % 2 == 0 { 255 }
+            else if y < 2 { 215 }
+            else if rng.gen_bool(0.15) { 200 } else { 240 }
+        }
+        Cell::Stone => {
+            // two staggered rows of cobbles
+            let row    = y * 2 / n;
+            let offset = if row == 0 { 0 } else { n / 2 };
+            let seam_x = (x + offset) % (n / 2) == 0;
+            let seam_y = y == 0 || y == n / 2;
+            if seam_x || seam_y { 185 } else { rng.gen_range(225..=255) }
+        }
+        Cell::Obsidian => {
+            // dark glassy ground with a diagonal glint
+            if x + y == last || x + y == last - 1 { 255 } else { rng.gen_range(205..225) }
+        }
+        Cell::Snow => {
+            if rng.gen_bool(0.08) { 225 } else { 255 }
+        }
+        Cell::Glass => {
+            // frame and a short shine stroke
+            if x == 0 || y == 0 || x == last || y == last { 210 }
+            else if x == y && x > 0 && x < n / 2 { 255 }
+            else { 240 }
+        }
+        Cell::Booster => {
+            // an up chevron (booster.rs draws the real direction arrow)
+            let mid = n as i32 / 2;
+            let dx  = (x as i32 - mid).abs();
+            if y as i32 == dx + 1 || y as i32 == dx + 2 { 255 } else { 205 }
+        }
+        Cell::Ladder => {
+            // rails and rungs over a dim back
+            if x == 1 || x == last - 1 || y % 3 == 1 { 255 } else { 150 }
+        }
+        Cell::Platform => {
+            // plank on top, thin supports below
+            if y < 2 { 255 } else if y == 2 { 200 } else { 160 }
+        }
+        Cell::Wall => {
+            // running bond bricks
+            let course = y / (n / 2).max(1);
+            let offset = if course % 2 == 0 { 0 } else { n / 2 };
+            if y % (n / 2) == 0 || (x + offset) % n == 0 { 190 } else { 240 }
+        }
+        Cell::Portal => {
+            // bright ring around a dim core
+            let c = last as f32 * 0.5;
+            let r = ((x as f32 - c).powi(2) + (y as f32 - c).powi(2)).sqrt();
+            if (r - c * 0.75).abs() < 0.8 { 255 } else if r < c * 0.75 { 170 } else { 215 }
+        }
+    }
+}
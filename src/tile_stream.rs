@@ -1,59 +1,123 @@
-//! run‑time terrain streaming, digging, collision & helpers
+//! run‑time terrain streaming, collision & helpers
 //!
 //! All code that *updates* and *renders* the already‑generated
 //! tiles lives here.  Generation itself is in `world_gen.rs`.
 
-use bevy::input::ButtonInput;
 use bevy::prelude::*;
 use bevy::window::Window;
-use noise::NoiseFn;
+use std::collections::HashSet;
 
 use crate::components::*;
 use crate::constants::*;
 
 /* ===========================================================
-   loaded window (4×3 chunk grid)
+   loaded window – sized to cover the (zoomed) viewport,
+   never smaller than LOADED_CHUNK_COLS × LOADED_CHUNK_ROWS
    =========================================================== */
 #[derive(Resource, Copy, Clone, Debug, PartialEq, Eq)]
 pub struct LoadedWindow {
     pub origin_cx: i32, // left‑most loaded chunk column
     pub origin_cy: i32, // top‑most  loaded chunk row
+    pub cols:      i32, // span in chunks
+    pub rows:      i32,
 }
+
+impl LoadedWindow {
+    /// is tile (x, y) inside the streamed chunk window?
+    #[inline]
+    pub fn contains_tile(&self, x: usize, y: usize) -> bool {
+        let cx = (x / CHUNK_WIDTH)  as i32;
+        let cy = (y / CHUNK_HEIGHT) as i32;
+        cx >= self.origin_cx
+            && cx <  self.origin_cx + self.cols
+            && cy >= self.origin_cy
+            && cy <  self.origin_cy + self.rows
+    }
+}
+use crate::tile_atlas::TileAtlas;
+use crate::visibility::LightingConfig;
 use crate::world_gen::{
-    tile_to_world_y, world_to_tile_y, ActiveRect, LastRect, Terrain, Tile, TileKind,
+    tile_base_rgb, tile_to_world_y, world_to_tile_y, ActiveRect, Terrain, Tile, TileKind,
     EXPLORED_BRIGHTNESS,
 };
 
 /* ===========================================================
    helpers for streaming sprites
    =========================================================== */
+/// lit → full, seen before → dimmed, never seen → black; a background
+/// wall never drops below WALL_MIN_BRIGHTNESS.
+/// `explored` lives on the full grid, so it survives streaming.
+/// Full bright lights everything that reaches the redraw queue.
 #[inline]
-fn brightness(tile: &Tile) -> f32 {
-    if tile.visible {
+fn brightness(tile: &Tile, full_bright: bool) -> f32 {
+    let floor = if tile.wall.is_some() { WALL_MIN_BRIGHTNESS } else { 0.0 };
+    // lava is its own light source
+    if full_bright || tile.kind == TileKind::Lava || tile.visible {
         1.0
     } else if tile.explored {
-        EXPLORED_BRIGHTNESS
+        EXPLORED_BRIGHTNESS.max(floor)
     } else {
-        0.0
+        floor
     }
 }
 
+/// open tile (air / sky) with a wall behind it – drawn as the wall
+#[inline]
+fn shows_wall(tile: &Tile) -> bool {
+    tile.wall.is_some() && matches!(tile.kind, TileKind::Air | TileKind::Sky)
+}
+
+/// 1.0 = open, down to 1 − AO_STRENGTH for an open tile boxed in by
+/// solid ones; solid tiles themselves are left alone
 #[inline]
-fn color_and_z(terrain: &Terrain, x: usize, y: usize) -> (Color, f32) {
+fn ambient_occlusion(terrain: &Terrain, x: usize, y: usize) -> f32 {
+    let tile = &terrain.tiles[y][x];
+    if tile.kind != TileKind::Air && !shows_wall(tile) {
+        return 1.0;
+    }
+    let mut occ = 0.0;
+    for dy in -1..=1_i32 {
+        for dx in -1..=1_i32 {
+            if (dx, dy) == (0, 0) || !solid(terrain, x as i32 + dx, y as i32 + dy) {
+                continue;
+            }
+            occ += if dx == 0 || dy == 0 { 1.0 } else { 0.5 };
+        }
+    }
+    1.0 - AO_STRENGTH * occ / 6.0
+}
+
+/// textured sprite (tinted by colour, brightness and AO) and depth
+#[inline]
+fn sprite_and_z(terrain: &Terrain, atlas: &TileAtlas, x: usize, y: usize) -> (Sprite, f32) {
     let tile     = terrain.tiles[y][x];
-    let base_rgb = tile.base_rgb * brightness(&tile);
+    let tint     = match tile.decal {
+        Some(decal) => {
+            let (rgb, amount) = decal.blend();
+            tile.base_rgb.lerp(rgb, amount)
+        }
+        None => tile.base_rgb,
+    };
+    let base_rgb = tint * tile.display_brightness * ambient_occlusion(terrain, x, y);
 
     let color = Color::srgb(
         base_rgb.x.clamp(0.0, 1.0),
         base_rgb.y.clamp(0.0, 1.0),
         base_rgb.z.clamp(0.0, 1.0),
     );
-    let z = if tile.kind == TileKind::Air { -1.0 } else { 0.0 };
-    (color, z)
+    // air and walls sit behind everything else
+    let z = if tile.kind == TileKind::Air || shows_wall(&tile) { -1.0 } else { 0.0 };
+    (atlas.sprite(&tile, color), z)
 }
 
 #[inline]
-fn ensure_sprite(commands: &mut Commands, terrain: &mut Terrain, x: i32, y: i32) {
+fn ensure_sprite(
+    commands: &mut Commands,
+    terrain: &mut Terrain,
+    atlas: &TileAtlas,
+    x: i32,
+    y: i32,
+) {
     if x < 0
         || y < 0
         || x >= terrain.width as i32
@@ -66,30 +130,22 @@ fn ensure_sprite(commands: &mut Commands, terrain: &mut Terrain, x: i32, y: i32)
     if terrain.sprite_entities[idx].is_some() {
         return;
     }
-    if !matches!(
-        terrain.tiles[uy][ux].kind,
-        TileKind::Grass
-            | TileKind::Dirt
-            | TileKind::Stone
-            | TileKind::Obsidian
-            | TileKind::Snow
-            | TileKind::Air
-    ) {
-        return; // Sky never gets a sprite
+    let kind = terrain.tiles[uy][ux].kind;
+    if !(solid_kind(kind)
+        || matches!(kind, TileKind::Lava | TileKind::Ladder | TileKind::Platform | TileKind::Portal | TileKind::Air)
+        || shows_wall(&terrain.tiles[uy][ux]))
+    {
+        return; // open sky never gets a sprite
     }
 
-    let (color, z) = color_and_z(terrain, ux, uy);
+    let (sprite, z) = sprite_and_z(terrain, atlas, ux, uy);
 
     let entity = if let Some(e) = terrain.free_sprites.pop() {
         commands.entity(e).insert((
             Visibility::Visible,
-            Sprite {
-                color,
-                custom_size: Some(Vec2::splat(TILE_SIZE)),
-                ..default()
-            },
+            sprite,
             Transform::from_xyz(
-                ux as f32 * TILE_SIZE,
+                ux as f32 * tile_size(),
                 tile_to_world_y(terrain.height, uy),
                 z,
             ),
@@ -97,11 +153,48 @@ fn ensure_sprite(commands: &mut Commands, terrain: &mut Terrain, x: i32, y: i32)
         ));
         e
     } else {
-        spawn_tile(commands, terrain, ux, uy)
+        spawn_tile(commands, terrain, atlas, ux, uy)
     };
     terrain.sprite_entities[idx] = Some(entity);
 }
 
+/* ===========================================================
+   brightness fade – tiles ease toward brightness() instead of popping
+   =========================================================== */
+/// tiles whose `display_brightness` hasn't reached its target yet
+#[derive(Resource, Default)]
+pub struct FadingTiles(pub HashSet<(usize, usize)>);
+
+/// runs before `redraw_changed_tiles_system`: picks up freshly changed
+/// tiles, steps every fading tile and re‑queues it for redraw.  Tiles leave
+/// the set once within `BRIGHTNESS_EPSILON`, so the queue doesn't churn.
+pub fn brightness_fade_system(
+    time: Res<Time>,
+    lighting: Res<LightingConfig>,
+    mut terrain: ResMut<Terrain>,
+    mut fading: ResMut<FadingTiles>,
+) {
+    let full_bright = lighting.full_bright;
+    for &(x, y) in terrain.changed_tiles.iter() {
+        let tile = &terrain.tiles[y][x];
+        if (tile.display_brightness - brightness(tile, full_bright)).abs() > BRIGHTNESS_EPSILON {
+            fading.0.insert((x, y));
+        }
+    }
+
+    let step = BRIGHTNESS_FADE_SPEED * time.delta_secs();
+    fading.0.retain(|&(x, y)| {
+        let tile   = &mut terrain.tiles[y][x];
+        let target = brightness(tile, full_bright);
+        let diff   = target - tile.display_brightness;
+        let done   = diff.abs() <= step.max(BRIGHTNESS_EPSILON);
+
+        tile.display_brightness = if done { target } else { tile.display_brightness + step * diff.signum() };
+        terrain.changed_tiles.push((x, y));
+        !done
+    });
+}
+
 /* ===========================================================
    chunk helpers (groups of CHUNK_WIDTH × CHUNK_HEIGHT tiles)
    =========================================================== */
@@ -109,6 +202,7 @@ fn ensure_sprite(commands: &mut Commands, terrain: &mut Terrain, x: i32, y: i32)
    fn ensure_chunk(
        commands: &mut Commands,
        terrain:  &mut Terrain,
+       atlas:    &TileAtlas,
        cx: i32,
        cy: i32,
    ) {
@@ -119,7 +213,7 @@ fn ensure_sprite(commands: &mut Commands, terrain: &mut Terrain, x: i32, y: i32)
    
        for y in min_y..=max_y {
            for x in min_x..=max_x {
-               ensure_sprite(commands, terrain, x, y);
+               ensure_sprite(commands, terrain, atlas, x, y);
            }
        }
    }
@@ -150,42 +244,48 @@ fn ensure_sprite(commands: &mut Commands, terrain: &mut Terrain, x: i32, y: i32)
    }
 
 /* ===========================================================
-   stream_tiles_system – stripe differencing + pooling
+   chunk rectangle streamed last frame (for differencing)
+   =========================================================== */
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ChunkRect { pub min_cx: i32, pub max_cx: i32, pub min_cy: i32, pub max_cy: i32 }
+
+/// `None` until the first fill – re‑inserted by world generation so a
+/// fresh world always streams in from scratch
+#[derive(Resource, Default)]
+pub struct StreamedRect(pub Option<ChunkRect>);
+
+/* ===========================================================
+   stream_tiles_system – stripe differencing + pooling
    =========================================================== */
 pub fn stream_tiles_system(
     mut commands: Commands,
     mut terrain: ResMut<Terrain>,
     loaded: Res<LoadedWindow>,
+    mut streamed: ResMut<StreamedRect>,
+    atlas: Res<TileAtlas>,
 ) {
     /* -----------------------------------------------------------
    chunk‑level differencing
     ----------------------------------------------------------- */
     let new_min_cx = loaded.origin_cx;
-    let new_max_cx = loaded.origin_cx + LOADED_CHUNK_COLS - 1;
+    let new_max_cx = loaded.origin_cx + loaded.cols - 1;
     let new_min_cy = loaded.origin_cy;
-    let new_max_cy = loaded.origin_cy + LOADED_CHUNK_ROWS - 1;
-
-    #[derive(Copy, Clone, PartialEq)]
-    struct ChunkRect { min_cx: i32, max_cx: i32, min_cy: i32, max_cy: i32 }
-    static mut PREV: Option<ChunkRect> = None;
+    let new_max_cy = loaded.origin_cy + loaded.rows - 1;
 
     let new_rect = ChunkRect { min_cx: new_min_cx, max_cx: new_max_cx,
                             min_cy: new_min_cy, max_cy: new_max_cy };
 
-    let prev = unsafe { PREV };
-
-    if prev.is_none() {
+    let Some(prev) = streamed.0 else {
         // first frame: fill everything
         for cy in new_min_cy..=new_max_cy {
             for cx in new_min_cx..=new_max_cx {
-                ensure_chunk(&mut commands, &mut terrain, cx, cy);
+                ensure_chunk(&mut commands, &mut terrain, &atlas, cx, cy);
             }
         }
-        unsafe { PREV = Some(new_rect) };
+        streamed.0 = Some(new_rect);
         return;
-    }
+    };
 
-    let prev = prev.unwrap();
     if prev == new_rect {
         return;     // camera still inside same chunk window
     }
@@ -194,14 +294,14 @@ pub fn stream_tiles_system(
     for cx in new_min_cx..=new_max_cx {
         if cx < prev.min_cx || cx > prev.max_cx {
             for cy in new_min_cy..=new_max_cy {
-                ensure_chunk(&mut commands, &mut terrain, cx, cy);
+                ensure_chunk(&mut commands, &mut terrain, &atlas, cx, cy);
             }
         }
     }
     for cy in new_min_cy..=new_max_cy {
         if cy < prev.min_cy || cy > prev.max_cy {
             for cx in new_min_cx..=new_max_cx {
-                ensure_chunk(&mut commands, &mut terrain, cx, cy);
+                ensure_chunk(&mut commands, &mut terrain, &atlas, cx, cy);
             }
         }
     }
@@ -224,27 +324,47 @@ pub fn stream_tiles_system(
         }
     }
 
-    unsafe { PREV = Some(new_rect) };
+    streamed.0 = Some(new_rect);
 }
 
 /* ===========================================================
    shift_loaded_window_system
-   – keeps a 4×3 chunk window centred on the player and
+   – keeps a viewport‑sized chunk window centred on the camera and
      moves it whenever they step into an edge chunk
    =========================================================== */
 pub fn shift_loaded_window_system(
-    cam_q: Query<&Transform, With<Camera>>,
+    cam_q: Query<(&Transform, &OrthographicProjection), With<Camera>>,
+    window_q: Query<&Window>,
     terrain: Res<Terrain>,
     mut window_res: Option<ResMut<LoadedWindow>>,
     mut commands: Commands,
 ) {
-    let cam_tf = match cam_q.get_single() {
+    let (cam_tf, proj) = match cam_q.get_single() {
         Ok(t) => t,
         Err(_) => return,
     };
 
+    // chunks needed to cover the viewport plus one on every side; read
+    // every frame, so resizes and free‑cam zoom are picked up directly
+    let (world_cols, world_rows) = (
+        terrain.width  as i32 / CHUNK_WIDTH  as i32,
+        terrain.height as i32 / CHUNK_HEIGHT as i32,
+    );
+    let (cols, rows) = match window_q.get_single() {
+        Ok(window) => {
+            let span = |px: f32, chunk: usize| {
+                (px * proj.scale / (chunk as f32 * tile_size())).ceil() as i32 + 2
+            };
+            (
+                span(window.width(),  CHUNK_WIDTH ).max(LOADED_CHUNK_COLS).min(world_cols),
+                span(window.height(), CHUNK_HEIGHT).max(LOADED_CHUNK_ROWS).min(world_rows),
+            )
+        }
+        Err(_) => (LOADED_CHUNK_COLS, LOADED_CHUNK_ROWS),
+    };
+
     // Player position in chunk space
-    let px = (cam_tf.translation.x / TILE_SIZE).round() as i32;
+    let px = (cam_tf.translation.x / tile_size()).round() as i32;
     let py = world_to_tile_y(terrain.height, cam_tf.translation.y);
     let player_cx = px / CHUNK_WIDTH as i32;
     let player_cy = py / CHUNK_HEIGHT as i32;
@@ -257,19 +377,23 @@ pub fn shift_loaded_window_system(
             // is guaranteed to be inside it even if they crossed multiple chunks
             // in one frame (e.g. during fast falls or dashes).
  
-            let max_cx = (terrain.width as i32 / CHUNK_WIDTH as i32) - LOADED_CHUNK_COLS;
-            let max_cy = (terrain.height as i32 / CHUNK_HEIGHT as i32) - LOADED_CHUNK_ROWS;
+            let max_cx = world_cols - cols;
+            let max_cy = world_rows - rows;
  
             let new_origin_cx = player_cx
-                .saturating_sub(LOADED_CHUNK_COLS / 2)
+                .saturating_sub(cols / 2)
                 .clamp(0, max_cx);
             let new_origin_cy = player_cy
-                .saturating_sub(LOADED_CHUNK_ROWS / 2)
+                .saturating_sub(rows / 2)
                 .clamp(0, max_cy);
  
-            if new_origin_cx != win.origin_cx || new_origin_cy != win.origin_cy {
+            if new_origin_cx != win.origin_cx || new_origin_cy != win.origin_cy
+                || cols != win.cols || rows != win.rows
+            {
                 win.origin_cx = new_origin_cx;
                 win.origin_cy = new_origin_cy;
+                win.cols      = cols;
+                win.rows      = rows;
                 moved = true;
             }
 
@@ -280,9 +404,9 @@ pub fn shift_loaded_window_system(
         }
         None => {
             // first run – centre the window on the player's chunk
-            let origin_cx = player_cx - LOADED_CHUNK_COLS / 2;
-            let origin_cy = player_cy - LOADED_CHUNK_ROWS / 2;
-            commands.insert_resource(LoadedWindow { origin_cx, origin_cy });
+            let origin_cx = player_cx - cols / 2;
+            let origin_cy = player_cy - rows / 2;
+            commands.insert_resource(LoadedWindow { origin_cx, origin_cy, cols, rows });
         }
     }
 }
@@ -291,24 +415,25 @@ pub fn shift_loaded_window_system(
    update_active_rect_system
    =========================================================== */
 pub fn update_active_rect_system(
-    cam_q: Query<&Transform, With<Camera>>,
+    cam_q: Query<(&Transform, &OrthographicProjection), With<Camera>>,
     window_q: Query<&Window>,
     terrain: Res<Terrain>,
     mut rect_res: Option<ResMut<ActiveRect>>,
     mut commands: Commands,
 ) {
-    let cam_tf = match cam_q.get_single() {
+    let (cam_tf, proj) = match cam_q.get_single() {
         Ok(t) => t,
         Err(_) => return,
     };
     let window = window_q.single();
 
+    // the free camera can zoom out, so cover the scaled view
     let pad_x =
-        ((window.width() * 0.5) / TILE_SIZE).ceil() as i32 + ACTIVE_MARGIN;
+        ((window.width() * 0.5 * proj.scale) / tile_size()).ceil() as i32 + ACTIVE_MARGIN;
     let pad_y =
-        ((window.height() * 0.5) / TILE_SIZE).ceil() as i32 + ACTIVE_MARGIN;
+        ((window.height() * 0.5 * proj.scale) / tile_size()).ceil() as i32 + ACTIVE_MARGIN;
 
-    let px = (cam_tf.translation.x / TILE_SIZE).round() as i32;
+    let px = (cam_tf.translation.x / tile_size()).round() as i32;
     let py = world_to_tile_y(terrain.height, cam_tf.translation.y);
 
     let new = ActiveRect {
@@ -325,112 +450,124 @@ pub fn update_active_rect_system(
     }
 }
 
+/* ===========================================================
+   redraw timing – shown by the F3 overlay
+   =========================================================== */
+/// cost of the last non‑empty redraw batch, for chasing FOV / blast spikes
+#[derive(Resource, Default, Clone, Copy)]
+pub struct RedrawStats {
+    pub micros: u128,
+    pub tiles:  usize,
+    pub chunks: usize,
+}
+
 /* ===========================================================
    redraw_changed_tiles_system
    =========================================================== */
 pub fn redraw_changed_tiles_system(
     mut commands: Commands,
     mut terrain: ResMut<Terrain>,
+    loaded: Option<Res<LoadedWindow>>,
+    mut stats: ResMut<RedrawStats>,
+    atlas: Res<TileAtlas>,
 ) {
-    use crate::constants::{
-        COLOR_NOISE_SCALE, COLOR_VARIATION_LEVELS, COLOR_VARIATION_STRENGTH,
-    };
+    if terrain.changed_tiles.is_empty() {
+        return;
+    }
+    let started = std::time::Instant::now();
 
     let mut spawns:  Vec<(Sprite, Transform, TileSprite)> =
         Vec::new();
     let mut inserts: Vec<(Entity, (Visibility, Sprite, Transform, TileSprite))> =
         Vec::new();
 
-    // drain the entire queue once to reduce the number of atomic/pointer operations
-    let changed: Vec<(usize, usize)> = terrain.changed_tiles.drain(..).collect();
-    for (x, y) in changed {
-        let idx_sprite = terrain.idx(x, y);
-        let kind       = terrain.tiles[y][x].kind;
-
-        /* SKY → just hide / recycle */
-        if kind == TileKind::Sky {
-            if let Some(e) = terrain.sprite_entities[idx_sprite] {
-                commands.entity(e).insert(Visibility::Hidden);
-                terrain.free_sprites.push(e);
-                terrain.sprite_entities[idx_sprite] = None;
+    // one batch per dirty chunk (each tile queued once however often it
+    // was pushed): walking a chunk at a time keeps the tile rows and
+    // sprite slots we touch close together.  The queue is lent out for
+    // the walk and handed back emptied, buckets and all
+    let mut changed = std::mem::take(&mut terrain.changed_tiles);
+    let mut tiles   = 0;
+    for batch in changed.batches() {
+        /* window check once per chunk instead of per tile */
+        let in_window = loaded.as_ref().is_none_or(|l| l.contains_tile(batch[0].0, batch[0].1));
+        tiles += batch.len();
+        for &(x, y) in batch {
+            let idx_sprite = terrain.idx(x, y);
+            let kind       = terrain.tiles[y][x].kind;
+
+            /* SKY → just hide / recycle (unless a wall stands behind it) */
+            if kind == TileKind::Sky && terrain.tiles[y][x].wall.is_none() {
+                if let Some(e) = terrain.sprite_entities[idx_sprite] {
+                    commands.entity(e).insert(Visibility::Hidden);
+                    terrain.free_sprites.push(e);
+                    terrain.sprite_entities[idx_sprite] = None;
+                }
+                continue;
             }
-            continue;
-        }
 
-        /* re‑tint --------------------------------------------------------- */
-        let raw = terrain.color_noise.get([
-            x as f64 * COLOR_NOISE_SCALE,
-            y as f64 * COLOR_NOISE_SCALE,
-        ]) as f32;
-
-        let step = (((raw + 1.0) * 0.5) * COLOR_VARIATION_LEVELS as f32)
-            .floor()
-            .clamp(0.0, (COLOR_VARIATION_LEVELS - 1) as f32);
-        let norm   = step / (COLOR_VARIATION_LEVELS as f32 - 1.0) * 2.0 - 1.0;
-        let factor = 1.0 + norm * COLOR_VARIATION_STRENGTH;
-
-        terrain.tiles[y][x].base_rgb = match kind {
-            TileKind::Grass    => Vec3::new(0.13, 0.70, 0.08) * factor,
-            TileKind::Snow     => Vec3::new(0.95, 0.95, 0.95) * factor,
-            TileKind::Dirt     => Vec3::new(0.55, 0.27, 0.07) * factor,
-            TileKind::Stone    => Vec3::new(0.50, 0.50, 0.50) * factor,
-            TileKind::Obsidian => Vec3::new(0.20, 0.05, 0.35) * factor,
-            TileKind::Air      => Vec3::new(0.20, 0.10, 0.05) * factor,
-            _                  => terrain.tiles[y][x].base_rgb,
-        };
+            /* re‑tint (lava keeps its pulse colour) ---------------------------- */
+            let wall = terrain.tiles[y][x].wall;
+            if let (Some(wall), TileKind::Air | TileKind::Sky) = (wall, kind) {
+                terrain.tiles[y][x].base_rgb = wall.rgb();
+            } else if kind != TileKind::Lava {
+                terrain.tiles[y][x].base_rgb = tile_base_rgb(
+                    kind, x, y, terrain.height, &terrain.color_noise, &terrain.temp_noise,
+                );
+            }
 
-        /* colour & depth -------------------------------------------------- */
-        let (color, z) = color_and_z(&terrain, x, y);
-        let tile_sprite = TileSprite { x, y };
-
-        match terrain.sprite_entities[idx_sprite] {
-            Some(entity) => {
-                let transform = Transform {
-                    translation: Vec3::new(
-                        x as f32 * TILE_SIZE,
-                        tile_to_world_y(terrain.height, y),
-                        z,
-                    ),
-                    ..default()
-                };
-                let sprite = Sprite {
-                    color,
-                    custom_size: Some(Vec2::splat(TILE_SIZE)),
-                    ..default()
-                };
-                inserts.push((
-                    entity,
-                    (Visibility::Visible, sprite, transform, tile_sprite),
-                ));
+            /* outside the streamed window without a sprite: leave it to
+               ensure_sprite, which re‑applies brightness when it scrolls in */
+            if terrain.sprite_entities[idx_sprite].is_none() && !in_window {
+                continue;
             }
-            None => {
-                let transform = Transform {
-                    translation: Vec3::new(
-                        x as f32 * TILE_SIZE,
-                        tile_to_world_y(terrain.height, y),
-                        z,
-                    ),
-                    ..default()
-                };
-                let sprite = Sprite {
-                    color,
-                    custom_size: Some(Vec2::splat(TILE_SIZE)),
-                    ..default()
-                };
-
-                if let Some(entity) = terrain.free_sprites.pop() {
+
+            /* sprite & depth -------------------------------------------------- */
+            let (sprite, z) = sprite_and_z(&terrain, &atlas, x, y);
+            let tile_sprite = TileSprite { x, y };
+
+            match terrain.sprite_entities[idx_sprite] {
+                Some(entity) => {
+                    let transform = Transform {
+                        translation: Vec3::new(
+                            x as f32 * tile_size(),
+                            tile_to_world_y(terrain.height, y),
+                            z,
+                        ),
+                        ..default()
+                    };
                     inserts.push((
                         entity,
                         (Visibility::Visible, sprite, transform, tile_sprite),
                     ));
-                    terrain.sprite_entities[idx_sprite] = Some(entity);
-                } else {
-                    spawns.push((sprite, transform, tile_sprite));
+                }
+                None => {
+                    let transform = Transform {
+                        translation: Vec3::new(
+                            x as f32 * tile_size(),
+                            tile_to_world_y(terrain.height, y),
+                            z,
+                        ),
+                        ..default()
+                    };
+
+                    if let Some(entity) = terrain.free_sprites.pop() {
+                        inserts.push((
+                            entity,
+                            (Visibility::Visible, sprite, transform, tile_sprite),
+                        ));
+                        terrain.sprite_entities[idx_sprite] = Some(entity);
+                    } else {
+                        spawns.push((sprite, transform, tile_sprite));
+                    }
                 }
             }
         }
     }
 
+    let chunks = changed.batches().count();
+    changed.clear();
+    terrain.changed_tiles = changed;
+
     /* flush command buffers ---------------------------------------------- */
     if !spawns.is_empty() {
         commands.spawn_batch(spawns);
@@ -438,6 +575,12 @@ pub fn redraw_changed_tiles_system(
     if !inserts.is_empty() {
         commands.insert_or_spawn_batch(inserts);
     }
+
+    *stats = RedrawStats {
+        micros: started.elapsed().as_micros(),
+        tiles,
+        chunks,
+    };
 }
 
 /* ===========================================================
@@ -446,19 +589,16 @@ pub fn redraw_changed_tiles_system(
 pub fn spawn_tile(
     commands: &mut Commands,
     terrain: &Terrain,
+    atlas: &TileAtlas,
     x: usize,
     y: usize,
 ) -> Entity {
-    let (color, z) = color_and_z(terrain, x, y);
+    let (sprite, z) = sprite_and_z(terrain, atlas, x, y);
     commands
         .spawn((
-            Sprite {
-                color,
-                custom_size: Some(Vec2::splat(TILE_SIZE)),
-                ..default()
-            },
+            sprite,
             Transform::from_xyz(
-                x as f32 * TILE_SIZE,
+                x as f32 * tile_size(),
                 tile_to_world_y(terrain.height, y),
                 z,
             ),
@@ -468,68 +608,92 @@ pub fn spawn_tile(
 }
 
 /* ===========================================================
-   digging_system (mouse circular dig)
+   solid collision check
    =========================================================== */
-pub fn digging_system(
-    mouse: Res<ButtonInput<MouseButton>>,
-    windows: Query<&Window>,
-    cam_q: Query<(&Camera, &GlobalTransform)>,
-    mut terrain: ResMut<Terrain>,
-) {
-    if !mouse.pressed(MouseButton::Left) {
-        return;
-    }
-    let window = windows.single();
-    let Some(cursor) = window.cursor_position() else {
-        return;
-    };
-    let (cam, cam_tf) = cam_q.single();
-    let Ok(world) = cam.viewport_to_world_2d(cam_tf, cursor) else {
-        return;
-    };
+/// the one list of tile kinds that block movement (and get a sprite)
+#[inline]
+pub fn solid_kind(kind: TileKind) -> bool {
+    matches!(
+        kind,
+        TileKind::Grass
+            | TileKind::Dirt
+            | TileKind::Stone
+            | TileKind::Obsidian
+            | TileKind::Snow
+            | TileKind::Glass
+            | TileKind::Booster
+    )
+}
 
-    let min_x = ((world.x - DIG_RADIUS) / TILE_SIZE).floor() as i32;
-    let max_x = ((world.x + DIG_RADIUS) / TILE_SIZE).ceil() as i32;
+/// what the pickaxe can break: every solid kind plus the placed
+/// non‑solid ones (ladders, platforms, portals)
+#[inline]
+pub fn minable_kind(kind: TileKind) -> bool {
+    solid_kind(kind) || matches!(kind, TileKind::Ladder | TileKind::Platform | TileKind::Portal)
+}
 
-    let min_y_world = world.y - DIG_RADIUS;
-    let max_y_world = world.y + DIG_RADIUS;
-    let min_y = world_to_tile_y(terrain.height, max_y_world);
-    let max_y = world_to_tile_y(terrain.height, min_y_world);
+/// tile kinds that block light – every solid kind except glass
+#[inline]
+pub fn opaque_kind(kind: TileKind) -> bool {
+    solid_kind(kind) && kind != TileKind::Glass
+}
 
-    for ty in min_y..=max_y {
-        for tx in min_x..=max_x {
-            if tx < 0
-                || ty < 0
-                || tx >= terrain.width as i32
-                || ty >= terrain.height as i32
-            {
-                continue;
-            }
-            let dx = tx as f32 * TILE_SIZE - world.x;
-            let dy = tile_to_world_y(terrain.height, ty as usize) - world.y;
-            if dx * dx + dy * dy < DIG_RADIUS * DIG_RADIUS {
-                let (ux, uy) = (tx as usize, ty as usize);
-                if matches!(
-                    terrain.tiles[uy][ux].kind,
-                    TileKind::Grass
-                        | TileKind::Dirt
-                        | TileKind::Stone
-                        | TileKind::Obsidian
-                        | TileKind::Snow
-                ) {
-                    terrain.tiles[uy][ux].kind = TileKind::Air;
-                    terrain.changed_tiles.push_back((ux, uy));
-                }
-            }
-        }
+/// tile kinds that are liquid – never solid; projectiles and particles
+/// pass through them but are slowed / splash
+#[inline]
+pub fn is_liquid(kind: TileKind) -> bool {
+    matches!(kind, TileKind::Lava)
+}
+
+/// liquid check on the grid (out‑of‑bounds is not liquid)
+#[inline]
+pub fn liquid_at(terrain: &Terrain, tx: i32, ty: i32) -> bool {
+    tx >= 0 && ty >= 0
+        && tx < terrain.width as i32 && ty < terrain.height as i32
+        && is_liquid(terrain.tiles[ty as usize][tx as usize].kind)
+}
+
+/// out‑of‑bounds counts as solid
+#[inline]
+pub fn solid(terrain: &Terrain, tx: i32, ty: i32) -> bool {
+    if tx < 0
+        || ty < 0
+        || tx >= terrain.width as i32
+        || ty >= terrain.height as i32
+    {
+        return true;
     }
+    solid_kind(terrain.tiles[ty as usize][tx as usize].kind)
 }
 
 /* ===========================================================
-   solid collision check
+   layer‑aware collision – what the movement sweeps use
    =========================================================== */
+/// movers a tile kind can block; one‑way kinds also need the right
+/// `Approach` (see `solid_for`)
 #[inline]
-pub fn solid(terrain: &Terrain, tx: i32, ty: i32) -> bool {
+pub fn tile_layers(kind: TileKind) -> CollisionLayers {
+    match kind {
+        TileKind::Platform      => CollisionLayers::PLAYER,
+        k if solid_kind(k)      => CollisionLayers::ALL,
+        _                       => CollisionLayers::NONE,
+    }
+}
+
+/// how a body meets the tile it is probing
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Approach {
+    /// horizontal sweep or step‑up check
+    Side,
+    Rising,
+    /// `feet` is the body's bottom edge before the step; `drop_through`
+    /// lets it fall past one‑way tiles (S held)
+    Falling { feet: f32, drop_through: bool },
+}
+
+/// `solid` for a body on `layers`: out‑of‑bounds still blocks everyone,
+/// a one‑way platform only blocks a body falling onto its top face
+pub fn solid_for(terrain: &Terrain, tx: i32, ty: i32, layers: CollisionLayers, approach: Approach) -> bool {
     if tx < 0
         || ty < 0
         || tx >= terrain.width as i32
@@ -537,14 +701,120 @@ pub fn solid(terrain: &Terrain, tx: i32, ty: i32) -> bool {
     {
         return true;
     }
-    matches!(
-        terrain.tiles[ty as usize][tx as usize].kind,
-        TileKind::Grass
-            | TileKind::Dirt
-            | TileKind::Stone
-            | TileKind::Obsidian
-            | TileKind::Snow
-    )
+    let kind = terrain.tiles[ty as usize][tx as usize].kind;
+    if !tile_layers(kind).overlaps(layers) {
+        return false;
+    }
+    if kind != TileKind::Platform {
+        return true;
+    }
+    let top = (terrain.height as i32 - ty) as f32 * tile_size();
+    matches!(approach, Approach::Falling { feet, drop_through: false } if feet >= top - 0.1)
+}
+
+/// `true` if no solid tile lies on the segment `a → b` (world space)
+pub fn line_of_sight(terrain: &Terrain, a: Vec2, b: Vec2) -> bool {
+    let delta = b - a;
+    raycast_tiles(terrain, a, delta, delta.length()).is_none()
+}
+
+/* ===========================================================
+   tile raycast – grid DDA, visits every tile the ray touches
+   =========================================================== */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    /// world‑space point where the ray entered the tile
+    pub pos:    Vec2,
+    /// grid coords of the blocking tile (may lie outside the grid)
+    pub tile:   IVec2,
+    /// world distance from the origin to `pos`
+    pub dist:   f32,
+    /// face that was hit, in world space (y up); zero if the ray
+    /// started inside the blocking tile
+    pub normal: Vec2,
+}
+
+/// first `solid` tile along `dir` from `origin` within `max_dist`
+/// (world units); `None` if the ray stays clear
+#[inline]
+pub fn raycast_tiles(terrain: &Terrain, origin: Vec2, dir: Vec2, max_dist: f32) -> Option<RayHit> {
+    raycast_tiles_with(terrain, origin, dir, max_dist, solid)
+}
+
+/// same as `raycast_tiles` but `blocks(terrain, tx, ty)` decides what
+/// stops the ray – e.g. `liquid_at` for a splash test, or a check that
+/// lets glass through.  A ray that leaves the grid without being
+/// blocked returns `None`.
+pub fn raycast_tiles_with(
+    terrain: &Terrain,
+    origin: Vec2,
+    dir: Vec2,
+    max_dist: f32,
+    blocks: impl Fn(&Terrain, i32, i32) -> bool,
+) -> Option<RayHit> {
+    let ts = tile_size();
+    let h = terrain.height as i32;
+    let in_grid = |tx: i32, ty: i32| tx >= 0 && ty >= 0 && tx < terrain.width as i32 && ty < h;
+
+    // work in tile units with y up; `row` counts from the bottom
+    let p = origin / ts;
+    let mut tx = p.x.floor() as i32;
+    let mut row = p.y.floor() as i32;
+
+    if blocks(terrain, tx, h - 1 - row) {
+        return Some(RayHit { pos: origin, tile: IVec2::new(tx, h - 1 - row), dist: 0.0, normal: Vec2::ZERO });
+    }
+    let started_inside = in_grid(tx, h - 1 - row);
+    let dir = dir.normalize_or_zero();
+    if dir == Vec2::ZERO {
+        return None;
+    }
+
+    let step_x = if dir.x > 0.0 { 1 } else { -1 };
+    let step_y = if dir.y > 0.0 { 1 } else { -1 };
+    let delta_x = if dir.x != 0.0 { 1.0 / dir.x.abs() } else { f32::INFINITY };
+    let delta_y = if dir.y != 0.0 { 1.0 / dir.y.abs() } else { f32::INFINITY };
+    let mut next_x = if dir.x > 0.0 {
+        (tx as f32 + 1.0 - p.x) * delta_x
+    } else if dir.x < 0.0 {
+        (p.x - tx as f32) * delta_x
+    } else {
+        f32::INFINITY
+    };
+    let mut next_y = if dir.y > 0.0 {
+        (row as f32 + 1.0 - p.y) * delta_y
+    } else if dir.y < 0.0 {
+        (p.y - row as f32) * delta_y
+    } else {
+        f32::INFINITY
+    };
+    let max_t = max_dist / ts;
+
+    loop {
+        let (t, normal) = if next_x < next_y {
+            let t = next_x;
+            tx += step_x;
+            next_x += delta_x;
+            (t, Vec2::new(-step_x as f32, 0.0))
+        } else {
+            let t = next_y;
+            row += step_y;
+            next_y += delta_y;
+            (t, Vec2::new(0.0, -step_y as f32))
+        };
+        if t > max_t {
+            return None;
+        }
+        let ty = h - 1 - row;
+        if blocks(terrain, tx, ty) {
+            return Some(RayHit { pos: origin + dir * t * ts, tile: IVec2::new(tx, ty), dist: t * ts, normal });
+        }
+        // the grid is convex – a ray that started inside and left it
+        // never comes back
+        if started_inside && !in_grid(tx, ty) {
+            return None;
+        }
+    }
 }
 
 /* ===========================================================
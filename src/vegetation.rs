@@ -0,0 +1,119 @@
+//! vegetation – grass spreads back over dug‑out dirt
+//!
+//! Every `GRASS_TICK` seconds a bounded random sample of tiles inside the
+//! streamed window is checked (never the whole map): dirt with open air
+//! above and grass beside it turns to grass, grass buried under a solid
+//! tile dies back to dirt.  Exposed grass now and then sprouts a small
+//! decorative plant on top; plants wither with their grass and are dropped
+//! once they leave the streamed window.
+
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::components::Plant;
+use crate::constants::*;
+use crate::tile_stream::LoadedWindow;
+use crate::world_gen::{base_mine_time, tile_to_world_y, Terrain, TileKind};
+
+/// nothing blocks the tile – what grass needs above it
+#[inline]
+fn open(terrain: &Terrain, x: usize, y: usize) -> bool {
+    matches!(terrain.tiles[y][x].kind, TileKind::Air | TileKind::Sky)
+}
+
+/// exposed grass on a side neighbour, one row up, level or down (slopes)
+fn grass_beside(terrain: &Terrain, x: usize, y: usize) -> bool {
+    [-1i32, 1].iter().any(|&dx| {
+        (-1i32..=1).any(|dy| {
+            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+            nx >= 0 && ny >= 1
+                && nx < terrain.width as i32 && ny < terrain.height as i32
+                && terrain.tiles[ny as usize][nx as usize].kind == TileKind::Grass
+                && open(terrain, nx as usize, ny as usize - 1)
+        })
+    })
+}
+
+/* ===========================================================
+   slow growth tick
+   =========================================================== */
+pub fn grass_growth_system(
+    time: Res<Time>,
+    mut since_last: Local<f32>,
+    loaded: Option<Res<LoadedWindow>>,
+    mut terrain: ResMut<Terrain>,
+    mut commands: Commands,
+    mut plants: Query<(Entity, &Plant, &mut Sprite)>,
+) {
+    *since_last += time.delta_secs();
+    if *since_last < GRASS_TICK {
+        return;
+    }
+    *since_last = 0.0;
+    let Some(loaded) = loaded else { return };
+
+    let (w, h) = (terrain.width as i32, terrain.height as i32);
+    let min_x  = (loaded.origin_cx * CHUNK_WIDTH as i32).clamp(0, w - 1) as usize;
+    let max_x  = ((loaded.origin_cx + loaded.cols) * CHUNK_WIDTH as i32 - 1).clamp(0, w - 1) as usize;
+    let min_y  = (loaded.origin_cy * CHUNK_HEIGHT as i32).clamp(1, h - 1) as usize;
+    let max_y  = ((loaded.origin_cy + loaded.rows) * CHUNK_HEIGHT as i32 - 1).clamp(1, h - 1) as usize;
+
+    /* ---- plants: wither with their grass, leave with the window ---- */
+    let mut occupied = HashSet::new();
+    for (e, plant, mut sprite) in &mut plants {
+        let (x, y) = (plant.x, plant.y);
+        let rooted = terrain.tiles[y][x].kind == TileKind::Grass && open(&terrain, x, y - 1);
+        if !rooted || !loaded.contains_tile(x, y) {
+            commands.entity(e).despawn();
+            continue;
+        }
+        // follow the lighting of the tile it grows on
+        let b = terrain.tiles[y][x].display_brightness;
+        sprite.color = Color::srgb(PLANT_RGB.x * b, PLANT_RGB.y * b, PLANT_RGB.z * b);
+        occupied.insert((x, y));
+    }
+
+    /* ---- bounded random sample of the window ---- */
+    let mut rng = rand::thread_rng();
+    for _ in 0..GRASS_SAMPLES {
+        let x = rng.gen_range(min_x..=max_x);
+        let y = rng.gen_range(min_y..=max_y);
+
+        let kind = match terrain.tiles[y][x].kind {
+            TileKind::Dirt if open(&terrain, x, y - 1) && grass_beside(&terrain, x, y)
+                && rng.gen::<f32>() < GRASS_SPREAD_CHANCE => TileKind::Grass,
+            TileKind::Grass if !open(&terrain, x, y - 1) => TileKind::Dirt,
+            TileKind::Grass => {
+                if occupied.len() < PLANT_MAX_ALIVE
+                    && !occupied.contains(&(x, y))
+                    && rng.gen::<f32>() < PLANT_SPROUT_CHANCE
+                {
+                    let b = terrain.tiles[y][x].display_brightness;
+                    let size = Vec2::new(PLANT_SIZE.x, rng.gen_range(PLANT_SIZE.x..=PLANT_SIZE.y));
+                    commands.spawn((
+                        Sprite {
+                            color: Color::srgb(PLANT_RGB.x * b, PLANT_RGB.y * b, PLANT_RGB.z * b),
+                            custom_size: Some(size),
+                            ..default()
+                        },
+                        Transform::from_xyz(
+                            x as f32 * tile_size(),
+                            tile_to_world_y(terrain.height, y) + (tile_size() + size.y) * 0.5,
+                            0.5,
+                        ),
+                        Plant { x, y },
+                    ));
+                    occupied.insert((x, y));
+                }
+                continue;
+            }
+            _ => continue,
+        };
+
+        let tile = &mut terrain.tiles[y][x];
+        tile.kind      = kind;
+        tile.mine_time = base_mine_time(kind);
+        terrain.touch(x, y);
+    }
+}
@@ -0,0 +1,86 @@
+//! depth vignette – the screen edges close in as the player goes down
+//!
+//! One screen‑sized sprite with a baked gradient (clear in the middle,
+//! black toward the corners) rides along with the camera at `VIGNETTE_Z`:
+//! above tiles and bodies, below the coloured glows and the UI.  Its alpha
+//! follows the player's depth below the surface – nothing at the surface,
+//! `VIGNETTE_MAX_ALPHA` from `VIGNETTE_FULL_DEPTH_FRAC` of the map down
+//! (the obsidian layer) – and eases there so cave mouths don't flicker.
+//! The clear centre keeps the FOV‑lit area around the player readable.
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::components::Player;
+use crate::constants::*;
+use crate::world_gen::{world_to_tile_y, Terrain};
+
+#[derive(Component)]
+pub struct Vignette;
+
+/* ===========================================================
+   startup: bake the gradient, spawn the (transparent) sprite
+   =========================================================== */
+pub fn setup_vignette(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let size = VIGNETTE_TEXTURE_SIZE;
+    let half = size as f32 * 0.5;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            // 0 in the middle, 1 at the edge midpoints, √2 in the corners
+            let d = Vec2::new(x as f32 + 0.5 - half, y as f32 + 0.5 - half).length() / half;
+            let t = ((d - VIGNETTE_CLEAR_RADIUS) / (1.0 - VIGNETTE_CLEAR_RADIUS)).clamp(0.0, 1.0);
+            let a = t * t * (3.0 - 2.0 * t);
+            data.extend_from_slice(&[0, 0, 0, (a * 255.0) as u8]);
+        }
+    }
+    let image = Image::new(
+        Extent3d { width: size, height: size, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    commands.spawn((
+        Sprite {
+            image: images.add(image),
+            color: Color::srgba(1.0, 1.0, 1.0, 0.0),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, VIGNETTE_Z),
+        Vignette,
+    ));
+}
+
+/* ===========================================================
+   follow the camera, cover the (zoomed) view, fade with depth
+   =========================================================== */
+pub fn depth_vignette_system(
+    time: Res<Time>,
+    terrain: Res<Terrain>,
+    window_q: Query<&Window>,
+    cam_q: Query<(&Transform, &OrthographicProjection), (With<Camera>, Without<Vignette>)>,
+    player_q: Query<&Transform, (With<Player>, Without<Vignette>)>,
+    mut q: Query<(&mut Transform, &mut Sprite), With<Vignette>>,
+) {
+    let Ok((mut tf, mut sprite)) = q.get_single_mut() else { return };
+    let (Ok((cam_tf, proj)), Ok(window)) = (cam_q.get_single(), window_q.get_single()) else {
+        return;
+    };
+    tf.translation.x   = cam_tf.translation.x;
+    tf.translation.y   = cam_tf.translation.y;
+    sprite.custom_size = Some(Vec2::new(window.width(), window.height()) * proj.scale);
+
+    let Ok(player_tf) = player_q.get_single() else { return };
+    let tx = ((player_tf.translation.x / tile_size()).floor() as i32)
+        .clamp(0, terrain.width as i32 - 1) as usize;
+    let ty    = world_to_tile_y(terrain.height, player_tf.translation.y);
+    let depth = (ty - terrain.height_map[tx] as i32).max(0) as f32;
+    let full  = terrain.height as f32 * VIGNETTE_FULL_DEPTH_FRAC;
+    let target = (depth / full).clamp(0.0, 1.0) * VIGNETTE_MAX_ALPHA;
+
+    let alpha = sprite.color.alpha();
+    let step  = VIGNETTE_FADE_SPEED * time.delta_secs();
+    sprite.color.set_alpha(alpha + (target - alpha).clamp(-step, step));
+}
@@ -1,14 +1,17 @@
 //! field‑of‑view & lighting (shadow‑casting) – radius‑bounded version
+//!
+//! `[` / `]` shrink / grow the FOV radius, F10 toggles full bright (every
+//! loaded tile lit, no shadow‑casting) for screenshots and checking
+//! generation.
 
+use bevy::input::ButtonInput;
 use bevy::prelude::*;
 use std::collections::HashSet;
 
 use crate::components::Player;
-use crate::constants::{TILE_SIZE,
-    CHUNK_WIDTH,  CHUNK_HEIGHT,
-    LOADED_CHUNK_COLS, LOADED_CHUNK_ROWS};
-use crate::world_gen::{world_to_tile_y, Terrain, TileKind};
-use crate::tile_stream::LoadedWindow;
+use crate::constants::{CHUNK_WIDTH, CHUNK_HEIGHT};
+use crate::world_gen::{world_to_cell, Terrain};
+use crate::tile_stream::{opaque_kind, LoadedWindow};
 
 /* ===========================================================
    Player‑tile resource
@@ -34,6 +37,51 @@ pub struct VisibleTiles {
 pub const FOV_RADIUS: i32 = 48;            // ← was 32
 pub const LIGHT_BLEED_RADIUS: i32 = 2;
 pub const ALWAYS_VISIBLE_DEPTH: usize = 4;
+/// tiles lit around every lava tile in view, shadow‑cast like the FOV
+pub const LAVA_LIGHT_RADIUS: i32 = 4;
+/// runtime radius limits and step per `[` / `]` press
+pub const FOV_RADIUS_MIN:  i32 = 8;
+pub const FOV_RADIUS_MAX:  i32 = 96;
+pub const FOV_RADIUS_STEP: i32 = 8;
+
+/* ===========================================================
+   lighting config – adjustable at runtime
+   =========================================================== */
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct LightingConfig {
+    pub fov_radius:  i32,
+    /// skip shadow‑casting and light every loaded tile
+    pub full_bright: bool,
+}
+
+impl Default for LightingConfig {
+    fn default() -> Self {
+        Self { fov_radius: FOV_RADIUS, full_bright: false }
+    }
+}
+
+/// `[` / `]` radius, F10 full bright; only writes on a press so the
+/// change tick (which triggers the FOV recompute) stays quiet otherwise
+pub fn lighting_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut cfg: ResMut<LightingConfig>,
+) {
+    if keys.just_pressed(KeyCode::F10) {
+        cfg.full_bright = !cfg.full_bright;
+        info!("full bright {}", if cfg.full_bright { "on" } else { "off" });
+    }
+    let step = if keys.just_pressed(KeyCode::BracketRight) {
+        FOV_RADIUS_STEP
+    } else if keys.just_pressed(KeyCode::BracketLeft) {
+        -FOV_RADIUS_STEP
+    } else {
+        0
+    };
+    if step != 0 {
+        cfg.fov_radius = (cfg.fov_radius + step).clamp(FOV_RADIUS_MIN, FOV_RADIUS_MAX);
+        info!("FOV radius {}", cfg.fov_radius);
+    }
+}
 
 /* ===========================================================
    startup
@@ -44,8 +92,7 @@ pub fn startup_fov_system(
     terrain: Res<Terrain>,
 ) {
     let tf = player_q.single();
-    let tx = (tf.translation.x / TILE_SIZE).floor() as i32;
-    let ty = world_to_tile_y(terrain.height, tf.translation.y);
+    let (tx, ty) = world_to_cell(terrain.height, tf.translation.truncate());
 
     commands.insert_resource(PlayerTile { x: tx, y: ty });
     commands.insert_resource(VisibleTiles::default());
@@ -61,8 +108,7 @@ pub fn detect_player_tile_change_system(
 ) {
     let Ok(tf) = player_q.get_single() else { return };
 
-    let nx = (tf.translation.x / TILE_SIZE).floor() as i32;
-    let ny = world_to_tile_y(terrain.height, tf.translation.y);
+    let (nx, ny) = world_to_cell(terrain.height, tf.translation.truncate());
 
     if player_tile.x == nx && player_tile.y == ny {
         return;
@@ -73,17 +119,23 @@ pub fn detect_player_tile_change_system(
 }
 
 /* ===========================================================
-   recompute FOV — runs only when `PlayerTile` changed
-   (optimised: all work is limited to the streamed chunk window)
+   recompute FOV — runs only when `PlayerTile` or the lighting
+   config changed (optimised: all work is limited to the streamed
+   chunk window)
    =========================================================== */
    pub fn recompute_fov_system(
     mut terrain:   ResMut<Terrain>,
     player_tile:   Res<PlayerTile>,
     loaded:        Res<LoadedWindow>,
+    cfg:           Res<LightingConfig>,
     mut vis:       ResMut<VisibleTiles>,
 ) {
-    // Early‑out if the player is still on the same tile
-    if !player_tile.is_changed() {
+    // Early‑out if the player is still on the same tile; full bright also
+    // has to follow the window so freshly streamed chunks light up
+    if !(player_tile.is_changed()
+        || cfg.is_changed()
+        || (cfg.full_bright && loaded.is_changed()))
+    {
         return;
     }
 
@@ -92,10 +144,10 @@ pub fn detect_player_tile_change_system(
 
     /* ---------- bounds of the current streamed chunk window ---------- */
     let min_x = (loaded.origin_cx * CHUNK_WIDTH  as i32).clamp(0, world_w - 1);
-    let max_x = ((loaded.origin_cx + LOADED_CHUNK_COLS - 1) * CHUNK_WIDTH  as i32
+    let max_x = ((loaded.origin_cx + loaded.cols - 1) * CHUNK_WIDTH  as i32
                 + CHUNK_WIDTH  as i32 - 1).clamp(0, world_w - 1);
     let min_y = (loaded.origin_cy * CHUNK_HEIGHT as i32).clamp(0, world_h - 1);
-    let max_y = ((loaded.origin_cy + LOADED_CHUNK_ROWS - 1) * CHUNK_HEIGHT as i32
+    let max_y = ((loaded.origin_cy + loaded.rows - 1) * CHUNK_HEIGHT as i32
                 + CHUNK_HEIGHT as i32 - 1).clamp(0, world_h - 1);
 
     /* ---------- fresh visible set ---------- */
@@ -106,21 +158,30 @@ pub fn detect_player_tile_change_system(
         ( 1,  0,  0,  1), ( 0,  1,  1,  0), ( 0, -1,  1,  0), (-1,  0,  0,  1),
         (-1,  0,  0, -1), ( 0, -1, -1,  0), ( 0,  1, -1,  0), ( 1,  0,  0, -1),
     ];
-    for &(xx, xy, yx, yy) in &OCT {
-        cast_light(
-            &terrain,
-            px,
-            py,
-            1,
-            1.0,
-            0.0,
-            FOV_RADIUS,
-            xx,
-            xy,
-            yx,
-            yy,
-            &mut new_visible,
-        );
+    if cfg.full_bright {
+        // the whole window, no shadow‑casting
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                new_visible.insert((x as usize, y as usize));
+            }
+        }
+    } else {
+        for &(xx, xy, yx, yy) in &OCT {
+            cast_light(
+                &terrain,
+                px,
+                py,
+                1,
+                1.0,
+                0.0,
+                cfg.fov_radius,
+                xx,
+                xy,
+                yx,
+                yy,
+                &mut new_visible,
+            );
+        }
     }
 
     /* Always include the player’s own tile */
@@ -161,16 +222,36 @@ pub fn detect_player_tile_change_system(
         }
     }
 
+    /* ---------- lava glow: seen lava lights what it can see ---------- */
+    let mut glow = HashSet::new();
+    for (x, y) in terrain.lava_in((min_x as usize, max_x as usize), (min_y as usize, max_y as usize)) {
+        if !new_visible.contains(&(x, y)) {
+            continue;
+        }
+        for &(xx, xy, yx, yy) in &OCT {
+            cast_light(
+                &terrain, x as i32, y as i32, 1, 1.0, 0.0, LAVA_LIGHT_RADIUS,
+                xx, xy, yx, yy, &mut glow,
+            );
+        }
+    }
+    new_visible.extend(glow.into_iter().filter(|&(ux, uy)| {
+        let (x, y) = (ux as i32, uy as i32);
+        x >= min_x && x <= max_x && y >= min_y && y <= max_y
+    }));
+
     /* ---------- diff old ↔ new sets ---------- */
     for &(ux, uy) in vis.set.difference(&new_visible) {
-        terrain.tiles[uy][ux].visible = false;
-        terrain.changed_tiles.push_back((ux, uy));
+        let tile = &mut terrain.tiles[uy][ux];
+        tile.visible  = false;
+        tile.explored = true;   // once seen, always dimmed – never black
+        terrain.changed_tiles.push((ux, uy));
     }
     for &(ux, uy) in new_visible.difference(&vis.set) {
         let tile = &mut terrain.tiles[uy][ux];
         tile.visible  = true;
         tile.explored = true;
-        terrain.changed_tiles.push_back((ux, uy));
+        terrain.changed_tiles.push((ux, uy));
     }
 
     /* ---------- store + recycle ---------- */
@@ -228,10 +309,7 @@ fn cast_light(
                     out.insert((tx as usize, ty as usize));
                 }
 
-                let opaque = matches!(
-                    terrain.tiles[ty as usize][tx as usize].kind,
-                    TileKind::Dirt | TileKind::Stone | TileKind::Obsidian | TileKind::Grass | TileKind::Snow
-                );
+                let opaque = opaque_kind(terrain.tiles[ty as usize][tx as usize].kind);
 
                 if blocked {
                     if opaque {
@@ -0,0 +1,82 @@
+//! background walls – a second layer behind the foreground tile
+//!
+//! Hold the wall slot (0): LMB / LT puts a wall behind the aimed open tile
+//! (stone walls first, then dirt), RMB hammers it back out into the pack.
+//! A wall is never solid; it only changes what is drawn behind air / sky,
+//! keeps that backdrop dimly lit outside the FOV (`WALL_MIN_BRIGHTNESS`)
+//! and stops reinforcement orcs from dropping in on top of it.  Ladders,
+//! lava and solid tiles still draw over it – one sprite per tile.
+
+use bevy::input::gamepad::Gamepad;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::components::{HeldItem, Inventory, ItemKind, Player};
+use crate::constants::*;
+use crate::gamepad::{cursor_to_tile, pad_just_pressed, GamepadAim, PAD_USE};
+use crate::world_gen::{Terrain, TileKind, WallKind};
+
+/// backpack item a wall kind is placed from / hammered back into
+#[inline]
+fn wall_item(kind: WallKind) -> ItemKind {
+    match kind {
+        WallKind::Dirt  => ItemKind::DirtWall,
+        WallKind::Stone => ItemKind::StoneWall,
+    }
+}
+
+/// does a wall stand behind tile (x, y)?  (out of bounds = no)
+#[inline]
+pub fn has_wall(terrain: &Terrain, tx: i32, ty: i32) -> bool {
+    tx >= 0 && ty >= 0
+        && tx < terrain.width as i32 && ty < terrain.height as i32
+        && terrain.tiles[ty as usize][tx as usize].wall.is_some()
+}
+
+/* ===========================================================
+   place (LMB / LT) and hammer out (RMB) with the wall slot held
+   =========================================================== */
+pub fn wall_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    pads: Query<&Gamepad>,
+    aim: Res<GamepadAim>,
+    mut terrain: ResMut<Terrain>,
+    mut q: Query<(&Transform, &mut Inventory), With<Player>>,
+) {
+    let Ok((tf, mut inv)) = q.get_single_mut() else { return };
+    if inv.selected != HeldItem::Wall {
+        return;
+    }
+    let place  = mouse.pressed(MouseButton::Left) || pad_just_pressed(&pads, PAD_USE);
+    let hammer = mouse.pressed(MouseButton::Right);
+    if !(place || hammer) {
+        return;
+    }
+    let Some(world) = aim.point else { return };
+    if (world - tf.translation.truncate()).length_squared() > tiles(DIG_RADIUS).powi(2) {
+        return;
+    }
+
+    let Some((ux, uy)) = cursor_to_tile(&aim, &terrain) else { return };
+    let tile     = terrain.tiles[uy][ux];
+
+    if hammer {
+        let Some(kind) = tile.wall else { return };
+        inv.add(wall_item(kind), 1);
+        terrain.tiles[uy][ux].wall = None;
+    } else {
+        // walls go up behind open space only; solid tiles would hide them
+        if tile.wall.is_some() || !matches!(tile.kind, TileKind::Air | TileKind::Sky) {
+            return;
+        }
+        let Some(kind) = [WallKind::Stone, WallKind::Dirt]
+            .into_iter()
+            .find(|&k| inv.count(wall_item(k)) > 0)
+        else {
+            return;
+        };
+        inv.take(wall_item(kind), 1);
+        terrain.tiles[uy][ux].wall = Some(kind);
+    }
+    terrain.changed_tiles.push((ux, uy));
+}
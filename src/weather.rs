@@ -0,0 +1,219 @@
+//! weather – snow and storms over the mountain caps
+//!
+//! `Weather` cycles clear → snow → storm at random intervals.  Flakes only
+//! spawn inside the camera view, in sky tiles above a snow‑topped column, and
+//! melt away as soon as they enter anything that isn't sky – so they never
+//! fall into caves.  While it snows, a few exposed surface notches on the
+//! snow caps fill in with a snow tile; filling only where a neighbour at that
+//! row is solid keeps the layer thin instead of stacking towers.
+//!
+//! `Wind` drifts toward a new random target every few seconds (harder in
+//! a storm).  Light things – flakes, debris, blood, exhaust – are pulled
+//! toward its speed through `Wind::push`, all with the same rule so they
+//! drift together; bodies and bullets ignore it.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::{Snowflake, Velocity};
+use crate::constants::*;
+use crate::tile_stream::solid;
+use crate::world_gen::{tile_to_world_y, world_to_tile_y, Terrain, TileKind};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum WeatherKind {
+    #[default]
+    Clear,
+    Snow,
+    Storm,
+}
+
+#[derive(Resource)]
+pub struct Weather {
+    pub kind: WeatherKind,
+    /// seconds until the next change
+    pub left: f32,
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self { kind: WeatherKind::Clear, left: WEATHER_SPELL.start }
+    }
+}
+
+impl Weather {
+    /// (flakes / s, settled tiles / s)
+    fn intensity(&self) -> (f32, f32) {
+        match self.kind {
+            WeatherKind::Clear => (0.0, 0.0),
+            WeatherKind::Snow  => (SNOW_FLAKES_PER_SEC, SNOW_SETTLE_RATE),
+            WeatherKind::Storm => (STORM_FLAKES_PER_SEC, STORM_SETTLE_RATE),
+        }
+    }
+}
+
+/// the air's sideways speed, shared by every ambient particle
+#[derive(Resource)]
+pub struct Wind {
+    pub velocity: Vec2,
+    /// calm‑weather target, before the storm multiplier
+    target: f32,
+    /// seconds until a new target is rolled
+    left:   f32,
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self { velocity: Vec2::ZERO, target: 0.0, left: 0.0 }
+    }
+}
+
+impl Wind {
+    /// pull a light particle's velocity toward the wind; `coupling` (1 / s)
+    /// is how light it is
+    #[inline]
+    pub fn push(&self, vel: &mut Vec2, coupling: f32, dt: f32) {
+        vel.x += (self.velocity.x - vel.x) * (coupling * dt).min(1.0);
+    }
+}
+
+/* ===========================================================
+   wind – ease toward a target that changes every few seconds
+   =========================================================== */
+pub fn wind_system(time: Res<Time>, weather: Res<Weather>, mut wind: ResMut<Wind>) {
+    let dt = time.delta_secs();
+    wind.left -= dt;
+    if wind.left <= 0.0 {
+        let mut rng = rand::thread_rng();
+        wind.target = rng.gen_range(-WIND_MAX..=WIND_MAX);
+        wind.left   = rng.gen_range(WIND_GUST_INTERVAL);
+    }
+    let target = if weather.kind == WeatherKind::Storm { wind.target * WIND_STORM_MULT } else { wind.target };
+    let step = WIND_CHANGE_RATE * dt;
+    wind.velocity.x += (target - wind.velocity.x).clamp(-step, step);
+}
+
+/// row of the first non‑sky tile in column `x`, if it is snow
+fn snow_cap(terrain: &Terrain, x: usize) -> Option<usize> {
+    let y = (0..terrain.height).find(|&y| terrain.tiles[y][x].kind != TileKind::Sky)?;
+    (terrain.tiles[y][x].kind == TileKind::Snow).then_some(y)
+}
+
+#[inline]
+fn sky_at(terrain: &Terrain, pos: Vec2) -> bool {
+    let tx = (pos.x / tile_size()).round() as i32;
+    let ty = world_to_tile_y(terrain.height, pos.y);
+    tx >= 0 && ty >= 0 && tx < terrain.width as i32 && ty < terrain.height as i32
+        && terrain.tiles[ty as usize][tx as usize].kind == TileKind::Sky
+}
+
+/* ===========================================================
+   cycle, spawn / move flakes, settle snow
+   =========================================================== */
+#[allow(clippy::too_many_arguments)]
+pub fn weather_system(
+    time: Res<Time>,
+    mut weather: ResMut<Weather>,
+    wind: Res<Wind>,
+    mut spawn_acc: Local<f32>,
+    mut settle_acc: Local<f32>,
+    mut terrain: ResMut<Terrain>,
+    mut commands: Commands,
+    cam_q: Query<(&Transform, &OrthographicProjection), (With<Camera>, Without<Snowflake>)>,
+    window_q: Query<&Window>,
+    mut flakes: Query<(Entity, &mut Transform, &mut Velocity), With<Snowflake>>,
+) {
+    let dt = time.delta_secs();
+    let mut rng = rand::thread_rng();
+
+    weather.left -= dt;
+    if weather.left <= 0.0 {
+        weather.kind = match rng.gen_range(0..3) {
+            0 => WeatherKind::Clear,
+            1 => WeatherKind::Snow,
+            _ => WeatherKind::Storm,
+        };
+        weather.left = rng.gen_range(WEATHER_SPELL);
+        info!("weather: {:?}", weather.kind);
+    }
+    let (flake_rate, settle_rate) = weather.intensity();
+
+    /* ---- move; melt on anything but sky ---- */
+    let mut live = 0;
+    for (e, mut tf, mut vel) in &mut flakes {
+        wind.push(&mut vel.0, WIND_SNOW_COUPLING, dt);
+        tf.translation += (vel.0 * dt).extend(0.0);
+        if sky_at(&terrain, tf.translation.truncate()) {
+            live += 1;
+        } else {
+            commands.entity(e).despawn();
+        }
+    }
+
+    /* ---- visible world rect ---- */
+    let (Ok((cam_tf, proj)), Ok(window)) = (cam_q.get_single(), window_q.get_single()) else {
+        return;
+    };
+    let half = Vec2::new(window.width(), window.height()) * 0.5 * proj.scale;
+    let view_min = cam_tf.translation.truncate() - half;
+    let view_max = cam_tf.translation.truncate() + half;
+    let col_min = ((view_min.x / tile_size()).floor() as i32).max(0) as usize;
+    let col_max = ((view_max.x / tile_size()).ceil() as i32).min(terrain.width as i32 - 1);
+    if col_max < col_min as i32 {
+        return;
+    }
+    let col_max = col_max as usize;
+
+    /* ---- spawn along the top edge, over snow caps only ---- */
+    *spawn_acc += flake_rate * dt;
+    while *spawn_acc >= 1.0 {
+        *spawn_acc -= 1.0;
+        if live >= SNOW_MAX_FLAKES {
+            continue;
+        }
+        let pos = Vec2::new(rng.gen_range(view_min.x..view_max.x), view_max.y);
+        let x   = (pos.x / tile_size()).round() as i32;
+        if x < 0 || x >= terrain.width as i32 || !sky_at(&terrain, pos)
+            || snow_cap(&terrain, x as usize).is_none()
+        {
+            continue;
+        }
+        let vel = Vec2::new(
+            wind.velocity.x + rng.gen_range(-10.0..10.0),
+            -SNOW_FALL_SPEED * rng.gen_range(0.7..1.3),
+        );
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(1.0, 1.0, 1.0, 0.85),
+                custom_size: Some(Vec2::splat(SNOW_FLAKE_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(pos.extend(15.0)),
+            Velocity(vel),
+            Snowflake,
+        ));
+        live += 1;
+    }
+
+    /* ---- settle: fill a notch on a visible snow cap ---- */
+    *settle_acc += settle_rate * dt;
+    while *settle_acc >= 1.0 {
+        *settle_acc -= 1.0;
+        let x = rng.gen_range(col_min..=col_max);
+        let Some(cap) = snow_cap(&terrain, x) else { continue };
+        if cap == 0 {
+            continue;
+        }
+        let y = cap - 1;
+        let (xi, yi) = (x as i32, y as i32);
+        if !(solid(&terrain, xi - 1, yi) || solid(&terrain, xi + 1, yi)) {
+            continue; // open on both sides – would start a pillar
+        }
+        if tile_to_world_y(terrain.height, y) < view_min.y {
+            continue; // below the view
+        }
+        terrain.tiles[y][x].kind      = TileKind::Snow;
+        terrain.tiles[y][x].mine_time = 0.15;
+        terrain.touch(x, y);
+    }
+}
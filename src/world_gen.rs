@@ -2,36 +2,57 @@
 //!
 //! All procedural‑generation code plus the shared data structures
 //! that represent the world live here.  Run‑time streaming and
-//! digging logic has been moved to `tile_stream.rs`.
+//! collision live in `tile_stream.rs`; digging is in `player.rs`.
 //!
 //! Compatible with **Bevy 0.15**
 
 use bevy::math::Mat2;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use noise::{NoiseFn, Perlin};
 use rand::Rng;
-use std::collections::VecDeque;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 
 use crate::components::*;
 use crate::constants::*;
+use crate::tile_stream::{solid_kind, StreamedRect};
 
 /* ===========================================================
    helpers (row‑0 = top)
    =========================================================== */
 #[inline]
 pub fn tile_to_world_y(terrain_h: usize, tile_y: usize) -> f32 {
-    (terrain_h as f32 - 1. - tile_y as f32) * TILE_SIZE
+    (terrain_h as f32 - 1. - tile_y as f32) * tile_size()
 }
 
 #[inline]
 pub fn world_to_tile_y(terrain_h: usize, world_y: f32) -> i32 {
-    (terrain_h as f32 - 1. - (world_y / TILE_SIZE).floor()) as i32
+    (terrain_h as f32 - 1. - (world_y / tile_size()).floor()) as i32
+}
+
+/// tile whose *sprite* covers world point `p` – sprites are centred on
+/// their grid point, so each owns `[k − ½, k + ½)` tiles on both axes.
+/// Use this for anything picked with the cursor; may be out of bounds
+/// (negative left of / above the map).
+#[inline]
+pub fn world_to_tile(terrain_h: usize, p: Vec2) -> (i32, i32) {
+    let col = (p.x / tile_size() + 0.5).floor() as i32;
+    let row = (p.y / tile_size() + 0.5).floor() as i32;
+    (col, terrain_h as i32 - 1 - row)
+}
+
+/// grid cell at world point `p` by the physics convention (floor on both
+/// axes, as in the movement sweeps); may be out of bounds
+#[inline]
+pub fn world_to_cell(terrain_h: usize, p: Vec2) -> (i32, i32) {
+    ((p.x / tile_size()).floor() as i32, world_to_tile_y(terrain_h, p.y))
 }
 
 /* ===========================================================
    tile data
    =========================================================== */
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum TileKind {
     Air,
     Sky,
@@ -40,15 +61,94 @@ pub enum TileKind {
     Stone,
     Obsidian,
     Snow,
+    Lava,    // non‑solid hazard, only below OBSIDIAN_START_FRAC
+    Ladder,  // player‑placed, climbable, non‑solid & transparent
+    Glass,   // player‑placed, solid but see‑through (FOV ignores it)
+    Booster, // player‑placed, solid; pushes what stands on it (see booster.rs)
+    Platform, // player‑placed, one‑way: the player lands on it, jumps up through it
+    Portal,   // player‑placed, non‑solid; linked in pairs (see portal.rs)
+}
+
+impl TileKind {
+    /// every kind, in declaration order (checked by the test at the end
+    /// of this file)
+    pub const ALL: &'static [TileKind] = &[
+        TileKind::Air,
+        TileKind::Sky,
+        TileKind::Grass,
+        TileKind::Dirt,
+        TileKind::Stone,
+        TileKind::Obsidian,
+        TileKind::Snow,
+        TileKind::Lava,
+        TileKind::Ladder,
+        TileKind::Glass,
+        TileKind::Booster,
+        TileKind::Platform,
+        TileKind::Portal,
+    ];
+
+    /// pickaxe tier needed to break the tile (see `PickaxeTier::max_hardness`)
+    #[inline]
+    pub fn hardness(self) -> u8 {
+        match self {
+            TileKind::Stone    => 1,
+            TileKind::Obsidian => 2,
+            _                  => 0,
+        }
+    }
+}
+
+/// background wall behind a tile (see walls.rs) – never solid
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WallKind {
+    Dirt,
+    Stone,
+}
+
+impl WallKind {
+    #[inline]
+    pub fn rgb(self) -> Vec3 {
+        match self {
+            WallKind::Dirt  => Vec3::new(0.32, 0.18, 0.08),
+            WallKind::Stone => Vec3::new(0.30, 0.30, 0.32),
+        }
+    }
+}
+
+/// cosmetic stain blended into a solid tile's tint – lives on the grid,
+/// so it survives streaming, and goes when the tile changes kind
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Decal {
+    /// blast and portal burns
+    Scorch,
+    /// where an orc fell
+    Blood,
+}
+
+impl Decal {
+    /// colour and how far the tile tint is pulled toward it
+    #[inline]
+    pub fn blend(self) -> (Vec3, f32) {
+        match self {
+            Decal::Scorch => (DECAL_SCORCH_RGB, DECAL_SCORCH_AMOUNT),
+            Decal::Blood  => (DECAL_BLOOD_RGB,  DECAL_BLOOD_AMOUNT),
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
 pub struct Tile {
     pub kind:      TileKind,
+    /// player‑placed backdrop, drawn where the tile itself is open
+    pub wall:      Option<WallKind>,
     pub visible:   bool,
     pub explored:  bool,
     pub mine_time: f32,
     pub base_rgb:  Vec3,
+    /// brightness currently on screen; eases toward `brightness()`
+    pub display_brightness: f32,
+    pub decal:     Option<Decal>,
 }
 
 /* ===========================================================
@@ -58,12 +158,114 @@ pub struct Tile {
 pub struct Terrain {
     pub tiles:           Vec<Vec<Tile>>,
     pub sprite_entities: Vec<Option<Entity>>,
-    pub changed_tiles:   VecDeque<(usize, usize)>,
+    pub changed_tiles:   ChangedTiles,
+    /// tiles whose kind changed since `support_system` last ran – only
+    /// these can take support away (FOV and fades don't land here)
+    pub kind_changed:    Vec<(usize, usize)>,
     pub free_sprites:    Vec<Entity>,          // pool managed by tile_stream
     pub width:           usize,
     pub height:          usize,
     pub height_map:      Vec<usize>,
     pub color_noise:     Perlin,
+    /// low‑frequency temperature wobble (see `tile_base_rgb`)
+    pub temp_noise:      Perlin,
+    /// air tiles resting on a cavern floor where crates spawn (see crates.rs)
+    pub crate_spots:     Vec<(usize, usize)>,
+    /// deep cavern floor tiles that get an orc portal (see spawner.rs)
+    pub spawner_spots:   Vec<(usize, usize)>,
+    /// what the generator managed to place (see world_stats.rs)
+    pub gen_counts:      GenCounts,
+    /// every lava tile, keyed `(y, x)` so a row span is one range
+    /// (kept current by `touch`; see `lava_in`)
+    pub lava:            BTreeSet<(usize, usize)>,
+}
+
+/// tiles waiting for `redraw_changed_tiles_system`, bucketed by chunk.
+/// Every chunk has a dirty flag and every tile a queued bit, so a tile
+/// pushed many times in a frame (FOV, the fade, a tile and its AO
+/// neighbours) is stored once, `is_empty` is a length check and the
+/// redraw walks one dirty chunk at a time without sorting.  `clear`
+/// empties the buckets in place, so a steady stream of edits settles
+/// into no allocations at all
+#[derive(Default)]
+pub struct ChangedTiles {
+    width:    usize,
+    chunks_x: usize,
+    /// per tile, one bit: already in its chunk's bucket
+    queued:   Vec<u64>,
+    /// per chunk: has anything queued
+    dirty:    Vec<bool>,
+    buckets:  Vec<Vec<(usize, usize)>>,
+    /// dirty chunks in the order they were first pushed to
+    order:    Vec<usize>,
+}
+
+impl ChangedTiles {
+    pub fn new(width: usize, height: usize) -> Self {
+        let chunks_x = width.div_ceil(CHUNK_WIDTH);
+        let chunks   = chunks_x * height.div_ceil(CHUNK_HEIGHT);
+        Self {
+            width,
+            chunks_x,
+            queued:  vec![0; (width * height).div_ceil(64)],
+            dirty:   vec![false; chunks],
+            buckets: vec![Vec::new(); chunks],
+            order:   Vec::new(),
+        }
+    }
+
+    /// queue (x, y) for redraw; a no‑op if it already is
+    #[inline]
+    pub fn push(&mut self, (x, y): (usize, usize)) {
+        let i   = y * self.width + x;
+        let bit = 1 << (i % 64);
+        if self.queued[i / 64] & bit != 0 {
+            return;
+        }
+        self.queued[i / 64] |= bit;
+        let c = (y / CHUNK_HEIGHT) * self.chunks_x + x / CHUNK_WIDTH;
+        if !self.dirty[c] {
+            self.dirty[c] = true;
+            self.order.push(c);
+        }
+        self.buckets[c].push((x, y));
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// every queued tile, chunk by chunk
+    pub fn iter(&self) -> impl Iterator<Item = &(usize, usize)> + '_ {
+        self.batches().flatten()
+    }
+
+    /// the queued tiles as one batch per dirty chunk
+    pub fn batches(&self) -> impl Iterator<Item = &[(usize, usize)]> + '_ {
+        self.order.iter().map(|&c| self.buckets[c].as_slice())
+    }
+
+    /// empty the queue, keeping every bucket's capacity
+    pub fn clear(&mut self) {
+        for c in self.order.drain(..) {
+            self.dirty[c] = false;
+            for &(x, y) in &self.buckets[c] {
+                let i = y * self.width + x;
+                self.queued[i / 64] &= !(1 << (i % 64));
+            }
+            self.buckets[c].clear();
+        }
+    }
+}
+
+/// feature counts reported by `generate_terrain`
+#[derive(Clone, Copy, Default, Debug)]
+pub struct GenCounts {
+    pub mountains:  usize,
+    pub islands:    usize,
+    /// tiles opened up by the underground cavern walkers
+    pub cave_tiles: usize,
 }
 
 impl Terrain {
@@ -71,6 +273,132 @@ impl Terrain {
     pub fn idx(&self, x: usize, y: usize) -> usize {
         y * self.width + x
     }
+
+    /// queue a tile whose kind changed, plus its 8 neighbours – their
+    /// ambient occlusion depends on it.  Any decal goes with the old kind.
+    /// The tile is also handed to the support check (`kind_changed`).
+    pub fn touch(&mut self, x: usize, y: usize) {
+        self.tiles[y][x].decal = None;
+        self.kind_changed.push((x, y));
+        if self.tiles[y][x].kind == TileKind::Lava {
+            self.lava.insert((y, x));
+        } else {
+            self.lava.remove(&(y, x));
+        }
+        for dy in -1..=1_i32 {
+            for dx in -1..=1_i32 {
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                if nx >= 0 && ny >= 0 && (nx as usize) < self.width && (ny as usize) < self.height {
+                    self.changed_tiles.push((nx as usize, ny as usize));
+                }
+            }
+        }
+    }
+
+    /// lava tiles `(x, y)` inside the given inclusive rectangle
+    pub fn lava_in(
+        &self,
+        (min_x, max_x): (usize, usize),
+        (min_y, max_y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (min_y..=max_y).flat_map(move |y| {
+            self.lava.range((y, min_x)..=(y, max_x)).map(|&(y, x)| (x, y))
+        })
+    }
+
+    /// dig (x, y) out to air – the one path for mining, carving and
+    /// anything else that removes a tile
+    pub fn clear(&mut self, x: usize, y: usize) {
+        let tile = &mut self.tiles[y][x];
+        tile.kind      = TileKind::Air;
+        tile.mine_time = 0.0;
+        self.touch(x, y);
+    }
+
+    /// stain the solid tile at (x, y); off the map or open space is ignored,
+    /// and only an actual change is queued for redraw
+    pub fn paint_decal(&mut self, x: i32, y: i32, decal: Decal) {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return;
+        }
+        let (ux, uy) = (x as usize, y as usize);
+        let tile = &mut self.tiles[uy][ux];
+        if !solid_kind(tile.kind) || tile.decal == Some(decal) {
+            return;
+        }
+        tile.decal = Some(decal);
+        self.changed_tiles.push((ux, uy));
+    }
+
+    /// `paint_decal` on every tile within `radius` tiles of (x, y)
+    pub fn splat_decal(&mut self, x: i32, y: i32, radius: f32, decal: Decal) {
+        let r = radius.ceil() as i32;
+        for dy in -r..=r {
+            for dx in -r..=r {
+                if ((dx * dx + dy * dy) as f32) <= radius * radius {
+                    self.paint_decal(x + dx, y + dy, decal);
+                }
+            }
+        }
+    }
+}
+
+/* ===========================================================
+   TerrainQuery – read‑only access with the bounds checks built in.
+   Out of range reads as "nothing there": `None` / `false`.  Note
+   that `tile_stream::solid` does the opposite (the world edge is a
+   wall for collision); use that one for movement sweeps.
+   Derefs to `Terrain` for everything else.
+   =========================================================== */
+#[derive(SystemParam)]
+pub struct TerrainQuery<'w> {
+    terrain: Res<'w, Terrain>,
+}
+
+impl TerrainQuery<'_> {
+    /// the tile at grid `(x, y)`, if it is on the map
+    #[inline]
+    pub fn tile(&self, (x, y): (i32, i32)) -> Option<&Tile> {
+        (x >= 0 && y >= 0 && (x as usize) < self.terrain.width && (y as usize) < self.terrain.height)
+            .then(|| &self.terrain.tiles[y as usize][x as usize])
+    }
+
+    #[inline]
+    pub fn kind_at(&self, tile: (i32, i32)) -> Option<TileKind> {
+        self.tile(tile).map(|t| t.kind)
+    }
+
+    #[inline]
+    pub fn is_solid(&self, tile: (i32, i32)) -> bool {
+        self.kind_at(tile).is_some_and(solid_kind)
+    }
+
+    /// on‑map tile whose sprite covers `world` (see `world_to_tile`)
+    #[inline]
+    pub fn world_to_tile(&self, world: Vec2) -> Option<(usize, usize)> {
+        let (x, y) = world_to_tile(self.terrain.height, world);
+        self.tile((x, y)).map(|_| (x as usize, y as usize))
+    }
+
+    /// grid cell at `world` (see `world_to_cell`); may be off the map
+    #[inline]
+    pub fn world_to_cell(&self, world: Vec2) -> (i32, i32) {
+        world_to_cell(self.terrain.height, world)
+    }
+
+    /// centre of tile `(x, y)` in world space
+    #[inline]
+    pub fn tile_to_world(&self, (x, y): (usize, usize)) -> Vec2 {
+        Vec2::new(x as f32 * tile_size(), tile_to_world_y(self.terrain.height, y))
+    }
+}
+
+impl std::ops::Deref for TerrainQuery<'_> {
+    type Target = Terrain;
+
+    fn deref(&self) -> &Terrain {
+        &self.terrain
+    }
 }
 
 /* sliding active rectangle ------------------------------------------------ */
@@ -82,10 +410,6 @@ pub struct ActiveRect {
     pub max_y: i32,
 }
 
-/* rectangle from previous frame (for early‑out) --------------------------- */
-#[derive(Resource, Default)]
-pub struct LastRect(pub Option<ActiveRect>);
-
 /* ===========================================================
    generation parameters & knobs
    =========================================================== */
@@ -96,6 +420,125 @@ pub const EXPLORED_BRIGHTNESS: f32 = 0.25;
 /* tweakables ------------------------------------------------------------- */
 const OBSIDIAN_START_FRAC: f32 = 0.80;   // bottom 20 % of map is obsidian
 
+/* lava pockets inside the obsidian layer */
+const LAVA_FREQ:   f64 = 0.07;
+const LAVA_THRESH: f64 = 0.45;
+pub const LAVA_RGB: Vec3 = Vec3::new(1.00, 0.42, 0.05);
+
+/* ladders (never generated, only placed) */
+pub const LADDER_RGB: Vec3 = Vec3::new(0.70, 0.48, 0.20);
+pub const LADDER_MINE_TIME: f32 = 0.10;
+pub const PLATFORM_RGB: Vec3 = Vec3::new(0.60, 0.40, 0.18);
+pub const PLATFORM_MINE_TIME: f32 = 0.10;
+
+/* glass (never generated, only placed) */
+pub const GLASS_RGB: Vec3 = Vec3::new(0.70, 0.88, 0.95);
+pub const GLASS_MINE_TIME: f32 = 0.25;
+
+/* boosters (never generated, only placed) */
+pub const BOOSTER_RGB: Vec3 = Vec3::new(0.85, 0.55, 0.15);
+pub const BOOSTER_MINE_TIME: f32 = 0.50;
+
+/* portals (never generated, only placed) */
+pub const PORTAL_RGB: Vec3 = Vec3::new(0.55, 0.30, 0.95);
+pub const PORTAL_MINE_TIME: f32 = 0.25;
+
+/// mine time a freshly generated tile of `kind` gets (snow is stored as grass)
+pub fn base_mine_time(kind: TileKind) -> f32 {
+    match kind {
+        TileKind::Grass | TileKind::Snow => 0.10,
+        TileKind::Dirt     => 1.0,
+        TileKind::Stone    => 2.50,
+        TileKind::Obsidian => 10.00,
+        TileKind::Ladder   => LADDER_MINE_TIME,
+        TileKind::Platform => PLATFORM_MINE_TIME,
+        TileKind::Glass    => GLASS_MINE_TIME,
+        TileKind::Booster  => BOOSTER_MINE_TIME,
+        TileKind::Portal   => PORTAL_MINE_TIME,
+        TileKind::Air | TileKind::Sky | TileKind::Lava => 0.0,
+    }
+}
+
+/* ===========================================================
+   tile colour – shared by generation and the redraw re‑tint, so a
+   re‑drawn tile always gets the colour it was generated with
+   =========================================================== */
+/// −1 cold … +1 hot: cools with depth, then warms again over the last
+/// TEMP_HEAT_BAND above the lava layer; `temp_noise` wobbles the bands
+fn temperature(temp_noise: &Perlin, x: usize, y: usize, h: usize) -> f32 {
+    let d      = y as f32 / h as f32;
+    let cold   = -(d / OBSIDIAN_START_FRAC).min(1.0);
+    let heat   = ((d - OBSIDIAN_START_FRAC + TEMP_HEAT_BAND) / TEMP_HEAT_BAND)
+        .clamp(0.0, 1.0) * 2.0;
+    let wobble = temp_noise.get([x as f64 * TEMP_NOISE_SCALE, y as f64 * TEMP_NOISE_SCALE]) as f32
+        * TEMP_NOISE_AMOUNT;
+    (cold + heat + wobble).clamp(-1.0, 1.0)
+}
+
+/// palette × discrete `color_noise` step × temperature tint.  Lava is
+/// animated by lava_pulse_system; placed ladders / platforms / boosters
+/// keep the noise step but skip the temperature tint, and glass and
+/// portals are left untinted
+pub fn tile_base_rgb(
+    kind: TileKind,
+    x: usize,
+    y: usize,
+    h: usize,
+    color_noise: &Perlin,
+    temp_noise: &Perlin,
+) -> Vec3 {
+    let raw = color_noise.get([
+        x as f64 * COLOR_NOISE_SCALE,
+        y as f64 * COLOR_NOISE_SCALE,
+    ]) as f32;
+
+    let step = (((raw + 1.0) * 0.5) * COLOR_VARIATION_LEVELS as f32)
+        .floor()
+        .clamp(0.0, (COLOR_VARIATION_LEVELS - 1) as f32);
+    let norm   = step / (COLOR_VARIATION_LEVELS as f32 - 1.0) * 2.0 - 1.0;
+    let factor = 1.0 + norm * COLOR_VARIATION_STRENGTH;
+
+    let t    = temperature(temp_noise, x, y, h);
+    let tint = Vec3::ONE.lerp(if t < 0.0 { COLD_TINT } else { WARM_TINT }, t.abs());
+    let natural = factor * tint;
+
+    match kind {
+        TileKind::Grass    => Vec3::new(0.13, 0.70, 0.08) * natural,
+        TileKind::Snow     => Vec3::new(0.95, 0.95, 0.95) * natural,
+        TileKind::Dirt     => Vec3::new(0.55, 0.27, 0.07) * natural,
+        TileKind::Stone    => Vec3::new(0.50, 0.50, 0.50) * natural,
+        TileKind::Obsidian => Vec3::new(0.20, 0.05, 0.35) * natural,
+        TileKind::Air      => BACKGROUND_BROWN            * natural,
+        TileKind::Lava     => LAVA_RGB,
+        TileKind::Ladder   => LADDER_RGB  * factor,
+        TileKind::Platform => PLATFORM_RGB * factor,
+        TileKind::Glass    => GLASS_RGB,
+        TileKind::Booster  => BOOSTER_RGB * factor,
+        TileKind::Portal   => PORTAL_RGB,
+        TileKind::Sky      => Vec3::ZERO, // never drawn
+    }
+}
+
+/// generation's colour pass – the same `tile_base_rgb` the redraw uses,
+/// split into row bands across threads
+fn tint_tiles(tiles: &mut [Vec<Tile>], h: usize, color_noise: &Perlin, temp_noise: &Perlin) {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let band    = tiles.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|scope| {
+        for (i, rows) in tiles.chunks_mut(band).enumerate() {
+            scope.spawn(move || {
+                for (dy, row) in rows.iter_mut().enumerate() {
+                    let y = i * band + dy;
+                    for (x, tile) in row.iter_mut().enumerate() {
+                        tile.base_rgb = tile_base_rgb(tile.kind, x, y, h, color_noise, temp_noise);
+                    }
+                }
+            });
+        }
+    });
+}
+
 /* rift (vertical chasm) parameters */
 const RIFT_FREQ:   f64 = 0.018;
 const RIFT_THRESH: f64 = 0.75;
@@ -107,22 +550,59 @@ const STONE_TO_OBSID: f32 = 0.05;
 /* surface grass ratio */
 const GRASS_RATIO: f32 = 0.85;
 
+/* mountains either side of spawn */
+const MOUNTAINS_PER_SIDE: usize = 3;
+
 /* ===========================================================
-   generate world + player
+   generation knobs – the constants above are the defaults;
+   the options screen (options.rs) edits a copy before generation
    =========================================================== */
-pub fn generate_world_and_player(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
-) {
-    /* --- sprite sheet ---------------------------------------------------- */
-    let sheet   = asset_server.load("textures/player_sheet.png");
-    let layout  = TextureAtlasLayout::from_grid(UVec2::new(100, 100), 6, 1, None, None);
-    let layout_handle = atlas_layouts.add(layout);
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct GenParams {
+    /// multiplier on the surface hill amplitude
+    pub surface_amp:        f32,
+    /// multiplier on the number of cavern walkers
+    pub cave_density:       f32,
+    /// > 1 = rarer lava pockets & obsidian leaks, < 1 = more common
+    pub ore_rarity:         f32,
+    pub grass_ratio:        f32,
+    pub rift_thresh:        f64,
+    pub mountains_per_side: usize,
+    /// world size in chunks – read through `dims`, which clamps
+    pub chunks_x:           usize,
+    pub chunks_y:           usize,
+}
+
+impl Default for GenParams {
+    fn default() -> Self {
+        Self {
+            surface_amp:        1.0,
+            cave_density:       1.0,
+            ore_rarity:         1.0,
+            grass_ratio:        GRASS_RATIO,
+            rift_thresh:        RIFT_THRESH,
+            mountains_per_side: MOUNTAINS_PER_SIDE,
+            chunks_x:           NUM_CHUNKS_X,
+            chunks_y:           NUM_CHUNKS_Y,
+        }
+    }
+}
 
+impl GenParams {
+    /// world width / height in tiles, clamped to the supported range
+    pub fn dims(&self) -> (usize, usize) {
+        let cx = self.chunks_x.clamp(WORLD_CHUNKS_X_MIN, WORLD_CHUNKS_X_MAX);
+        let cy = self.chunks_y.clamp(WORLD_CHUNKS_Y_MIN, WORLD_CHUNKS_Y_MAX);
+        (cx * CHUNK_WIDTH, cy * CHUNK_HEIGHT)
+    }
+}
+
+/* ===========================================================
+   generate world – pure, runs on a background task (see loading.rs)
+   =========================================================== */
+pub fn generate_terrain(params: &GenParams, progress: &AtomicU32) -> Terrain {
     /* --- dimensions ------------------------------------------------------ */
-    let w = CHUNK_WIDTH * NUM_CHUNKS_X;
-    let h = CHUNK_HEIGHT * NUM_CHUNKS_Y;
+    let (w, h) = params.dims();
 
     /* --- surface height map --------------------------------------------- */
     let mut height_map = vec![0usize; w];
@@ -130,8 +610,8 @@ pub fn generate_world_and_player(
     let noise_cliffs = Perlin::new(rand::thread_rng().gen());
 
     let base     = h as f32 * 0.35;
-    let amp_low  =  5.0;
-    let amp_high = 12.0;
+    let amp_low  =  5.0 * params.surface_amp;
+    let amp_high = 12.0 * params.surface_amp;
 
     let cliff_freq     = 0.12;
     let cliff_thresh   = 0.85;
@@ -157,10 +637,13 @@ pub fn generate_world_and_player(
         vec![
             Tile {
                 kind:      TileKind::Air,
+                wall:      None,
                 visible:   false,
                 explored:  false,
                 mine_time: 0.0,
                 base_rgb:  BACKGROUND_BROWN,
+                display_brightness: 0.0,
+                decal:     None,
             };
             w
         ];
@@ -170,102 +653,111 @@ pub fn generate_world_and_player(
 
     /* noises -------------------------------------------------------------- */
     let noise_rift  = Perlin::new(rand::thread_rng().gen());
+    let noise_lava  = Perlin::new(rand::thread_rng().gen());
     let color_noise = Perlin::new(rand::thread_rng().gen());
+    let temp_noise  = Perlin::new(rand::thread_rng().gen());
 
-    let mut rng = rand::thread_rng();
+    /* rarity scales the odds: leak chance / r, lava tail (1 − thresh) / r */
+    let rarity      = params.ore_rarity.max(0.1);
+    let obsid_leak  = STONE_TO_OBSID / rarity;
+    let lava_thresh = 1.0 - (1.0 - LAVA_THRESH) / rarity as f64;
 
     /* ========== column‑wise generation ================================== */
-    for x in 0..w {
-        let surface = height_map[x];
-
-        /* sky tiles ------------------------------------------------------- */
-        for y in 0..surface {
-            tiles[y][x].kind      = TileKind::Sky;
-            tiles[y][x].mine_time = 0.0;
+    /* columns are independent: split every row into the same bands of
+       columns and give each band to its own thread */
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let band    = w.div_ceil(threads);
+    let mut bands: Vec<Vec<&mut [Tile]>> = (0..threads).map(|_| Vec::with_capacity(h)).collect();
+    for row in tiles.iter_mut() {
+        for (i, chunk) in row.chunks_mut(band).enumerate() {
+            bands[i].push(chunk);
         }
+    }
+    let cols_done = AtomicUsize::new(0);
 
-        /* pre‑compute rift value for column ------------------------------ */
-        let rift_val = noise_rift.get([x as f64 * RIFT_FREQ, 0.0]);
-
-        /* ground tiles ---------------------------------------------------- */
-        for y in surface..h {
-            let depth = y - surface;
-            let mut kind = if depth < MIN_CAVE_DEPTH {
-                if depth > h / 4 { TileKind::Stone } else { TileKind::Dirt }
-            } else {
-                if rift_val > RIFT_THRESH && depth > 3 {
-                    TileKind::Air
-                } else if y >= (h as f32 * OBSIDIAN_START_FRAC) as usize {
-                    TileKind::Obsidian
-                } else if depth > h / 4 {
-                    TileKind::Stone
-                } else {
-                    TileKind::Dirt
-                }
-            };
-
-            /* surface: mostly grass ------------------------------------ */
-            if depth == 0 {
-                kind = if rng.gen::<f32>() < GRASS_RATIO {
-                    TileKind::Grass
-                } else {
-                    TileKind::Dirt
-                };
-            } else {
-                /* probabilistic lower‑layer clusters -------------------- */
-                match kind {
-                    TileKind::Dirt if rng.gen::<f32>() < DIRT_TO_STONE =>
-                        kind = TileKind::Stone,
-                    TileKind::Stone if rng.gen::<f32>() < STONE_TO_OBSID =>
-                        kind = TileKind::Obsidian,
-                    _ => {}
-                }
+    std::thread::scope(|scope| {
+        for (i, mut rows) in bands.into_iter().enumerate() {
+            if rows.is_empty() {
+                continue;
             }
+            let (height_map, cols_done) = (&height_map, &cols_done);
+            scope.spawn(move || {
+                let mut rng = rand::thread_rng();
+                for lx in 0..rows[0].len() {
+                    let x = i * band + lx;
+                    let surface = height_map[x];
+
+                    /* sky tiles ------------------------------------------------------- */
+                    for y in 0..surface {
+                        rows[y][lx].kind      = TileKind::Sky;
+                        rows[y][lx].mine_time = 0.0;
+                    }
 
-            /* assign mine time ----------------------------------------- */
-            let (kind, mine_time) = match kind {
-                TileKind::Grass     => (TileKind::Grass,    0.10),
-                TileKind::Snow      => (TileKind::Grass,    0.10),
-                TileKind::Dirt      => (TileKind::Dirt,     1.0),
-                TileKind::Stone     => (TileKind::Stone,    2.50),
-                TileKind::Obsidian  => (TileKind::Obsidian, 10.00),
-                TileKind::Air | TileKind::Sky => (kind, 0.0),
-            };
-            tiles[y][x].kind      = kind;
-            tiles[y][x].mine_time = mine_time;
+                    /* pre‑compute rift value for column ------------------------------ */
+                    let rift_val = noise_rift.get([x as f64 * RIFT_FREQ, 0.0]);
 
-            /* -------- per‑tile tint (discrete steps) -------- */
-            use crate::constants::{
-                COLOR_NOISE_SCALE, COLOR_VARIATION_LEVELS, COLOR_VARIATION_STRENGTH,
-            };
+                    /* ground tiles ---------------------------------------------------- */
+                    for y in surface..h {
+                        let depth = y - surface;
+                        let mut kind = if depth < MIN_CAVE_DEPTH {
+                            if depth > h / 4 { TileKind::Stone } else { TileKind::Dirt }
+                        } else {
+                            if rift_val > params.rift_thresh && depth > 3 {
+                                TileKind::Air
+                            } else if y >= (h as f32 * OBSIDIAN_START_FRAC) as usize {
+                                TileKind::Obsidian
+                            } else if depth > h / 4 {
+                                TileKind::Stone
+                            } else {
+                                TileKind::Dirt
+                            }
+                        };
 
-            let raw = color_noise.get([
-                x as f64 * COLOR_NOISE_SCALE,
-                y as f64 * COLOR_NOISE_SCALE,
-            ]) as f32;
-
-            let step = (((raw + 1.0) * 0.5) * COLOR_VARIATION_LEVELS as f32)
-                .floor()
-                .clamp(0.0, (COLOR_VARIATION_LEVELS - 1) as f32);
-            let norm   = step / (COLOR_VARIATION_LEVELS as f32 - 1.0) * 2.0 - 1.0;
-            let factor = 1.0 + norm * COLOR_VARIATION_STRENGTH;
-
-            tiles[y][x].base_rgb = match kind {
-                TileKind::Grass    => Vec3::new(0.13, 0.70, 0.08) * factor,
-                TileKind::Snow     => Vec3::new(0.95, 0.95, 0.95) * factor,
-                TileKind::Dirt     => Vec3::new(0.55, 0.27, 0.07) * factor,
-                TileKind::Stone    => Vec3::new(0.50, 0.50, 0.50) * factor,
-                TileKind::Obsidian => Vec3::new(0.20, 0.05, 0.35) * factor,
-                TileKind::Air      => BACKGROUND_BROWN            * factor,
-                TileKind::Sky      => Vec3::ZERO, // unused
-            };
+                        /* surface: mostly grass ------------------------------------ */
+                        if depth == 0 {
+                            kind = if rng.gen::<f32>() < params.grass_ratio {
+                                TileKind::Grass
+                            } else {
+                                TileKind::Dirt
+                            };
+                        } else {
+                            /* probabilistic lower‑layer clusters -------------------- */
+                            match kind {
+                                TileKind::Dirt if rng.gen::<f32>() < DIRT_TO_STONE =>
+                                    kind = TileKind::Stone,
+                                TileKind::Stone if rng.gen::<f32>() < obsid_leak =>
+                                    kind = TileKind::Obsidian,
+                                _ => {}
+                            }
+
+                            /* lava pockets deep in the obsidian zone ---------------- */
+                            if kind == TileKind::Obsidian
+                                && y >= (h as f32 * OBSIDIAN_START_FRAC) as usize
+                                && noise_lava.get([x as f64 * LAVA_FREQ, y as f64 * LAVA_FREQ]) > lava_thresh
+                            {
+                                kind = TileKind::Lava;
+                            }
+                        }
+
+                        /* assign mine time ----------------------------------------- */
+                        let kind = if kind == TileKind::Snow { TileKind::Grass } else { kind };
+                        let mine_time = base_mine_time(kind);
+                        rows[y][lx].kind      = kind;
+                        rows[y][lx].mine_time = mine_time;
+                    }
+                    let done = cols_done.fetch_add(1, Ordering::Relaxed) + 1;
+                    progress.fetch_max((done * 700 / w) as u32, Ordering::Relaxed);
+                }
+            });
         }
-    }
+    });
 
-    generate_mountains(&mut tiles, &height_map, w, h, w / 2);
+    let mountains =
+        generate_mountains(&mut tiles, &height_map, w, h, w / 2, params.mountains_per_side);
+    progress.store(800, Ordering::Relaxed);
 
     /* ──────────────────── Sky islands (robust) ────────────────── */
-    {
+    let islands = {
         /* tunables --------------------------------------------------------- */
         const ISLAND_MIN_RADIUS : usize = 80;
         const ISLAND_RADIUS_MAX : usize = 128;
@@ -280,7 +772,7 @@ pub fn generate_world_and_player(
 
         /* realistic island count for this map width ----------------------- */
         let min_footprint  = (ISLAND_MIN_RADIUS as i32 * 2 + ISLAND_GAP) as usize;
-        let target_islands = (w / min_footprint).clamp(1, 16);
+        let target_islands = (w / min_footprint).min(16);  // none on tiny worlds
 
         const MAX_SEARCH: usize = 3_000;          // tries per island before giving up
 
@@ -309,8 +801,13 @@ pub fn generate_world_and_player(
                     let ry_bottom = rx * ISLAND_Y_SCALE;
                     let ry_top    = (rx * 0.30).max(8.0);
 
-                    let cx = rng.gen_range(rx as i32 + 4 .. w as i32 - rx as i32 - 4);
-                    let cy = rng.gen_range(3 .. height_map[cx as usize] as i32 / 2);
+                    /* narrow worlds / high surfaces can leave no room – re‑roll */
+                    let (lo, hi) = (rx as i32 + 4, w as i32 - rx as i32 - 4);
+                    if lo >= hi { continue; }
+                    let cx  = rng.gen_range(lo .. hi);
+                    let top = height_map[cx as usize] as i32 / 2;
+                    if top <= 3 { continue; }
+                    let cy  = rng.gen_range(3 .. top);
 
                     let rect = Rect {
                         min_x: (cx as f32 - rx - ISLAND_GAP as f32) as i32,
@@ -436,17 +933,67 @@ pub fn generate_world_and_player(
                 }
             }
         }
-    }
+        placed.len()
+    };
+
+    progress.store(900, Ordering::Relaxed);
 
     /* ──────────────────── Underground caverns ─────────── */
-    carve_underground_caverns(&mut tiles, w, h, &height_map);
+    let (rooms, cave_tiles) =
+        carve_underground_caverns(&mut tiles, w, h, &height_map, params.cave_density);
+    let mut crate_spots = settle_on_floor(&tiles, &rooms);
+    let spawner_spots   = take_spawner_spots(&mut crate_spots, h);
+    progress.store(950, Ordering::Relaxed);
+
+    /* colour last, once mountains / islands / caverns have settled every kind */
+    tint_tiles(&mut tiles, h, &color_noise, &temp_noise);
+    let lava = tiles
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter().enumerate().filter(|(_, t)| t.kind == TileKind::Lava).map(move |(x, _)| (y, x))
+        })
+        .collect();
+    progress.store(1000, Ordering::Relaxed);
+
+    Terrain {
+        tiles,
+        sprite_entities,
+        changed_tiles: ChangedTiles::new(w, h),
+        kind_changed:  Vec::new(),
+        free_sprites:  Vec::new(),
+        width:  w,
+        height: h,
+        height_map,
+        color_noise,
+        temp_noise,
+        crate_spots,
+        spawner_spots,
+        gen_counts: GenCounts { mountains, islands, cave_tiles },
+        lava,
+    }
+}
+
+/* ===========================================================
+   spawn player – once the generated Terrain is in place
+   =========================================================== */
+pub fn spawn_player(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    terrain: Res<Terrain>,
+) {
+    /* --- sprite sheet ---------------------------------------------------- */
+    let sheet   = asset_server.load("textures/player_sheet.png");
+    let layout  = TextureAtlasLayout::from_grid(UVec2::new(100, 100), 6, 1, None, None);
+    let layout_handle = atlas_layouts.add(layout);
 
     /* --- spawn player ---------------------------------------------------- */
-    let spawn_x  = w / 2;
-    let surf_row = height_map[spawn_x];
+    let spawn_x  = terrain.width / 2;
+    let surf_row = terrain.height_map[spawn_x];
     let spawn    = Vec2::new(
-        spawn_x as f32 * TILE_SIZE,
-        tile_to_world_y(h, surf_row) + TILE_SIZE * 0.5 + PLAYER_HEIGHT * 0.5 + 4.0,
+        spawn_x as f32 * tile_size(),
+        tile_to_world_y(terrain.height, surf_row) + tile_size() * 0.5 + PLAYER_HEIGHT * 0.5 + 4.0,
     );
 
     commands.spawn((
@@ -459,25 +1006,16 @@ pub fn generate_world_and_player(
             scale: Vec3::splat(1.8),
             ..default()
         },
-        Player { grounded: false },
+        Player { grounded: false, coyote: 0.0, jump_buffer: 0.0, knockback: 0.0 },
         Velocity(Vec2::ZERO),
-        Inventory { selected: HeldItem::Pickaxe },
+        Inventory::new(HeldItem::Pickaxe),
+        Collider { half: Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT) / 2.0 },
+        CollisionLayers::PLAYER,
         AnimationIndices { first: 0, last: 5 },
         AnimationTimer(Timer::from_seconds(0.12, TimerMode::Repeating)),
     ));
 
-    /* --- insert resources ----------------------------------------------- */
-    commands.insert_resource(Terrain {
-        tiles,
-        sprite_entities,
-        changed_tiles: VecDeque::new(),
-        free_sprites:  Vec::new(),
-        width:  w,
-        height: h,
-        height_map,
-        color_noise,
-    });
-    commands.insert_resource(LastRect::default());
+    commands.insert_resource(StreamedRect::default());
 }
 
 /* ──────────────────── Mountains (new) ────────────────── */
@@ -487,11 +1025,11 @@ fn generate_mountains(
     w: usize,
     h: usize,
     player_x: usize,
-) {
+    mountains_per_side: usize,
+) -> usize {
     use rand::Rng;
     use noise::{NoiseFn, Perlin};
 
-    const MOUNTAINS_PER_SIDE:     usize = 3;
     const MIN_DIST_FROM_PLAYER:   i32   = 200;
     const MIN_GAP_BETWEEN:        i32   = 120;
     const WIDTH_MIN:              usize = 256;
@@ -506,12 +1044,13 @@ fn generate_mountains(
     let mut rng         = rand::thread_rng();
     let ridge_noise     = Perlin::new(rng.gen());
     let mut placed: Vec<Band> = Vec::new();
+    let mut total = 0usize;
 
     for side in [true, false] {                // true = left, false = right
         let mut attempts = 0usize;
         let mut made     = 0usize;
 
-        while made < MOUNTAINS_PER_SIDE && attempts < MAX_ATTEMPTS {
+        while made < mountains_per_side && attempts < MAX_ATTEMPTS {
             attempts += 1;
 
             /* --- choose footprint & reject if it overlaps ---------------- */
@@ -519,11 +1058,13 @@ fn generate_mountains(
             let half   = width / 2;
             let height = rng.gen_range(HEIGHT_MIN..=HEIGHT_MAX) as i32;
 
-            let cx = if side {
-                rng.gen_range(half .. player_x as i32 - MIN_DIST_FROM_PLAYER - half)
+            let (lo, hi) = if side {
+                (half, player_x as i32 - MIN_DIST_FROM_PLAYER - half)
             } else {
-                rng.gen_range(player_x as i32 + MIN_DIST_FROM_PLAYER + half .. w as i32 - half - 1)
+                (player_x as i32 + MIN_DIST_FROM_PLAYER + half, w as i32 - half - 1)
             };
+            if lo >= hi { continue; }              // side too narrow for this width
+            let cx = rng.gen_range(lo .. hi);
 
             let span = Band { l: cx - half - MIN_GAP_BETWEEN, r: cx + half + MIN_GAP_BETWEEN };
             if placed.iter().any(|b| b.r >= span.l && b.l <= span.r) { continue; }
@@ -600,24 +1141,29 @@ fn generate_mountains(
                 MAX_ATTEMPTS,
                 if side { "left" } else { "right" },
                 made,
-                MOUNTAINS_PER_SIDE
+                mountains_per_side
             );
         }
 
         println!("Successfully generated mountains.");
+        total += made;
     }
+    total
 }
 
 
 /* ===========================================================
    walker‑style underground caverns (larger & more elaborate)
+   – returns the centres of some of the rooms it carved and how
+   many tiles it opened up
    =========================================================== */
    fn carve_underground_caverns(
     tiles: &mut [Vec<Tile>],
     width: usize,
     height: usize,
     height_map: &[usize],
-) {
+    density: f32,
+) -> (Vec<(usize, usize)>, usize) {
     use rand::Rng;
     use bevy::math::{Vec2, Mat2};
 
@@ -630,8 +1176,10 @@ fn generate_mountains(
     const UNDER_ROOM_R_MIN:   i32 = 6;
     const UNDER_ROOM_R_MAX:   i32 = 10;
 
+    let mut rooms = Vec::new();
+    let mut carved = 0usize;
     let mut rng = rand::thread_rng();
-    let walker_count = (width / 32).max(10);
+    let walker_count = (((width / 32) as f32 * density) as usize).max(10);
 
     // Seed walkers a bit below the surface but above obsidian
     let mut walkers: Vec<(Vec2, Vec2)> = Vec::new();
@@ -655,11 +1203,14 @@ fn generate_mountains(
         let steps = rng.gen_range(UNDER_STEPS_MIN..=UNDER_STEPS_MAX);
         for _ in 0..steps {
             let radius = if rng.gen::<f32>() < 0.15 {
+                if rng.gen::<f32>() < CRATE_ROOM_CHANCE {
+                    rooms.push((pos.x as usize, pos.y as usize));
+                }
                 rng.gen_range(UNDER_ROOM_R_MIN..=UNDER_ROOM_R_MAX)
             } else {
                 rng.gen_range(UNDER_TUNNEL_R_MIN..=UNDER_TUNNEL_R_MAX)
             };
-            carve_disc(tiles, width, height, pos.x as i32, pos.y as i32, radius);
+            carved += carve_disc(tiles, width, height, pos.x as i32, pos.y as i32, radius);
 
             if rng.gen::<f32>() < UNDER_TURN_CHANCE {
                 let ang = rng.gen_range(-1.0..1.0);
@@ -674,6 +1225,51 @@ fn generate_mountains(
             }
         }
     }
+    (rooms, carved)
+}
+
+/* ===========================================================
+   drop each point straight down onto the floor below it
+   (later walkers may have carved through, so re‑check here)
+   =========================================================== */
+fn settle_on_floor(tiles: &[Vec<Tile>], points: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let h = tiles.len();
+    let mut out: Vec<(usize, usize)> = Vec::new();
+    for &(x, mut y) in points {
+        if tiles[y][x].kind != TileKind::Air {
+            continue;
+        }
+        while y + 1 < h && tiles[y + 1][x].kind == TileKind::Air {
+            y += 1;
+        }
+        if y + 1 >= h || !solid_kind(tiles[y + 1][x].kind) || out.contains(&(x, y)) {
+            continue;
+        }
+        out.push((x, y));
+    }
+    out
+}
+
+/// moves up to SPAWNER_COUNT deep, well‑spaced floor spots out of
+/// `spots` (so no crate shares a room corner with a portal)
+fn take_spawner_spots(spots: &mut Vec<(usize, usize)>, h: usize) -> Vec<(usize, usize)> {
+    use rand::seq::SliceRandom;
+
+    let min_y = (h as f32 * SPAWNER_MIN_DEPTH_FRAC) as usize;
+    let mut deep: Vec<(usize, usize)> = spots.iter().copied().filter(|&(_, y)| y >= min_y).collect();
+    deep.shuffle(&mut rand::thread_rng());
+
+    let mut out: Vec<(usize, usize)> = Vec::new();
+    for (x, y) in deep {
+        if out.len() >= SPAWNER_COUNT {
+            break;
+        }
+        if out.iter().all(|&(ox, oy)| x.abs_diff(ox).max(y.abs_diff(oy)) >= SPAWNER_MIN_SPACING) {
+            out.push((x, y));
+        }
+    }
+    spots.retain(|p| !out.contains(p));
+    out
 }
 
 #[inline(always)]
@@ -684,7 +1280,8 @@ fn carve_disc(
     cx: i32,
     cy: i32,
     r:  i32,
-) {
+) -> usize {
+    let mut opened = 0;
     for dx in -r..=r {
         let nx    = dx as f32 / r as f32;
         let slice = ((1.0 - nx * nx).sqrt() * r as f32).round() as i32;
@@ -694,9 +1291,112 @@ fn carve_disc(
             let y = cy + dy;
             if x < 0 || x >= w as i32 || y < 0 || y >= h as i32 { continue; }
             if matches!(tiles[y as usize][x as usize].kind, TileKind::Sky) { continue; }
+            if tiles[y as usize][x as usize].kind != TileKind::Air { opened += 1; }
 
             tiles[y as usize][x as usize].kind      = TileKind::Air;
             tiles[y as usize][x as usize].mine_time = 0.0;
         }
     }
-}
\ No newline at end of file
+    opened
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// position in declaration order – exhaustive on purpose, so a new
+    /// kind doesn't compile until it is numbered here (and `ALL` is
+    /// checked against it below)
+    fn ordinal(kind: TileKind) -> usize {
+        match kind {
+            TileKind::Air      => 0,
+            TileKind::Sky      => 1,
+            TileKind::Grass    => 2,
+            TileKind::Dirt     => 3,
+            TileKind::Stone    => 4,
+            TileKind::Obsidian => 5,
+            TileKind::Snow     => 6,
+            TileKind::Lava     => 7,
+            TileKind::Ladder   => 8,
+            TileKind::Glass    => 9,
+            TileKind::Booster  => 10,
+            TileKind::Platform => 11,
+            TileKind::Portal   => 12,
+        }
+    }
+
+    #[test]
+    fn all_lists_every_kind_once() {
+        for (i, &kind) in TileKind::ALL.iter().enumerate() {
+            assert_eq!(ordinal(kind), i, "{kind:?} out of place in TileKind::ALL");
+        }
+        assert_eq!(TileKind::ALL.len(), ordinal(TileKind::Portal) + 1);
+    }
+
+    #[test]
+    fn changed_tiles_queue_once_per_tile_and_requeue_after_clear() {
+        let (w, h) = (CHUNK_WIDTH * 2 + 3, CHUNK_HEIGHT + 1);
+        let mut q = ChangedTiles::new(w, h);
+        for _ in 0..3 {
+            q.push((1, 1));
+        }
+        q.push((CHUNK_WIDTH * 2 + 2, CHUNK_HEIGHT)); // last tile, ragged chunk
+        q.push((2, 1));
+        let batches: Vec<_> = q.batches().collect();
+        assert_eq!(batches, [&[(1, 1), (2, 1)][..], &[(CHUNK_WIDTH * 2 + 2, CHUNK_HEIGHT)][..]]);
+
+        q.clear();
+        assert!(q.is_empty());
+        q.push((1, 1));
+        assert_eq!(q.iter().copied().collect::<Vec<_>>(), [(1, 1)]);
+    }
+
+    const W: usize = 8;
+    const H: usize = 6;
+
+    /// where `tile_stream` puts the sprite of tile (x, y)
+    fn centre(x: usize, y: usize) -> Vec2 {
+        Vec2::new(x as f32 * tile_size(), tile_to_world_y(H, y))
+    }
+
+    #[test]
+    fn world_to_tile_hits_every_sprite_centre() {
+        for y in 0..H {
+            for x in 0..W {
+                assert_eq!(world_to_tile(H, centre(x, y)), (x as i32, y as i32));
+            }
+        }
+    }
+
+    #[test]
+    fn world_to_tile_first_and_last_tiles() {
+        assert_eq!(world_to_tile(H, Vec2::ZERO), (0, H as i32 - 1));
+        assert_eq!(world_to_tile(H, centre(0, 0)), (0, 0));
+        assert_eq!(world_to_tile(H, centre(W - 1, H - 1)), (W as i32 - 1, H as i32 - 1));
+    }
+
+    #[test]
+    fn world_to_tile_half_tile_edges() {
+        let half = tile_size() * 0.5;
+        let eps  = 0.01;
+        let c    = centre(3, 2);
+        // just inside each edge: still the same tile
+        assert_eq!(world_to_tile(H, c + Vec2::new(half - eps, 0.0)), (3, 2));
+        assert_eq!(world_to_tile(H, c - Vec2::new(half - eps, 0.0)), (3, 2));
+        assert_eq!(world_to_tile(H, c + Vec2::new(0.0, half - eps)), (3, 2));
+        assert_eq!(world_to_tile(H, c - Vec2::new(0.0, half - eps)), (3, 2));
+        // just outside: the neighbour (y grows down in tiles)
+        assert_eq!(world_to_tile(H, c + Vec2::new(half + eps, 0.0)), (4, 2));
+        assert_eq!(world_to_tile(H, c - Vec2::new(half + eps, 0.0)), (2, 2));
+        assert_eq!(world_to_tile(H, c + Vec2::new(0.0, half + eps)), (3, 1));
+        assert_eq!(world_to_tile(H, c - Vec2::new(0.0, half + eps)), (3, 3));
+    }
+
+    #[test]
+    fn world_to_tile_off_the_map_goes_out_of_range() {
+        let half = tile_size() * 0.5;
+        assert_eq!(world_to_tile(H, Vec2::new(-half - 0.01, 0.0)).0, -1);
+        assert_eq!(world_to_tile(H, Vec2::new(0.0, -half - 0.01)).1, H as i32);
+        assert_eq!(world_to_tile(H, centre(0, 0) + Vec2::new(0.0, half + 0.01)).1, -1);
+    }
+}
@@ -0,0 +1,73 @@
+//! generation report – what the world generator actually produced
+//!
+//! Runs once when the game starts: counts every tile kind, reads the
+//! mountain / sky‑island / cavern numbers `generate_terrain` recorded and
+//! the surface height range, logs all of it and keeps a summary for the
+//! F3 overlay.  Degenerate seeds (no caves, no islands, no mountains) are
+//! flagged with a warning so parameter changes are easy to sanity‑check.
+
+use bevy::prelude::*;
+
+use crate::world_gen::{GenCounts, Terrain, TileKind};
+
+/// every kind, in the order they are reported
+const KINDS: &[TileKind] = TileKind::ALL;
+
+#[derive(Resource, Default)]
+pub struct WorldStats {
+    /// tiles per kind, same order as `KINDS`
+    pub tiles:       Vec<(TileKind, usize)>,
+    pub counts:      GenCounts,
+    /// shallowest / deepest surface row
+    pub surface_min: usize,
+    pub surface_max: usize,
+}
+
+impl WorldStats {
+    /// one line for the F3 overlay
+    pub fn summary(&self) -> String {
+        let GenCounts { mountains, islands, cave_tiles } = self.counts;
+        format!(
+            "world: {mountains} mountains, {islands} islands, {cave_tiles} cave tiles, \
+             surface rows {}..{}",
+            self.surface_min, self.surface_max,
+        )
+    }
+}
+
+/* ===========================================================
+   once on entering the game, right after generation
+   =========================================================== */
+pub fn world_stats_system(mut commands: Commands, terrain: Res<Terrain>) {
+    let mut per_kind = [0usize; KINDS.len()];
+    for row in &terrain.tiles {
+        for tile in row {
+            if let Some(i) = KINDS.iter().position(|&k| k == tile.kind) {
+                per_kind[i] += 1;
+            }
+        }
+    }
+
+    let stats = WorldStats {
+        tiles:       KINDS.iter().copied().zip(per_kind).collect(),
+        counts:      terrain.gen_counts,
+        surface_min: terrain.height_map.iter().copied().min().unwrap_or(0),
+        surface_max: terrain.height_map.iter().copied().max().unwrap_or(0),
+    };
+
+    info!("{} × {} tiles – {}", terrain.width, terrain.height, stats.summary());
+    for (kind, n) in &stats.tiles {
+        info!("  {kind:?}: {n}");
+    }
+    let GenCounts { mountains, islands, cave_tiles } = stats.counts;
+    if cave_tiles == 0 {
+        warn!("world generation carved no caves");
+    }
+    if islands == 0 {
+        warn!("world generation placed no sky islands");
+    }
+    if mountains == 0 {
+        warn!("world generation placed no mountains");
+    }
+    commands.insert_resource(stats);
+}